@@ -0,0 +1,28 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+//!
+//! Prints a report of the core strategies, as one JSON object per line.
+//!
+//! `cargo run --example report -- [samples]`
+fn main() {
+    let samples = std::env::args()
+        .nth(1)
+        .map(|n| n.parse().expect("samples must be a number"))
+        .unwrap_or(1000);
+
+    for report in proptest_semver::report(samples) {
+        println!("{}", report.to_json());
+    }
+}
@@ -0,0 +1,316 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+//!
+//! Implementations of [proptest::arbitrary::Arbitrary] for the types of this
+//! crate, and for newtype wrappers around `semver` types, so they can be used
+//! with [any] and [any_with], or in structs deriving `Arbitrary` without
+//! `#[proptest(strategy = ...)]` attributes everywhere.
+//!
+//! With the `serde` feature, each of the `*Params` here is `Serialize` and
+//! `Deserialize`, where missing fields are their default, and each
+//! [SizeRange] is a non-empty `[start, end]` pair.
+use proptest::collection::SizeRange;
+use proptest::prelude::*;
+use semver::{BuildMetadata, Comparator, Prerelease, Version, VersionReq};
+
+use crate::identifier::{
+    arb_build_metadata_identifier_of_len, arb_pre_release_identifier_of_len, DEFAULT_IDENTIFIER_LEN,
+};
+use crate::{
+    arb_full_comparator_vec, arb_full_comparator_with_ops, ComparatorVec, FullComparator, OpWeights,
+};
+
+// A small default, since every comparator in a [ComparatorVec::List] is
+// generated.
+const DEFAULT_MAX_COMPARATORS: usize = 4;
+
+/// Parameters for [ArbPrerelease].
+#[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default, deny_unknown_fields)
+)]
+pub struct PrereleaseParams {
+    /// (default: `0..4`) The number of identifiers, where `0` is an empty
+    /// [semver::Prerelease].
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialize::size_range"))]
+    pub identifiers: SizeRange,
+
+    /// (default: `1..9`) The length of each identifier.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialize::size_range"))]
+    pub identifier_len: SizeRange,
+
+    /// (default: `0.5`) Follows [proptest::option::Probability] rules, for
+    /// each identifier being numeric.
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serialize::unit_interval")
+    )]
+    pub probability_of_numeric: f64,
+}
+
+impl Default for PrereleaseParams {
+    fn default() -> Self {
+        Self {
+            identifiers: (0..4).into(),
+            identifier_len: DEFAULT_IDENTIFIER_LEN.into(),
+            probability_of_numeric: 0.5,
+        }
+    }
+}
+
+/// Parameters for [ArbBuildMetadata].
+#[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default, deny_unknown_fields)
+)]
+pub struct BuildMetadataParams {
+    /// (default: `0..4`) The number of identifiers, where `0` is an empty
+    /// [semver::BuildMetadata].
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialize::size_range"))]
+    pub identifiers: SizeRange,
+
+    /// (default: `1..9`) The length of each identifier.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialize::size_range"))]
+    pub identifier_len: SizeRange,
+}
+
+impl Default for BuildMetadataParams {
+    fn default() -> Self {
+        Self {
+            identifiers: (0..4).into(),
+            identifier_len: DEFAULT_IDENTIFIER_LEN.into(),
+        }
+    }
+}
+
+/// A [semver::Prerelease] implementing [proptest::arbitrary::Arbitrary].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ArbPrerelease(pub Prerelease);
+
+impl From<ArbPrerelease> for Prerelease {
+    fn from(value: ArbPrerelease) -> Self {
+        value.0
+    }
+}
+
+impl Arbitrary for ArbPrerelease {
+    type Parameters = PrereleaseParams;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        prop::collection::vec(
+            arb_pre_release_identifier_of_len(args.identifier_len, args.probability_of_numeric),
+            args.identifiers,
+        )
+        .prop_map(|identifiers| ArbPrerelease(Prerelease::new(&identifiers.join(".")).unwrap()))
+        .boxed()
+    }
+}
+
+/// A [semver::BuildMetadata] implementing [proptest::arbitrary::Arbitrary].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ArbBuildMetadata(pub BuildMetadata);
+
+impl From<ArbBuildMetadata> for BuildMetadata {
+    fn from(value: ArbBuildMetadata) -> Self {
+        value.0
+    }
+}
+
+impl Arbitrary for ArbBuildMetadata {
+    type Parameters = BuildMetadataParams;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        prop::collection::vec(
+            arb_build_metadata_identifier_of_len(args.identifier_len),
+            args.identifiers,
+        )
+        .prop_map(|identifiers| {
+            ArbBuildMetadata(BuildMetadata::new(&identifiers.join(".")).unwrap())
+        })
+        .boxed()
+    }
+}
+
+/// Parameters for [ArbVersion].
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default, deny_unknown_fields)
+)]
+pub struct VersionParams {
+    /// (default: [PrereleaseParams::default]) The Pre-Release, which is empty
+    /// when it has no identifiers.
+    pub pre: PrereleaseParams,
+
+    /// (default: [BuildMetadataParams::default]) The Build Metadata, which is
+    /// empty when it has no identifiers.
+    pub build: BuildMetadataParams,
+}
+
+/// A [semver::Version] implementing [proptest::arbitrary::Arbitrary].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ArbVersion(pub Version);
+
+impl From<ArbVersion> for Version {
+    fn from(value: ArbVersion) -> Self {
+        value.0
+    }
+}
+
+impl Arbitrary for ArbVersion {
+    type Parameters = VersionParams;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        (
+            any::<u64>(),
+            any::<u64>(),
+            any::<u64>(),
+            any_with::<ArbPrerelease>(args.pre),
+            any_with::<ArbBuildMetadata>(args.build),
+        )
+            .prop_map(|(major, minor, patch, pre, build)| {
+                ArbVersion(Version {
+                    major,
+                    minor,
+                    patch,
+                    pre: pre.into(),
+                    build: build.into(),
+                })
+            })
+            .boxed()
+    }
+}
+
+/// Parameters for [FullComparator], see [crate::arb_full_comparator].
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default, deny_unknown_fields)
+)]
+pub struct FullComparatorParams {
+    /// (default: [OpWeights::default]) The weight of each operator, such as
+    /// [OpWeights::CRATES_IO].
+    pub ops: OpWeights,
+    pub weight_of_plain: Option<u32>,
+    pub weight_of_wildcard_minor: Option<u32>,
+    pub weight_of_wildcard_patch: Option<u32>,
+}
+
+impl Arbitrary for FullComparator {
+    type Parameters = FullComparatorParams;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        arb_full_comparator_with_ops(
+            &args.ops,
+            args.weight_of_plain,
+            args.weight_of_wildcard_minor,
+            args.weight_of_wildcard_patch,
+        )
+        .boxed()
+    }
+}
+
+/// Parameters for [ComparatorVec], see [arb_full_comparator_vec].
+#[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default, deny_unknown_fields)
+)]
+pub struct ComparatorVecParams {
+    /// (default: `4`) Should always be less than or equal to
+    /// [crate::MAX_COMPARATORS_IN_VERSION_REQ_STRING].
+    pub max_comparators: usize,
+    pub weight_of_wildcard: Option<u32>,
+    pub weight_of_comparator_list: Option<u32>,
+}
+
+impl Default for ComparatorVecParams {
+    fn default() -> Self {
+        Self {
+            max_comparators: DEFAULT_MAX_COMPARATORS,
+            weight_of_wildcard: None,
+            weight_of_comparator_list: None,
+        }
+    }
+}
+
+impl Arbitrary for ComparatorVec {
+    type Parameters = ComparatorVecParams;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        arb_full_comparator_vec(
+            args.max_comparators,
+            args.weight_of_wildcard,
+            args.weight_of_comparator_list,
+        )
+        .boxed()
+    }
+}
+
+/// A [semver::Comparator] implementing [proptest::arbitrary::Arbitrary],
+/// parsed from a [FullComparator].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ArbComparator(pub Comparator);
+
+impl From<ArbComparator> for Comparator {
+    fn from(value: ArbComparator) -> Self {
+        value.0
+    }
+}
+
+impl Arbitrary for ArbComparator {
+    type Parameters = FullComparatorParams;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        any_with::<FullComparator>(args)
+            .prop_map(|c| ArbComparator(Comparator::parse(&c.to_string()).unwrap()))
+            .boxed()
+    }
+}
+
+/// A [semver::VersionReq] implementing [proptest::arbitrary::Arbitrary],
+/// parsed from a [ComparatorVec].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ArbVersionReq(pub VersionReq);
+
+impl From<ArbVersionReq> for VersionReq {
+    fn from(value: ArbVersionReq) -> Self {
+        value.0
+    }
+}
+
+impl Arbitrary for ArbVersionReq {
+    type Parameters = ComparatorVecParams;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        any_with::<ComparatorVec>(args)
+            .prop_map(|c| ArbVersionReq(VersionReq::parse(&c.to_string()).unwrap()))
+            .boxed()
+    }
+}
@@ -0,0 +1,86 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+//!
+//! Version strings handed out as borrowed `&str` from a single buffer, for
+//! testing zero-copy parsers without cloning into a `String` per version.
+use proptest::prelude::*;
+use std::fmt::{self, Write};
+use std::ops::Range;
+
+use crate::arb_version;
+
+/// Version strings, stored back to back in a single buffer.
+///
+/// Borrowed strings live as long as the arena, which is owned by the test
+/// case, so nothing is ever leaked to get a `&'static str`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct VersionArena {
+    buffer: String,
+    spans: Vec<Range<usize>>,
+}
+
+impl VersionArena {
+    /// Appends the string of `s` to the arena, such as a [semver::Version],
+    /// without an intermediate `String`.
+    pub fn push(&mut self, s: impl fmt::Display) {
+        let start = self.buffer.len();
+        write!(self.buffer, "{s}").unwrap();
+        self.spans.push(start..self.buffer.len());
+    }
+
+    /// The string at `index`, if any.
+    pub fn get(&self, index: usize) -> Option<&str> {
+        self.spans.get(index).map(|span| &self.buffer[span.clone()])
+    }
+
+    /// Every string, in the order they were pushed.
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.spans.iter().map(|span| &self.buffer[span.clone()])
+    }
+
+    pub fn len(&self) -> usize {
+        self.spans.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+}
+
+impl<'a> FromIterator<&'a str> for VersionArena {
+    fn from_iter<T: IntoIterator<Item = &'a str>>(iter: T) -> Self {
+        let mut arena = VersionArena::default();
+        for s in iter {
+            arena.push(s);
+        }
+        arena
+    }
+}
+
+prop_compose! {
+    /// Provides a [VersionArena] of valid version strings, from [arb_version].
+    ///
+    /// * `max_versions` - The maximum number of versions, which is at least
+    ///   `1`.
+    pub fn arb_version_arena(max_versions: usize)(
+        versions in prop::collection::vec(arb_version(), 1..=max_versions)
+    ) -> VersionArena {
+        let mut arena = VersionArena::default();
+        for v in versions {
+            arena.push(v);
+        }
+        arena
+    }
+}
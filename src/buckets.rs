@@ -0,0 +1,389 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+//!
+//! Versions filled into buckets of known sizes, for testing aggregations with
+//! exact expected counts.
+use proptest::prelude::*;
+use semver::{BuildMetadata, Prerelease, Version};
+use std::collections::BTreeMap;
+
+use crate::precedence::{
+    arb_offsets, extend_pre_release, previous_triple, triple, triple_between, version_of, Triple,
+};
+use crate::{
+    arb_option_semver_build_metadata, arb_option_semver_prerelease, arb_pre_release_string,
+    arb_version, arb_version_above,
+};
+
+/// A bucket of versions, by shared components.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum VersionBucket {
+    /// Every version with this `MAJOR`.
+    Major(u64),
+
+    /// Every version with this `MAJOR.MINOR`.
+    Minor(u64, u64),
+}
+
+impl VersionBucket {
+    /// Returns `true` if `version` falls in this bucket.
+    pub fn contains(&self, version: &Version) -> bool {
+        match *self {
+            VersionBucket::Major(major) => version.major == major,
+            VersionBucket::Minor(major, minor) => (version.major, version.minor) == (major, minor),
+        }
+    }
+
+    /// The bucket of `version`, at some `level`.
+    pub fn of(version: &Version, level: BucketLevel) -> Self {
+        match level {
+            BucketLevel::Major => VersionBucket::Major(version.major),
+            BucketLevel::Minor => VersionBucket::Minor(version.major, version.minor),
+        }
+    }
+
+    fn overlaps(&self, other: &VersionBucket) -> bool {
+        use VersionBucket::*;
+        match (*self, *other) {
+            (Major(a), Major(b)) | (Major(a), Minor(b, _)) | (Minor(a, _), Major(b)) => a == b,
+            (Minor(a, x), Minor(b, y)) => (a, x) == (b, y),
+        }
+    }
+}
+
+/// How coarse a [VersionBucket] is.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BucketLevel {
+    Major,
+    Minor,
+}
+
+/// Provides distinct versions, with exactly the requested count in each
+/// bucket, in no particular order.
+///
+/// Versions are sometimes a Pre-Release, and sometimes have Build Metadata.
+///
+/// * `buckets` - Each bucket, with the number of versions to fill it with. No
+///   two buckets may overlap (such as `Major(1)` and `Minor(1, 2)`).
+pub fn arb_versions_in_buckets(
+    buckets: &[(VersionBucket, usize)],
+) -> impl Strategy<Value = Vec<Version>> {
+    for (i, (a, _)) in buckets.iter().enumerate() {
+        for (b, _) in &buckets[i + 1..] {
+            assert!(!a.overlaps(b), "{a:?} overlaps {b:?}");
+        }
+    }
+
+    let filled = buckets
+        .iter()
+        .map(|&(bucket, count)| prop::collection::btree_set(arb_version_in_bucket(bucket), count))
+        .collect::<Vec<_>>();
+
+    filled
+        .prop_map(|filled| filled.into_iter().flatten().collect::<Vec<_>>())
+        .prop_shuffle()
+}
+
+fn arb_version_in_bucket(bucket: VersionBucket) -> impl Strategy<Value = Version> {
+    (
+        any::<u64>(),
+        any::<u64>(),
+        arb_option_semver_prerelease(0.25),
+        arb_option_semver_build_metadata(0.25),
+    )
+        .prop_map(move |(minor, patch, pre, build)| {
+            let (major, minor) = match bucket {
+                VersionBucket::Major(major) => (major, minor),
+                VersionBucket::Minor(major, minor) => (major, minor),
+            };
+
+            let mut v = Version::new(major, minor, patch);
+            v.pre = pre.unwrap_or(Prerelease::EMPTY);
+            v.build = build.unwrap_or(BuildMetadata::EMPTY);
+            v
+        })
+}
+
+/// Versions partitioned into shards, from [arb_sharded_versions].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShardedVersions {
+    /// Each shard, sorted by [Ord].
+    pub shards: Vec<Vec<Version>>,
+
+    /// Every version of every shard, sorted by [Ord].
+    pub merged: Vec<Version>,
+}
+
+/// Provides a universe of versions partitioned into shards, with each shard
+/// sorted, and the merged order of every version, for testing parallel merge
+/// and aggregation code against a known global answer.
+///
+/// Versions are sorted by [Ord] (precedence, then Build Metadata), so the
+/// merged order is unique. Versions are often small and sometimes repeated,
+/// both within and across shards, and shards may be empty.
+///
+/// * `shards` - The number of shards, which is at least `1`.
+/// * `max` - The maximum number of versions, across every shard.
+pub fn arb_sharded_versions(shards: usize, max: usize) -> impl Strategy<Value = ShardedVersions> {
+    assert!(shards >= 1, "there are no shards to fill");
+
+    let version = prop_oneof![
+        arb_version(),
+        (0..3u64, 0..3u64, 0..3u64)
+            .prop_map(|(major, minor, patch)| Version::new(major, minor, patch)),
+    ];
+
+    prop::collection::vec((version, 0..shards), 0..=max).prop_map(move |assigned| {
+        let mut sharded = vec![Vec::new(); shards];
+        for (v, shard) in &assigned {
+            sharded[*shard].push(v.clone());
+        }
+        for shard in &mut sharded {
+            shard.sort();
+        }
+
+        let mut merged = assigned.into_iter().map(|(v, _)| v).collect::<Vec<_>>();
+        merged.sort();
+
+        ShardedVersions {
+            shards: sharded,
+            merged,
+        }
+    })
+}
+
+/// The maximum number of versions from [arb_version_bucketing_case].
+const MAX_POPULATION: usize = 64;
+
+/// A population of client versions, and it's expected coarse buckets, from
+/// [arb_version_bucketing_case].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BucketingCase {
+    pub versions: Vec<Version>,
+    pub level: BucketLevel,
+
+    /// The number of versions in each bucket, without any empty buckets.
+    pub counts: BTreeMap<VersionBucket, usize>,
+}
+
+/// Provides a population of client versions, alongside the count of each
+/// bucket at some level, for testing telemetry aggregation which must only
+/// report coarse buckets.
+///
+/// Most versions fall into a few popular buckets, with many distinct
+/// `PATCH`, Pre-Release and Build Metadata within them, while rare outliers
+/// each land in a bucket of their own, so exact rare versions are easy to
+/// leak by accident.
+pub fn arb_version_bucketing_case() -> impl Strategy<Value = BucketingCase> {
+    let popular = (0..3u64, 0..3u64)
+        .prop_flat_map(|(major, minor)| arb_version_in_bucket(VersionBucket::Minor(major, minor)));
+    let version = prop_oneof![9 => popular, 1 => arb_version()];
+
+    (
+        prop::collection::vec(version, 0..=MAX_POPULATION),
+        prop_oneof![Just(BucketLevel::Major), Just(BucketLevel::Minor)],
+    )
+        .prop_map(|(versions, level)| {
+            let mut counts = BTreeMap::new();
+            for v in &versions {
+                *counts.entry(VersionBucket::of(v, level)).or_insert(0) += 1;
+            }
+            BucketingCase {
+                versions,
+                level,
+                counts,
+            }
+        })
+}
+
+/// A Cargo compatibility class, of versions a `^` requirement treats as
+/// compatible with each other.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CompatibilityClass {
+    /// Every version with this non-zero `MAJOR`.
+    Major(u64),
+
+    /// Every `0.MINOR` version, with this non-zero `MINOR`.
+    Minor(u64),
+
+    /// Every `0.0.PATCH` version, with this `PATCH`.
+    Patch(u64),
+}
+
+impl CompatibilityClass {
+    /// The compatibility class of `version`.
+    pub fn of(version: &Version) -> Self {
+        match (version.major, version.minor) {
+            (0, 0) => CompatibilityClass::Patch(version.patch),
+            (0, minor) => CompatibilityClass::Minor(minor),
+            (major, _) => CompatibilityClass::Major(major),
+        }
+    }
+
+    /// The first `MAJOR.MINOR.PATCH` of the class after this one, if any.
+    fn next(&self) -> Option<Triple> {
+        match *self {
+            CompatibilityClass::Major(major) => Some((major.checked_add(1)?, 0, 0)),
+            CompatibilityClass::Minor(minor) => Some((0, minor.checked_add(1)?, 0)),
+            CompatibilityClass::Patch(patch) => Some((0, 0, patch.checked_add(1)?)),
+        }
+    }
+}
+
+/// Versions grouped by compatibility class, from
+/// [arb_compatibility_classes].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CompatibilityClasses {
+    /// Every version of every class, in no particular order.
+    pub versions: Vec<Version>,
+
+    /// The versions of each class, sorted by [Ord], without any empty
+    /// classes.
+    pub classes: BTreeMap<CompatibilityClass, Vec<Version>>,
+}
+
+fn arb_version_in_class(class: CompatibilityClass) -> impl Strategy<Value = Version> {
+    (0..20u64, 0..20u64, arb_option_semver_prerelease(0.25)).prop_map(move |(minor, patch, pre)| {
+        let mut v = match class {
+            CompatibilityClass::Major(major) => Version::new(major, minor, patch),
+            CompatibilityClass::Minor(minor) => Version::new(0, minor, patch),
+            CompatibilityClass::Patch(patch) => Version::new(0, 0, patch),
+        };
+        v.pre = pre.unwrap_or(Prerelease::EMPTY);
+        v
+    })
+}
+
+/// Provides distinct versions pre-grouped into Cargo compatibility classes
+/// (see [CompatibilityClass]), for testing dedup by compatibility class in
+/// dependency analyzers.
+///
+/// Classes are small numbers, so neighbouring classes (such as `1.x` and
+/// `2.x`, or `0.1.x` and `1.x`) are common. Versions are sometimes a
+/// Pre-Release, and never have Build Metadata.
+///
+/// * `max_classes` - The maximum number of classes, which is at least `1`.
+/// * `max_per_class` - The maximum number of versions in each class, which is
+///   at least `1`.
+pub fn arb_compatibility_classes(
+    max_classes: usize,
+    max_per_class: usize,
+) -> impl Strategy<Value = CompatibilityClasses> {
+    assert!(max_classes >= 1, "there is at least one class");
+    assert!(max_per_class >= 1, "every class has at least one version");
+
+    let class = prop_oneof![
+        (1..10u64).prop_map(CompatibilityClass::Major),
+        (1..10u64).prop_map(CompatibilityClass::Minor),
+        (0..10u64).prop_map(CompatibilityClass::Patch),
+    ];
+
+    prop::collection::btree_set(class, 1..=max_classes)
+        .prop_flat_map(move |classes| {
+            classes
+                .into_iter()
+                .map(|class| {
+                    (
+                        Just(class),
+                        prop::collection::btree_set(arb_version_in_class(class), 1..=max_per_class),
+                    )
+                })
+                .collect::<Vec<_>>()
+        })
+        .prop_flat_map(|classes| {
+            let versions = classes
+                .iter()
+                .flat_map(|(_, versions)| versions.iter().cloned())
+                .collect::<Vec<_>>();
+            let classes = classes
+                .into_iter()
+                .map(|(class, versions)| (class, versions.into_iter().collect()))
+                .collect();
+            (Just(versions).prop_shuffle(), Just(classes))
+        })
+        .prop_map(|(versions, classes)| CompatibilityClasses { versions, classes })
+}
+
+/// A base version for compatibility pairs, which is often `0.x` or `0.0.x`,
+/// and sometimes a Pre-Release.
+fn arb_compatibility_base() -> impl Strategy<Value = Version> {
+    (
+        prop_oneof![2 => Just(0u64), 1 => 1..20u64],
+        prop_oneof![1 => Just(0u64), 2 => 0..20u64],
+        0..20u64,
+        arb_option_semver_prerelease(0.25),
+    )
+        .prop_map(|(major, minor, patch, pre)| version_of((major, minor, patch), pre))
+}
+
+/// Provides a pair of versions `(base, other)`, where `other` is compatible
+/// with `base` under Cargo's `^` rules (so `^base` matches `other`), for
+/// testing update checkers.
+///
+/// Bases are often `0.x` (where only `0.MINOR` is compatible) or `0.0.x`
+/// (where only `0.0.PATCH` is compatible). `other` is `base` itself, a later
+/// release of it's [CompatibilityClass], or a later Pre-Release of it's
+/// `MAJOR.MINOR.PATCH` when `base` is a Pre-Release. Versions never have
+/// Build Metadata.
+pub fn arb_semver_compatible_pair() -> impl Strategy<Value = (Version, Version)> {
+    arb_compatibility_base().prop_flat_map(|base| {
+        let t = triple(&base);
+        // The class always ends below `MAX.MAX.MAX`, since components are
+        // small.
+        let last = previous_triple(CompatibilityClass::of(&base).next().unwrap()).unwrap();
+
+        let mut options = vec![
+            Just(base.clone()).boxed(),
+            arb_offsets()
+                .prop_map(move |offsets| version_of(triple_between(t, last, offsets), None))
+                .boxed(),
+        ];
+        if !base.pre.is_empty() {
+            let pre = base.pre.clone();
+            options.push(
+                arb_pre_release_string()
+                    .prop_map(move |extra| version_of(t, Some(extend_pre_release(&pre, &extra))))
+                    .boxed(),
+            );
+        }
+
+        let base = base.clone();
+        prop::strategy::Union::new(options).prop_map(move |other| (base.clone(), other))
+    })
+}
+
+/// Provides a pair of versions `(base, other)`, where `other` is a breaking
+/// upgrade of `base` under Cargo's `^` rules (so it's in a later
+/// [CompatibilityClass], and `^base` doesn't match it), for testing update
+/// checkers.
+///
+/// Bases are often `0.x` (where a `MINOR` bump is breaking) or `0.0.x` (where
+/// a `PATCH` bump is breaking). `other` is often the first version of the
+/// next class (such as `0.3.0-0` or `0.3.0` after `0.2.5`), and otherwise
+/// any version above that. Versions never have Build Metadata.
+pub fn arb_semver_breaking_pair() -> impl Strategy<Value = (Version, Version)> {
+    arb_compatibility_base().prop_flat_map(|base| {
+        let next = CompatibilityClass::of(&base).next().unwrap();
+        let first = version_of(next, Some(Prerelease::new("0").unwrap()));
+
+        let base = base.clone();
+        prop_oneof![
+            1 => Just(first.clone()),
+            1 => Just(version_of(next, None)),
+            2 => arb_version_above(&first).unwrap(),
+        ]
+        .prop_map(move |other| (base.clone(), other))
+    })
+}
@@ -0,0 +1,354 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+//!
+//! A fluent builder for [semver::Version] strategies, for when more than a
+//! couple of things need controlling at once.
+use proptest::collection::SizeRange;
+use proptest::prelude::*;
+use semver::{BuildMetadata, Prerelease, Version};
+use std::ops::{Bound, RangeBounds, RangeInclusive};
+
+use crate::identifier::DEFAULT_IDENTIFIER_LEN;
+use crate::{
+    arb_build_metadata_identifiers, arb_semver_build_metadata, arb_semver_prerelease,
+    ArbPrerelease, BuildMetadataParams, IdentifierCharset, PrereleaseParams,
+    DEFAULT_PROBABILITY_OF_BUILD_METADATA, DEFAULT_PROBABILITY_OF_PRE_RELEASE,
+};
+
+/// Builds a [Strategy] of [semver::Version], such as:
+///
+/// ```
+/// use proptest_semver::VersionStrategy;
+///
+/// let strategy = VersionStrategy::new()
+///     .major(0..10u64)
+///     .prerelease_probability(0.2)
+///     .prerelease_identifiers(1..3)
+///     .prerelease_identifier_len(1..8)
+///     .build_metadata(false)
+///     .build();
+/// ```
+///
+/// By default, this is like [crate::arb_semver_version].
+#[derive(Clone, Debug)]
+pub struct VersionStrategy {
+    major: BoxedStrategy<u64>,
+    minor: BoxedStrategy<u64>,
+    patch: BoxedStrategy<u64>,
+    probability_of_pre_release: f64,
+    probability_of_build_metadata: f64,
+
+    /// When `None`, a Pre-Release is like [crate::arb_semver_prerelease].
+    pre_release: Option<PrereleaseParams>,
+
+    /// When `None`, Build Metadata is like [crate::arb_semver_build_metadata].
+    build_metadata: Option<BuildMetadataBounds>,
+}
+
+/// The bounds of Build Metadata, for [arb_build_metadata_identifiers].
+#[derive(Clone, Debug)]
+struct BuildMetadataBounds {
+    identifiers: SizeRange,
+    identifier_len: SizeRange,
+    charset: IdentifierCharset,
+}
+
+impl Default for VersionStrategy {
+    fn default() -> Self {
+        Self {
+            major: any::<u64>().boxed(),
+            minor: any::<u64>().boxed(),
+            patch: any::<u64>().boxed(),
+            probability_of_pre_release: DEFAULT_PROBABILITY_OF_PRE_RELEASE,
+            probability_of_build_metadata: DEFAULT_PROBABILITY_OF_BUILD_METADATA,
+            pre_release: None,
+            build_metadata: None,
+        }
+    }
+}
+
+/// Sometimes a value of `strategy`, where `probability` may also be exactly
+/// `0.0` or `1.0`.
+fn arb_sometimes<T: std::fmt::Debug + Clone + 'static>(
+    probability: f64,
+    strategy: impl Strategy<Value = T> + 'static,
+) -> BoxedStrategy<Option<T>> {
+    if probability <= 0.0 {
+        Just(None).boxed()
+    } else if probability >= 1.0 {
+        strategy.prop_map(Some).boxed()
+    } else {
+        prop::option::weighted(probability, strategy).boxed()
+    }
+}
+
+impl VersionStrategy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// (default: any [u64]) The MAJOR component, such as `0..10u64`.
+    pub fn major(mut self, major: impl Strategy<Value = u64> + 'static) -> Self {
+        self.major = major.boxed();
+        self
+    }
+
+    /// (default: any [u64]) The MINOR component.
+    pub fn minor(mut self, minor: impl Strategy<Value = u64> + 'static) -> Self {
+        self.minor = minor.boxed();
+        self
+    }
+
+    /// (default: any [u64]) The PATCH component.
+    pub fn patch(mut self, patch: impl Strategy<Value = u64> + 'static) -> Self {
+        self.patch = patch.boxed();
+        self
+    }
+
+    /// (default: `0.5`) The probability of having a Pre-Release, from `0.0`
+    /// (never) to `1.0` (always).
+    pub fn prerelease_probability(mut self, probability: f64) -> Self {
+        self.probability_of_pre_release = probability;
+        self
+    }
+
+    /// (default: unbounded) The number of identifiers in a Pre-Release, when
+    /// there is one, such as `1..3`.
+    ///
+    /// Bounding either the number or the length of identifiers bounds both,
+    /// with the other at it's [PrereleaseParams] default (but always with at
+    /// least one identifier).
+    pub fn prerelease_identifiers(mut self, count: impl Into<SizeRange>) -> Self {
+        self.pre_release_bounds().identifiers = count.into();
+        self
+    }
+
+    /// (default: unbounded) The length of each identifier of a Pre-Release,
+    /// such as `1..8`.
+    pub fn prerelease_identifier_len(mut self, len: impl Into<SizeRange>) -> Self {
+        self.pre_release_bounds().identifier_len = len.into();
+        self
+    }
+
+    /// Bounds a Pre-Release by `params` (such as ones loaded with the `serde`
+    /// feature), where the probability of having one is still
+    /// [VersionStrategy::prerelease_probability].
+    pub fn prerelease_params(mut self, params: PrereleaseParams) -> Self {
+        self.pre_release = Some(params);
+        self
+    }
+
+    /// Switches a Pre-Release over to [PrereleaseParams], which are bounded,
+    /// with at least one identifier.
+    fn pre_release_bounds(&mut self) -> &mut PrereleaseParams {
+        self.pre_release.get_or_insert_with(|| PrereleaseParams {
+            identifiers: (1..4).into(),
+            ..PrereleaseParams::default()
+        })
+    }
+
+    /// (default: `0.5`) The probability of having Build Metadata, from `0.0`
+    /// (never) to `1.0` (always).
+    pub fn build_metadata_probability(mut self, probability: f64) -> Self {
+        self.probability_of_build_metadata = probability;
+        self
+    }
+
+    /// (default: unbounded) The number of identifiers in Build Metadata, when
+    /// there is some, such as `1..3`.
+    ///
+    /// Bounding any of the number, length or characters of identifiers
+    /// bounds them all, with the others at their defaults of `1..4`
+    /// identifiers of `1..9` [IdentifierCharset::Alphanumeric] characters.
+    pub fn build_metadata_identifiers(mut self, count: impl Into<SizeRange>) -> Self {
+        self.build_metadata_bounds().identifiers = count.into();
+        self
+    }
+
+    /// (default: unbounded) The length of each identifier of Build Metadata,
+    /// such as `1..8`.
+    pub fn build_metadata_identifier_len(mut self, len: impl Into<SizeRange>) -> Self {
+        self.build_metadata_bounds().identifier_len = len.into();
+        self
+    }
+
+    /// (default: [IdentifierCharset::Alphanumeric]) The characters of each
+    /// identifier of Build Metadata, such as [IdentifierCharset::Hex] for git
+    /// commit hashes.
+    pub fn build_metadata_charset(mut self, charset: IdentifierCharset) -> Self {
+        self.build_metadata_bounds().charset = charset;
+        self
+    }
+
+    /// Bounds Build Metadata by `params` (such as ones loaded with the
+    /// `serde` feature), keeping the [VersionStrategy::build_metadata_charset].
+    pub fn build_metadata_params(mut self, params: BuildMetadataParams) -> Self {
+        let bounds = self.build_metadata_bounds();
+        bounds.identifiers = params.identifiers;
+        bounds.identifier_len = params.identifier_len;
+        self
+    }
+
+    fn build_metadata_bounds(&mut self) -> &mut BuildMetadataBounds {
+        self.build_metadata
+            .get_or_insert_with(|| BuildMetadataBounds {
+                identifiers: (1..4).into(),
+                identifier_len: DEFAULT_IDENTIFIER_LEN.into(),
+                charset: IdentifierCharset::default(),
+            })
+    }
+
+    /// Whether to ever have Build Metadata, short for
+    /// [VersionStrategy::build_metadata_probability] of `0.0`, or the
+    /// default.
+    pub fn build_metadata(self, enabled: bool) -> Self {
+        if enabled {
+            self.build_metadata_probability(DEFAULT_PROBABILITY_OF_BUILD_METADATA)
+        } else {
+            self.build_metadata_probability(0.0)
+        }
+    }
+
+    /// Provides the [Strategy].
+    pub fn build(self) -> BoxedStrategy<Version> {
+        let pre_release = match self.pre_release {
+            Some(params) => any_with::<ArbPrerelease>(params)
+                .prop_map(Prerelease::from)
+                .boxed(),
+            None => arb_semver_prerelease().boxed(),
+        };
+        let build_metadata = match self.build_metadata {
+            Some(bounds) => arb_build_metadata_identifiers(
+                bounds.identifiers,
+                bounds.identifier_len,
+                bounds.charset,
+            )
+            .boxed(),
+            None => arb_semver_build_metadata().boxed(),
+        };
+
+        (
+            self.major,
+            self.minor,
+            self.patch,
+            arb_sometimes(self.probability_of_pre_release, pre_release),
+            arb_sometimes(self.probability_of_build_metadata, build_metadata),
+        )
+            .prop_map(|(major, minor, patch, pre, build)| Version {
+                major,
+                minor,
+                patch,
+                pre: pre.unwrap_or(Prerelease::EMPTY),
+                build: build.unwrap_or(BuildMetadata::EMPTY),
+            })
+            .boxed()
+    }
+}
+
+/// The values of `range` as an inclusive range, panicking when it's empty.
+fn inclusive(range: impl RangeBounds<u64>) -> RangeInclusive<u64> {
+    let start = match range.start_bound() {
+        Bound::Included(n) => Some(*n),
+        Bound::Excluded(n) => n.checked_add(1),
+        Bound::Unbounded => Some(0),
+    };
+    let end = match range.end_bound() {
+        Bound::Included(n) => Some(*n),
+        Bound::Excluded(n) => n.checked_sub(1),
+        Bound::Unbounded => Some(u64::MAX),
+    };
+
+    match (start, end) {
+        (Some(start), Some(end)) if start <= end => start..=end,
+        _ => panic!("a component range must not be empty"),
+    }
+}
+
+/// Provides versions with each of `MAJOR`, `MINOR` and `PATCH` within a
+/// range, such as `0..=3` or `..`, and otherwise like
+/// [crate::arb_semver_version] (see [VersionStrategy] for more control).
+///
+/// * `major` - The range of `MAJOR`, which must not be empty.
+/// * `minor` - The range of `MINOR`, which must not be empty.
+/// * `patch` - The range of `PATCH`, which must not be empty.
+pub fn arb_version_with_components(
+    major: impl RangeBounds<u64>,
+    minor: impl RangeBounds<u64>,
+    patch: impl RangeBounds<u64>,
+) -> BoxedStrategy<Version> {
+    VersionStrategy::new()
+        .major(inclusive(major))
+        .minor(inclusive(minor))
+        .patch(inclusive(patch))
+        .build()
+}
+
+/// A component of a [arb_realistic_version], mostly single digits.
+fn arb_realistic_component() -> impl Strategy<Value = u64> {
+    prop_oneof![
+        6 => 0..5u64,
+        3 => 5..=30u64,
+        1 => 31..200u64,
+    ]
+}
+
+/// A Pre-Release of a [arb_realistic_version], such as `alpha`, `beta.2` or
+/// `rc.1`.
+fn arb_realistic_pre_release() -> impl Strategy<Value = Prerelease> {
+    (
+        prop::sample::select(vec!["alpha", "beta", "rc", "pre", "dev"]),
+        prop::option::weighted(0.75, 0..10u64),
+    )
+        .prop_map(|(tag, n)| match n {
+            Some(n) => Prerelease::new(&format!("{tag}.{n}")).unwrap(),
+            None => Prerelease::new(tag).unwrap(),
+        })
+}
+
+/// Build Metadata of a [arb_realistic_version], such as a short commit hash
+/// or a build number.
+fn arb_realistic_build_metadata() -> impl Strategy<Value = BuildMetadata> {
+    prop_oneof![
+        "[0-9a-f]{7}",
+        (1..1000u64).prop_map(|n| format!("build.{n}")),
+        "(20[0-9]{2})(0[1-9]|1[0-2])(0[1-9]|1[0-9]|2[0-8])",
+    ]
+    .prop_map(|build| BuildMetadata::new(&build).unwrap())
+}
+
+prop_compose! {
+    /// Provides versions that look like those actually published to
+    /// registries like crates.io or npm, for readable and representative test
+    /// fixtures.
+    ///
+    /// Components are mostly below `30` (and usually single digits), a fifth
+    /// have a short Pre-Release (such as `alpha`, `beta.2` or `rc.1`), and a
+    /// few have Build Metadata (such as a short commit hash).
+    pub fn arb_realistic_version()(
+        major in arb_realistic_component(),
+        minor in arb_realistic_component(),
+        patch in arb_realistic_component(),
+        pre in prop::option::weighted(0.2, arb_realistic_pre_release()),
+        build in prop::option::weighted(0.05, arb_realistic_build_metadata()),
+    ) -> Version {
+        Version {
+            major,
+            minor,
+            patch,
+            pre: pre.unwrap_or(Prerelease::EMPTY),
+            build: build.unwrap_or(BuildMetadata::EMPTY),
+        }
+    }
+}
@@ -0,0 +1,70 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+//!
+//! Strategies for [semver::Comparator] built directly as structs, limited to
+//! what parsing a string can actually produce.
+use proptest::prelude::*;
+use semver::{Comparator, Op, Prerelease};
+
+use crate::{arb_option_semver_prerelease, arb_semver_op};
+
+/// Returns `true` if `c` has a combination of fields that parsing some string
+/// can produce.
+///
+/// The struct fields of a [semver::Comparator] are public, and allow
+/// combinations that parsing never will:
+///
+/// * A `patch` without a `minor`.
+/// * A Pre-Release without a `patch`.
+/// * A `patch` with [semver::Op::Wildcard], which only comes from a `*` in the
+///   `minor` or `patch` position.
+pub fn is_parse_reachable(c: &Comparator) -> bool {
+    match (c.minor, c.patch) {
+        (None, Some(_)) => false,
+        (_, None) if !c.pre.is_empty() => false,
+        (_, Some(_)) if c.op == Op::Wildcard => false,
+        _ => true,
+    }
+}
+
+/// Provides a [semver::Comparator] via the struct itself, with only the field
+/// combinations that parsing some string can produce (see
+/// [is_parse_reachable]).
+///
+/// Unlike [crate::arb_semver_comparator], downstream code fuzzed with these
+/// never sees a comparator it couldn't get from [semver::VersionReq::parse].
+pub fn arb_semver_comparator_consistent() -> impl Strategy<Value = Comparator> {
+    (
+        arb_semver_op(None, None),
+        any::<u64>(),
+        any::<Option<u64>>(),
+        any::<Option<u64>>(),
+        arb_option_semver_prerelease(0.5),
+    )
+        .prop_map(|(op, major, minor, patch, pre)| {
+            // Each component is only present if the one before it is, and a
+            // wildcard always stands in for the `patch`.
+            let patch = minor.and(patch).filter(|_| op != Op::Wildcard);
+            let pre = patch.and(pre).unwrap_or(Prerelease::EMPTY);
+
+            Comparator {
+                op,
+                major,
+                minor,
+                patch,
+                pre,
+            }
+        })
+}
@@ -0,0 +1,98 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+//!
+//! Zero argument strategies as constants, for attribute positions (such as
+//! `#[strategy(...)]` from the `test-strategy` crate) and `static`s, where
+//! calling a strategy function with arguments is awkward or impossible.
+//!
+//! ```
+//! use proptest::strategy::{Strategy, ValueTree};
+//! use proptest::test_runner::TestRunner;
+//! use proptest_semver::{ConstStrategy, VERSION};
+//!
+//! static VERSIONS: ConstStrategy<semver::Version> = VERSION;
+//!
+//! let mut runner = TestRunner::deterministic();
+//! let v = VERSIONS.new_tree(&mut runner).unwrap().current();
+//! assert_eq!(semver::Version::parse(&v.to_string()).unwrap(), v);
+//! ```
+use proptest::prelude::*;
+use proptest::strategy::{NewTree, ValueTree};
+use proptest::test_runner::TestRunner;
+use semver::{BuildMetadata, Comparator, Prerelease, Version, VersionReq};
+use std::fmt;
+
+use crate::{
+    arb_semver_build_metadata, arb_semver_comparator_consistent, arb_semver_prerelease,
+    arb_version, arb_version_req,
+};
+
+/// The maximum number of comparators of a [VERSION_REQ].
+const DEFAULT_MAX_COMPARATORS: usize = 4;
+
+/// A [proptest::strategy::Strategy] that defers to a strategy function taking
+/// no arguments, so it can be built in a `const`.
+pub struct ConstStrategy<T>(fn() -> BoxedStrategy<T>);
+
+// NOTE(canardleteer): Derived `Clone` and `Copy` would require the same of
+//                     `T`, which a function pointer doesn't need.
+impl<T> Clone for ConstStrategy<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ConstStrategy<T> {}
+
+impl<T> ConstStrategy<T> {
+    /// Wraps a strategy function taking no arguments.
+    pub const fn new(strategy: fn() -> BoxedStrategy<T>) -> Self {
+        Self(strategy)
+    }
+}
+
+impl<T> fmt::Debug for ConstStrategy<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ConstStrategy").finish()
+    }
+}
+
+impl<T: fmt::Debug> Strategy for ConstStrategy<T> {
+    type Tree = Box<dyn ValueTree<Value = T>>;
+    type Value = T;
+
+    fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+        (self.0)().new_tree(runner)
+    }
+}
+
+/// Like [crate::arb_version].
+pub const VERSION: ConstStrategy<Version> = ConstStrategy::new(|| arb_version().boxed());
+
+/// Like [crate::arb_version_req], of up to `4` comparators.
+pub const VERSION_REQ: ConstStrategy<VersionReq> =
+    ConstStrategy::new(|| arb_version_req(DEFAULT_MAX_COMPARATORS).boxed());
+
+/// Like [crate::arb_semver_comparator_consistent].
+pub const COMPARATOR: ConstStrategy<Comparator> =
+    ConstStrategy::new(|| arb_semver_comparator_consistent().boxed());
+
+/// Like [crate::arb_semver_prerelease].
+pub const PRERELEASE: ConstStrategy<Prerelease> =
+    ConstStrategy::new(|| arb_semver_prerelease().boxed());
+
+/// Like [crate::arb_semver_build_metadata].
+pub const BUILD_METADATA: ConstStrategy<BuildMetadata> =
+    ConstStrategy::new(|| arb_semver_build_metadata().boxed());
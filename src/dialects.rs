@@ -0,0 +1,261 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+//!
+//! Range strings across the requirement dialects of different ecosystems,
+//! tagged with which dialects accept them, for driving compatibility matrices.
+use proptest::prelude::*;
+use semver::{BuildMetadata, Prerelease, Version};
+
+use crate::{arb_option_pre_release_string, arb_semver_build_metadata, arb_version};
+
+/// An ecosystem's dialect of version ranges.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Dialect {
+    /// Cargo, as parsed by [semver::VersionReq::parse].
+    Cargo,
+
+    /// npm's `node-semver` ranges, such as `>=1.2.3 <2.0.0 || 3.x`.
+    Npm,
+
+    /// RubyGems requirements, such as `~> 1.2`.
+    Gem,
+
+    /// Maven version ranges, such as `[1.0.0,2.0.0)`.
+    Maven,
+}
+
+/// A pair of release versions, where the first never has a higher precedence
+/// than the second.
+fn arb_ordered_releases() -> impl Strategy<Value = (Version, Version)> {
+    let release = (0..100u64, 0..100u64, 0..100u64)
+        .prop_map(|(major, minor, patch)| Version::new(major, minor, patch));
+    (release.clone(), release).prop_map(|(a, b)| if a <= b { (a, b) } else { (b, a) })
+}
+
+/// Provides a range string, alongside every [Dialect] which accepts it (which
+/// may be only one), for driving a compatibility matrix from a single
+/// property.
+///
+/// Ranges only name release versions, with small components, since not every
+/// dialect has the same idea of a Pre-Release. Whether a dialect accepts a
+/// range is by syntax alone; bounds are always ordered, but a dialect may
+/// still consider a range empty.
+pub fn arb_dialect_tagged_range() -> impl Strategy<Value = (String, Vec<Dialect>)> {
+    use Dialect::*;
+
+    (arb_ordered_releases(), 0..15u8).prop_map(|((a, b), shape)| {
+        let (range, dialects): (String, &[Dialect]) = match shape {
+            0 => (a.to_string(), &[Cargo, Npm, Gem, Maven]),
+            1 => (
+                format!("{}.{}", a.major, a.minor),
+                &[Cargo, Npm, Gem, Maven],
+            ),
+            2 => (format!("^{a}"), &[Cargo, Npm]),
+            3 => (format!("~{a}"), &[Cargo, Npm]),
+            4 => (format!("~> {}.{}", a.major, a.minor), &[Gem]),
+            5 => (format!(">= {a}"), &[Cargo, Npm, Gem]),
+            6 => (format!(">={a} <{b}"), &[Npm]),
+            7 => (format!(">={a}, <{b}"), &[Cargo]),
+            8 => (format!("{a} - {b}"), &[Npm]),
+            9 => (format!("^{a} || ^{b}"), &[Npm]),
+            10 => (format!("{}.x", a.major), &[Cargo, Npm]),
+            11 => ("*".to_string(), &[Cargo, Npm]),
+            12 => (format!("[{a},{b})"), &[Maven]),
+            13 => (format!("(,{b}]"), &[Maven]),
+            _ => (format!("!= {a}"), &[Gem]),
+        };
+        (range, dialects.to_vec())
+    })
+}
+
+/// A `bazel_dep` from a Bazel `MODULE.bazel` file, and how it's version is
+/// expected to be interpreted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BazelModuleDep {
+    /// The snippet, such as `bazel_dep(name = "rules_cc", version = "0.0.9")`.
+    pub snippet: String,
+
+    /// The module name.
+    pub name: String,
+
+    /// The version string, which is empty when the snippet has no `version`.
+    pub version: String,
+
+    /// The dot separated identifiers of the release part of the version.
+    pub release: Vec<String>,
+
+    /// The dot separated identifiers of the Pre-Release, if any.
+    pub pre_release: Vec<String>,
+
+    /// The version as Cargo would parse it, which is only `Some` when the
+    /// version is also strict SemVer.
+    pub semver: Option<Version>,
+}
+
+/// A release part in Bazel's relaxed dialect, which is any number of
+/// identifiers (such as `20230125.3` or `1.2.3.4`), alongside whether it's
+/// also the `MAJOR.MINOR.PATCH` of strict SemVer.
+fn arb_bazel_release() -> impl Strategy<Value = (Vec<String>, Option<(u64, u64, u64)>)> {
+    let numeric = prop_oneof![
+        (0..100u64).prop_map(|n| n.to_string()),
+        (20000101..20991231u64).prop_map(|n| n.to_string()),
+    ];
+    let identifier = prop_oneof![
+        3 => numeric,
+        1 => "[a-z][a-z0-9]{0,5}",
+    ];
+    prop_oneof![
+        (0..100u64, 0..100u64, 0..100u64).prop_map(|(major, minor, patch)| (
+            vec![major.to_string(), minor.to_string(), patch.to_string()],
+            Some((major, minor, patch))
+        )),
+        prop::collection::vec(identifier, 1..6).prop_map(|release| {
+            let triple = match release.as_slice() {
+                [major, minor, patch] => major
+                    .parse()
+                    .and_then(|major| Ok((major, minor.parse()?, patch.parse()?)))
+                    .ok(),
+                _ => None,
+            };
+            (release, triple)
+        }),
+    ]
+}
+
+/// Provides a `bazel_dep(name = "...", version = "...")` snippet from a Bazel
+/// `MODULE.bazel` file, alongside it's expected interpretation, for testing
+/// tooling which bridges build systems to Cargo semantics.
+///
+/// Versions are in Bazel's relaxed dialect, where the release part may have
+/// any number of identifiers, not all of them numeric, so only some of them
+/// are also strict SemVer. Occasionally, the version is omitted entirely.
+pub fn arb_bazel_module_dep() -> impl Strategy<Value = BazelModuleDep> {
+    let version = (
+        arb_bazel_release(),
+        arb_option_pre_release_string(0.25),
+        prop::option::weighted(0.1, arb_semver_build_metadata()),
+    );
+    (
+        "[a-z]([a-z0-9._-]{0,30}[a-z0-9])?",
+        prop::option::weighted(0.9, version),
+    )
+        .prop_map(|(name, version)| {
+            let Some(((release, triple), pre, build)) = version else {
+                return BazelModuleDep {
+                    snippet: format!("bazel_dep(name = \"{name}\")"),
+                    name,
+                    version: String::new(),
+                    release: Vec::new(),
+                    pre_release: Vec::new(),
+                    semver: None,
+                };
+            };
+
+            let mut version = release.join(".");
+            if let Some(pre) = &pre {
+                version.push('-');
+                version.push_str(pre);
+            }
+            if let Some(build) = &build {
+                version.push('+');
+                version.push_str(build.as_str());
+            }
+            let pre = pre.map_or(Prerelease::EMPTY, |pre| Prerelease::new(&pre).unwrap());
+            let semver = triple.map(|(major, minor, patch)| Version {
+                major,
+                minor,
+                patch,
+                pre: pre.clone(),
+                build: build.unwrap_or(BuildMetadata::EMPTY),
+            });
+
+            BazelModuleDep {
+                snippet: format!("bazel_dep(name = \"{name}\", version = \"{version}\")"),
+                name,
+                version,
+                release,
+                pre_release: pre
+                    .as_str()
+                    .split('.')
+                    .filter(|id| !id.is_empty())
+                    .map(String::from)
+                    .collect(),
+                semver,
+            }
+        })
+}
+
+/// Provides strings which parse as a [semver::VersionReq], but not as a
+/// [semver::Version], for testing APIs which accept "a version or a range"
+/// on the cases only one of them accepts.
+///
+/// Strings are just a `MAJOR` or `MAJOR.MINOR` (such as `1.2`), a version
+/// with an operator (such as `^1.2.3` or `>= 1.2.3-alpha`), a wildcard (such
+/// as `1.*` or `*`), more than one comparator, or a version surrounded by
+/// spaces.
+pub fn arb_req_only_string() -> impl Strategy<Value = String> {
+    let ops = prop::sample::select(vec!["=", ">", ">=", "<", "<=", "~", "^"]);
+    let wildcards = prop::sample::select(vec!["*", "x", "X"]);
+
+    prop_oneof![
+        (0..100u64).prop_map(|major| major.to_string()),
+        (0..100u64, 0..100u64).prop_map(|(major, minor)| format!("{major}.{minor}")),
+        (ops.clone(), any::<bool>(), arb_version()).prop_map(|(op, space, v)| {
+            let space = if space { " " } else { "" };
+            format!("{op}{space}{v}")
+        }),
+        (ops.clone(), 0..100u64, prop::option::of(0..100u64)).prop_map(|(op, major, minor)| {
+            match minor {
+                Some(minor) => format!("{op}{major}.{minor}"),
+                None => format!("{op}{major}"),
+            }
+        }),
+        (wildcards.clone(), 0..100u64, prop::option::of(0..100u64)).prop_map(
+            |(wildcard, major, minor)| match minor {
+                Some(minor) => format!("{major}.{minor}.{wildcard}"),
+                None => format!("{major}.{wildcard}"),
+            }
+        ),
+        wildcards.prop_map(String::from),
+        (ops.clone(), arb_version(), ops, arb_version())
+            .prop_map(|(a_op, a, b_op, b)| format!("{a_op}{a}, {b_op}{b}")),
+        (" {1,2}", arb_version(), " {0,2}")
+            .prop_map(|(before, v, after)| format!("{before}{v}{after}")),
+    ]
+}
+
+/// Provides strings with Build Metadata which parse as a [semver::Version],
+/// and which [semver::VersionReq] also accepts, but silently discards the
+/// Build Metadata of.
+///
+/// NOTE(canardleteer): There's no string `semver` accepts as a version, but
+///                     rejects as a requirement, since a comparator parses
+///                     (and then ignores) Build Metadata just like a version
+///                     does. The asymmetry left to test is in meaning: a
+///                     string like `1.2.3+build` is a version with Build
+///                     Metadata, but the requirement `^1.2.3`.
+pub fn arb_build_discarding_string() -> impl Strategy<Value = String> {
+    (
+        0..100u64,
+        0..100u64,
+        0..100u64,
+        arb_option_pre_release_string(0.25),
+        arb_semver_build_metadata(),
+    )
+        .prop_map(|(major, minor, patch, pre, build)| {
+            let pre = pre.map(|pre| format!("-{pre}")).unwrap_or_default();
+            format!("{major}.{minor}.{patch}{pre}+{build}")
+        })
+}
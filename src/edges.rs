@@ -0,0 +1,242 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+//!
+//! Versions at the edges of what's easy to get wrong.
+use proptest::prelude::*;
+use semver::{BuildMetadata, Prerelease, Version};
+
+use crate::{
+    arb_identifier, arb_option_build_metadata_string, arb_pre_release_string, arb_version,
+};
+
+/// A curated corpus of valid version strings at the edges of the spec, and of
+/// the `semver` crate, ordered roughly from most to least commonly mishandled.
+pub const EDGE_CASE_VERSIONS: &[&str] = &[
+    "0.0.0",
+    "1.0.0",
+    "0.0.1",
+    "0.1.0",
+    "1.0.0-0",
+    "1.0.0-alpha",
+    "1.0.0+build",
+    "1.0.0-alpha+build",
+    "18446744073709551615.18446744073709551615.18446744073709551615",
+    "1.0.0-alpha.1",
+    "1.0.0-alpha.beta",
+    "1.0.0-beta.11",
+    "1.0.0-rc.1",
+    "1.0.0--",
+    "1.0.0---a",
+    "1.0.0-0a",
+    "1.0.0-a-",
+    "1.0.0-18446744073709551616",
+    "1.0.0+00",
+    "1.0.0+-",
+    "1.0.0-0+0",
+    "1.0.0-RC.1",
+    "1.0.0-rc.1",
+    "10.20.30",
+    "1.2.3-0.0.0",
+    "1.2.3+a.b.c.d",
+    "99999999999999999.0.0",
+    "0.0.0-0.0",
+];
+
+/// A component that renders at an edge: `0`, [u64::MAX] (the most digits), or
+/// just around a power of ten (where the number of digits changes).
+fn arb_display_edge_component() -> impl Strategy<Value = u64> {
+    prop_oneof![
+        Just(0u64),
+        Just(u64::MAX),
+        (0u32..20).prop_map(|k| 10u64.pow(k)),
+        (1u32..20).prop_map(|k| 10u64.pow(k) - 1),
+    ]
+}
+
+prop_compose! {
+    /// Provides versions whose rendering is easy to get wrong in custom
+    /// formatters, such as:
+    ///
+    /// * The all zero `0.0.0`.
+    /// * Components at [u64::MAX], or just around a power of ten.
+    /// * A Pre-Release of a single, often single character, identifier (such as
+    ///   `1.0.0-0` or `1.0.0--`).
+    /// * Build Metadata without a Pre-Release, and with a leading zero (such as
+    ///   `1.0.0+00`).
+    ///
+    /// Each of Pre-Release and Build Metadata is as likely to be empty as not.
+    pub fn arb_display_edge_versions()(
+        zero in prop::bool::weighted(0.25),
+        major in arb_display_edge_component(),
+        minor in arb_display_edge_component(),
+        patch in arb_display_edge_component(),
+        pre in prop_oneof![
+            2 => Just(String::new()),
+            1 => prop::sample::select(vec!["0", "1", "a", "Z", "-"]).prop_map(String::from),
+            1 => arb_identifier(None, None),
+        ],
+        build in prop_oneof![
+            2 => Just(String::new()),
+            1 => prop::sample::select(vec!["0", "00", "-", "a.0"]).prop_map(String::from),
+            1 => arb_identifier(None, None),
+        ],
+    ) -> Version {
+        let (major, minor, patch) = if zero { (0, 0, 0) } else { (major, minor, patch) };
+        Version {
+            major,
+            minor,
+            patch,
+            pre: Prerelease::new(&pre).unwrap(),
+            build: BuildMetadata::new(&build).unwrap(),
+        }
+    }
+}
+
+/// A component at a boundary: `0`, `1`, or at or just below [u64::MAX].
+fn arb_boundary_component() -> impl Strategy<Value = u64> {
+    prop::sample::select(vec![0, 1, u64::MAX - 1, u64::MAX])
+}
+
+/// A Pre-Release at a boundary: empty, a single character, confusable with a
+/// number (`0`, `-0` or `0a`), or at the limits of a numeric identifier.
+fn arb_boundary_pre_release() -> impl Strategy<Value = Prerelease> {
+    prop_oneof![
+        prop::sample::select(vec![
+            "",
+            "0",
+            "1",
+            "a",
+            "Z",
+            "-",
+            "-0",
+            "0a",
+            "0.0",
+            "18446744073709551615",
+            "18446744073709551616",
+        ])
+        .prop_map(|pre| pre.to_string()),
+        // Long, but far below anything that would make a test slow.
+        (1..=64usize).prop_map(|len| "z".repeat(len)),
+        (1..=16usize).prop_map(|len| vec!["0"; len].join(".")),
+    ]
+    .prop_map(|pre| Prerelease::new(&pre).unwrap())
+}
+
+/// Provides versions weighted heavily toward boundary values, for catching
+/// regressions in comparison and rendering code:
+///
+/// * Every version of [EDGE_CASE_VERSIONS].
+/// * Components of `0`, `1`, or at or just below [u64::MAX] (such as
+///   `0.0.0`), with a Pre-Release that is empty, a single character,
+///   confusable with a number (such as `-0`), or long.
+/// * Occasionally, any version at all (see [crate::arb_version]).
+pub fn arb_boundary_version() -> impl Strategy<Value = Version> {
+    let edge_case =
+        prop::sample::select(EDGE_CASE_VERSIONS).prop_map(|v| Version::parse(v).unwrap());
+    let boundary = (
+        arb_boundary_component(),
+        arb_boundary_component(),
+        arb_boundary_component(),
+        arb_boundary_pre_release(),
+    )
+        .prop_map(|(major, minor, patch, pre)| Version {
+            major,
+            minor,
+            patch,
+            pre,
+            build: BuildMetadata::EMPTY,
+        });
+
+    prop_oneof![
+        4 => edge_case,
+        4 => boundary,
+        1 => arb_version(),
+    ]
+}
+
+/// A numeric identifier greater than [u64::MAX], which is often only just
+/// greater.
+fn arb_overflowing_number() -> impl Strategy<Value = String> {
+    let over = u64::MAX as u128 + 1;
+    prop_oneof![
+        (over..over + 1024).prop_map(|n| n.to_string()),
+        (over..=u128::MAX).prop_map(|n| n.to_string()),
+    ]
+}
+
+/// Joins the parts of a version string.
+fn version_string(core: &[String], pre: &[String], build: Option<String>) -> String {
+    let mut s = core.join(".");
+    if !pre.is_empty() {
+        s.push_str(&format!("-{}", pre.join(".")));
+    }
+    if let Some(build) = build {
+        s.push_str(&format!("+{build}"));
+    }
+    s
+}
+
+/// Provides version strings which are valid by the spec, but where one of
+/// `MAJOR`, `MINOR` or `PATCH` is greater than [u64::MAX], for
+/// deterministically hitting the overflow error of the `semver` crate (and
+/// other parsers with fixed width integers).
+///
+/// A Pre-Release is present half of the time, and Build Metadata a quarter of
+/// the time. See [arb_overflowing_pre_release_string] for an overflow the
+/// `semver` crate accepts.
+pub fn arb_overflowing_semver_string() -> impl Strategy<Value = String> {
+    (
+        any::<[u64; 3]>(),
+        0..3usize,
+        arb_overflowing_number(),
+        prop::option::of(arb_pre_release_string()),
+        arb_option_build_metadata_string(0.25),
+    )
+        .prop_map(|(core, at, number, pre, build)| {
+            let mut core = core.map(|c| c.to_string());
+            core[at] = number;
+            let pre = match pre {
+                Some(pre) => pre.split('.').map(String::from).collect::<Vec<_>>(),
+                None => Vec::new(),
+            };
+            version_string(&core, &pre, build)
+        })
+}
+
+/// Provides version strings which are valid by the spec, but where a numeric
+/// Pre-Release identifier is greater than [u64::MAX], for testing parsers
+/// with fixed width integers.
+///
+/// Unlike [arb_overflowing_semver_string], the `semver` crate accepts these,
+/// since it compares numeric Pre-Release identifiers by their digits. Build
+/// Metadata is present a quarter of the time.
+pub fn arb_overflowing_pre_release_string() -> impl Strategy<Value = String> {
+    (
+        any::<[u64; 3]>(),
+        arb_overflowing_number(),
+        prop::option::of(arb_pre_release_string()),
+        any::<prop::sample::Index>(),
+        arb_option_build_metadata_string(0.25),
+    )
+        .prop_map(|(core, number, pre, i, build)| {
+            let core = core.map(|c| c.to_string());
+            let mut pre = match pre {
+                Some(pre) => pre.split('.').map(String::from).collect::<Vec<_>>(),
+                None => Vec::new(),
+            };
+            pre.insert(i.index(pre.len() + 1), number);
+            version_string(&core, &pre, build)
+        })
+}
@@ -0,0 +1,499 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+//!
+//! Versions alongside encodings of them meant for storage or transport outside
+//! of `semver`, such as in a database column.
+use proptest::prelude::*;
+use semver::{BuildMetadata, Version, VersionReq};
+use std::fmt;
+
+use crate::{
+    arb_crate_name, arb_full_comparator, arb_option_semver_build_metadata, arb_separator_faults,
+    arb_version, arb_version_req,
+};
+
+/// The number of digits in [u64::MAX].
+const U64_DIGITS: usize = 20;
+
+/// The zero-padded, fixed-width key of a version's `MAJOR.MINOR.PATCH`, such
+/// as `00000000000000000001.00000000000000000002.00000000000000000003` for
+/// `1.2.3`.
+///
+/// The lexicographic order of keys matches the precedence of release
+/// versions. Pre-Release and Build Metadata are not part of the key.
+pub fn sortable_key(version: &Version) -> String {
+    format!(
+        "{:0w$}.{:0w$}.{:0w$}",
+        version.major,
+        version.minor,
+        version.patch,
+        w = U64_DIGITS
+    )
+}
+
+/// A component, biased toward small numbers, so that keys often share a
+/// prefix.
+fn arb_component() -> impl Strategy<Value = u64> {
+    prop_oneof![0u64..10, any::<u64>()]
+}
+
+prop_compose! {
+    /// Provides a release version, sometimes with Build Metadata, alongside
+    /// it's [sortable_key], for testing database sorting schemes against
+    /// semver precedence.
+    pub fn arb_version_with_sortable_key()(
+        major in arb_component(),
+        minor in arb_component(),
+        patch in arb_component(),
+        build in arb_option_semver_build_metadata(0.25),
+    ) -> (Version, String) {
+        let mut v = Version::new(major, minor, patch);
+        v.build = build.unwrap_or(BuildMetadata::EMPTY);
+        let key = sortable_key(&v);
+        (v, key)
+    }
+}
+
+/// How a package is pinned by an entry of a version-pinning policy.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PinPolicy {
+    /// Exactly one version, written as `=MAJOR.MINOR.PATCH<-Pre-Release>`.
+    Exact(Version),
+
+    /// Any version matching a requirement.
+    Range(VersionReq),
+
+    /// Every version, written as `*`.
+    Wildcard,
+}
+
+impl PinPolicy {
+    /// The requirement this policy is interpreted as.
+    pub fn version_req(&self) -> VersionReq {
+        match self {
+            PinPolicy::Exact(v) => VersionReq::parse(&format!("={v}")).unwrap(),
+            PinPolicy::Range(req) => req.clone(),
+            PinPolicy::Wildcard => VersionReq::STAR,
+        }
+    }
+}
+
+impl fmt::Display for PinPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PinPolicy::Exact(v) => write!(f, "={v}"),
+            PinPolicy::Range(req) => write!(f, "{req}"),
+            PinPolicy::Wildcard => write!(f, "*"),
+        }
+    }
+}
+
+/// A single entry of a version-pinning policy.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PolicyEntry {
+    /// The name of the package, from [arb_crate_name].
+    pub name: String,
+
+    pub policy: PinPolicy,
+}
+
+/// The format of a config fragment.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConfigFormat {
+    Toml,
+    Json,
+}
+
+/// Provides a [PinPolicy], mixing exact versions, ranges, and wildcards.
+pub fn arb_pin_policy() -> impl Strategy<Value = PinPolicy> {
+    prop_oneof![
+        3 => arb_version().prop_map(|mut v| {
+            // Build Metadata is ignored by requirements.
+            v.build = BuildMetadata::EMPTY;
+            PinPolicy::Exact(v)
+        }),
+        3 => prop::collection::vec(arb_full_comparator(None, None, None), 1..=3).prop_map(|c| {
+            let c = c.iter().map(|c| c.to_string()).collect::<Vec<_>>();
+            PinPolicy::Range(VersionReq::parse(&c.join(", ")).unwrap())
+        }),
+        1 => Just(PinPolicy::Wildcard),
+    ]
+}
+
+/// Provides a config fragment for a version-pinning policy (like the ban list
+/// of a `deny.toml`), with a list of entries under each of `keys`, alongside
+/// the structured interpretation of each list.
+///
+/// In TOML, each key is a top level array of inline tables:
+///
+/// ```toml
+/// deny = [
+///     { name = "foo", version = "=1.2.3" },
+///     { name = "bar", version = ">=1.0.0, <2.0.0" },
+/// ]
+/// ```
+///
+/// In JSON, each key is a member of a single object, with an array of
+/// objects with the same `name` and `version` members.
+///
+/// * `format` - The format of the fragment.
+/// * `keys` - The keys, which must be bare TOML keys (ASCII alphanumerics,
+///   `-` and `_`).
+/// * `max_entries` - The maximum number of entries under each key.
+pub fn arb_policy_config(
+    format: ConfigFormat,
+    keys: &[&str],
+    max_entries: usize,
+) -> impl Strategy<Value = (String, Vec<(String, Vec<PolicyEntry>)>)> {
+    for key in keys {
+        assert!(
+            !key.is_empty()
+                && key
+                    .bytes()
+                    .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_'),
+            "`{key}` is not a bare key"
+        );
+    }
+
+    let entry = (arb_crate_name(), arb_pin_policy())
+        .prop_map(|(name, policy)| PolicyEntry { name, policy })
+        .boxed();
+    let lists = keys
+        .iter()
+        .map(|key| {
+            (
+                Just(key.to_string()),
+                prop::collection::vec(entry.clone(), 0..=max_entries),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    lists.prop_map(move |lists: Vec<(String, Vec<PolicyEntry>)>| {
+        (policy_config(format, &lists), lists)
+    })
+}
+
+fn policy_config(format: ConfigFormat, lists: &[(String, Vec<PolicyEntry>)]) -> String {
+    let mut out = String::new();
+    match format {
+        ConfigFormat::Toml => {
+            for (key, entries) in lists {
+                out.push_str(&format!("{key} = [\n"));
+                for e in entries {
+                    out.push_str(&format!(
+                        "    {{ name = \"{}\", version = \"{}\" }},\n",
+                        e.name, e.policy
+                    ));
+                }
+                out.push_str("]\n");
+            }
+        }
+        ConfigFormat::Json => {
+            let members = lists
+                .iter()
+                .map(|(key, entries)| {
+                    let entries = entries
+                        .iter()
+                        .map(|e| {
+                            format!(
+                                "{{\"name\": \"{}\", \"version\": \"{}\"}}",
+                                e.name, e.policy
+                            )
+                        })
+                        .collect::<Vec<_>>();
+                    format!("\"{key}\": [{}]", entries.join(", "))
+                })
+                .collect::<Vec<_>>();
+            out.push_str(&format!("{{{}}}", members.join(", ")));
+        }
+    }
+    out
+}
+
+/// The syntax a version is passed to a program in, by
+/// [arb_cli_version_flag].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CliSyntax {
+    /// `--NAME=VALUE`
+    FlagEquals,
+
+    /// `--NAME VALUE`
+    FlagSpace,
+
+    /// `NAME=VALUE`, as an environment variable.
+    EnvVar,
+}
+
+/// What the value of a [CliVersionFlag] is expected to parse to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CliVersionValue {
+    /// A complete version, such as `1.4.0-rc.1`.
+    Version(Version),
+
+    /// Not a complete version, but a valid requirement, such as `1.2` or
+    /// `>=1.2`.
+    Req(VersionReq),
+
+    /// Neither a version nor a requirement.
+    Invalid,
+}
+
+impl CliVersionValue {
+    /// Interprets `value` as a [semver::Version], or failing that as a
+    /// [semver::VersionReq].
+    pub fn parse(value: &str) -> Self {
+        if let Ok(v) = Version::parse(value) {
+            CliVersionValue::Version(v)
+        } else if let Ok(req) = VersionReq::parse(value) {
+            CliVersionValue::Req(req)
+        } else {
+            CliVersionValue::Invalid
+        }
+    }
+}
+
+/// A version passed to a program as a flag or environment variable.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CliVersionFlag {
+    pub syntax: CliSyntax,
+
+    /// The name of the flag (without `--`) or environment variable.
+    pub name: String,
+
+    /// The value, exactly as written.
+    pub value: String,
+
+    /// The whole flag or assignment, such as `--min-version=1.2`.
+    pub text: String,
+
+    pub expected: CliVersionValue,
+}
+
+/// Provides a version passed to a program as a flag (such as
+/// `--min-version=1.2`) or environment variable (such as
+/// `PKG_VERSION=1.4.0-rc.1`), alongside what the value is expected to parse
+/// to, for testing CLI parsing layers that wrap `semver`.
+///
+/// Values are complete versions, partial versions and requirements, empty,
+/// or versions with a faulted separator (see [arb_separator_faults]), so
+/// error cases are common.
+pub fn arb_cli_version_flag() -> impl Strategy<Value = CliVersionFlag> {
+    let value = prop_oneof![
+        3 => arb_version().prop_map(|v| v.to_string()),
+        1 => (any::<u64>(), prop::option::of(any::<u64>())).prop_map(|(major, minor)| match minor {
+            Some(minor) => format!("{major}.{minor}"),
+            None => major.to_string(),
+        }),
+        1 => arb_full_comparator(None, None, None).prop_map(|c| c.to_string()),
+        1 => Just(String::new()),
+        2 => arb_version().prop_flat_map(|v| {
+            arb_separator_faults(&v.to_string(), None, None, None).prop_map(|(s, _)| s)
+        }),
+    ];
+
+    let named = prop_oneof![
+        (
+            prop::sample::select(vec![CliSyntax::FlagEquals, CliSyntax::FlagSpace]),
+            "[a-z][a-z0-9]{0,7}(-[a-z0-9]{1,8}){0,2}",
+        ),
+        (
+            Just(CliSyntax::EnvVar),
+            "[A-Z][A-Z0-9]{0,7}(_[A-Z0-9]{1,8}){0,2}"
+        ),
+    ];
+
+    (named, value).prop_map(|((syntax, name), value)| {
+        let text = match syntax {
+            CliSyntax::FlagEquals => format!("--{name}={value}"),
+            CliSyntax::FlagSpace => format!("--{name} {value}"),
+            CliSyntax::EnvVar => format!("{name}={value}"),
+        };
+        CliVersionFlag {
+            syntax,
+            expected: CliVersionValue::parse(&value),
+            name,
+            value,
+            text,
+        }
+    })
+}
+
+/// What a line of a blob from [arb_version_lines] is expected to be.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum VersionLine {
+    /// A valid version, such as `1.4.0-rc.1`.
+    Version(Version),
+
+    /// A comment, starting with `#`.
+    Comment,
+
+    /// An empty line, or only spaces and tabs.
+    Blank,
+
+    /// A line which isn't a valid version, exactly as written.
+    Invalid(String),
+}
+
+/// An invalid version string, from a version with a faulted separator (see
+/// [arb_separator_faults]), or with a trailing `.` if the fault happened to
+/// still be valid.
+fn arb_invalid_version_line() -> impl Strategy<Value = String> {
+    arb_version().prop_flat_map(|v| {
+        arb_separator_faults(&v.to_string(), None, None, None).prop_map(|(s, _)| {
+            if Version::parse(&s).is_ok() {
+                format!("{s}.")
+            } else {
+                s
+            }
+        })
+    })
+}
+
+/// Provides a newline delimited blob of version strings, with comments and
+/// blank lines, alongside what each line is expected to be, for testing bulk
+/// import endpoints and line oriented tools.
+///
+/// The blob sometimes ends with a trailing newline, which doesn't start
+/// another line (like [str::lines]).
+///
+/// * `max_lines` - The maximum number of lines.
+/// * `probability_of_invalid` - From `0.0` to `1.0`, for each line which
+///   would otherwise be a version being invalid instead.
+pub fn arb_version_lines(
+    max_lines: usize,
+    probability_of_invalid: f64,
+) -> impl Strategy<Value = (String, Vec<VersionLine>)> {
+    let version = (
+        prop::bool::weighted(probability_of_invalid),
+        arb_version(),
+        arb_invalid_version_line(),
+    )
+        .prop_map(|(invalid, v, s)| {
+            if invalid {
+                (s.clone(), VersionLine::Invalid(s))
+            } else {
+                (v.to_string(), VersionLine::Version(v))
+            }
+        });
+    let line = prop_oneof![
+        6 => version,
+        1 => "#[ -~]{0,24}".prop_map(|s| (s, VersionLine::Comment)),
+        1 => "[ \t]{0,3}".prop_map(|s| (s, VersionLine::Blank)),
+    ];
+
+    (prop::collection::vec(line, 0..=max_lines), any::<bool>()).prop_map(
+        |(lines, trailing_newline)| {
+            let (lines, expected): (Vec<_>, Vec<_>) = lines.into_iter().unzip();
+            let mut blob = lines.join("\n");
+            // An empty last line only exists with a newline after it.
+            let empty_last = lines.last().is_some_and(|line| line.is_empty());
+            if (trailing_newline && !lines.is_empty()) || empty_last {
+                blob.push('\n');
+            }
+            (blob, expected)
+        },
+    )
+}
+
+/// The kind of a column of a [RowSchema].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColumnKind {
+    Version,
+    Req,
+    Text,
+    Integer,
+}
+
+/// The schema of the delimited rows of [arb_versioned_rows].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RowSchema {
+    /// The delimiter between fields, such as `,` or `\t`, which is never `"`,
+    /// `\r` or `\n`.
+    pub delimiter: char,
+    pub columns: Vec<ColumnKind>,
+}
+
+/// The expected value of a field of a row from [arb_versioned_rows].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Cell {
+    Version(Version),
+    Req(VersionReq),
+    Text(String),
+    Integer(i64),
+}
+
+/// A field, quoted with `"` (and any `"` doubled) when it contains the
+/// `delimiter` or a `"`.
+fn quote_field(field: &str, delimiter: char) -> String {
+    if field.contains(delimiter) || field.contains('"') {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn arb_cell(kind: ColumnKind) -> BoxedStrategy<Cell> {
+    match kind {
+        ColumnKind::Version => arb_version().prop_map(Cell::Version).boxed(),
+        ColumnKind::Req => arb_version_req(3).prop_map(Cell::Req).boxed(),
+        ColumnKind::Text => "[ -~]{0,16}".prop_map(Cell::Text).boxed(),
+        ColumnKind::Integer => any::<i64>().prop_map(Cell::Integer).boxed(),
+    }
+}
+
+/// Provides delimited rows (without a trailing newline) containing version and
+/// requirement columns among other fields, alongside the expected value of
+/// each field, for testing code which extracts versions from tabular feeds.
+///
+/// Fields are quoted as in CSV, only when they contain the delimiter or a
+/// `"`, which is common for requirements (`>=1.0.0, <2.0.0`) when the
+/// delimiter is `,`. Text never contains a newline.
+///
+/// * `schema` - The delimiter and columns of every row.
+/// * `max_rows` - The maximum number of rows.
+pub fn arb_versioned_rows(
+    schema: &RowSchema,
+    max_rows: usize,
+) -> impl Strategy<Value = Vec<(String, Vec<Cell>)>> {
+    let delimiter = schema.delimiter;
+    assert!(
+        !['"', '\r', '\n'].contains(&delimiter),
+        "`{delimiter:?}` can't be a delimiter"
+    );
+
+    let row = schema
+        .columns
+        .iter()
+        .map(|kind| arb_cell(*kind))
+        .collect::<Vec<_>>()
+        .prop_map(move |cells| {
+            let line = cells
+                .iter()
+                .map(|cell| {
+                    let field = match cell {
+                        Cell::Version(v) => v.to_string(),
+                        Cell::Req(req) => req.to_string(),
+                        Cell::Text(text) => text.clone(),
+                        Cell::Integer(i) => i.to_string(),
+                    };
+                    quote_field(&field, delimiter)
+                })
+                .collect::<Vec<_>>()
+                .join(&delimiter.to_string());
+            (line, cells)
+        });
+
+    prop::collection::vec(row, 0..=max_rows)
+}
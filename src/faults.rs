@@ -0,0 +1,366 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+//!
+//! Fault injection into otherwise valid version strings, for testing the
+//! recovery heuristics of lenient parsers.
+use proptest::prelude::*;
+use std::fmt;
+
+use crate::{arb_build_metadata_string, arb_pre_release_string, arb_version};
+
+/// The separators of a version string.
+const SEPARATORS: [char; 3] = ['.', '-', '+'];
+
+/// How a separator was faulted by [arb_separator_faults].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SeparatorFaultKind {
+    /// The separator was replaced with a different separator.
+    Swap(char),
+
+    /// The separator was repeated.
+    Duplicate,
+
+    /// The separator was removed.
+    Remove,
+}
+
+/// Describes a single fault injected into a version string.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SeparatorFault {
+    pub kind: SeparatorFaultKind,
+
+    /// The byte offset of the faulted separator, in the original string.
+    pub offset: usize,
+
+    /// The separator that was faulted.
+    pub separator: char,
+}
+
+impl fmt::Display for SeparatorFault {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (separator, offset) = (self.separator, self.offset);
+        match self.kind {
+            SeparatorFaultKind::Swap(c) => {
+                write!(f, "swapped `{separator}` at {offset} for `{c}`")
+            }
+            SeparatorFaultKind::Duplicate => write!(f, "duplicated `{separator}` at {offset}"),
+            SeparatorFaultKind::Remove => write!(f, "removed `{separator}` at {offset}"),
+        }
+    }
+}
+
+impl SeparatorFault {
+    /// Applies this fault to `version`, which must be the string it was
+    /// generated for.
+    pub fn apply(&self, version: &str) -> String {
+        let (before, after) = version.split_at(self.offset);
+        let after = &after[self.separator.len_utf8()..];
+        match self.kind {
+            SeparatorFaultKind::Swap(c) => format!("{before}{c}{after}"),
+            SeparatorFaultKind::Duplicate => {
+                format!("{before}{0}{0}{after}", self.separator)
+            }
+            SeparatorFaultKind::Remove => format!("{before}{after}"),
+        }
+    }
+}
+
+/// The byte offsets of every separator in a version string.
+///
+/// Every `.` is a separator, as is the first `-` (which starts the
+/// Pre-Release, since `MAJOR.MINOR.PATCH` never contains a `-`) and the `+`.
+/// Any other `-` is part of an identifier.
+fn separator_offsets(version: &str) -> Vec<(usize, char)> {
+    let mut seen_pre_release = false;
+    let mut seen_build_metadata = false;
+
+    version
+        .char_indices()
+        .filter(|(_, c)| match c {
+            '.' => true,
+            '-' if !seen_pre_release && !seen_build_metadata => {
+                seen_pre_release = true;
+                true
+            }
+            '+' => {
+                seen_build_metadata = true;
+                true
+            }
+            _ => false,
+        })
+        .collect()
+}
+
+/// Provides a single separator fault of `version`, as the faulted string and a
+/// description of the fault.
+///
+/// The faulted string is not guaranteed to be invalid. For example,
+/// `1.0.0-alpha.1` with it's last `.` swapped for a `-` is still valid.
+///
+/// See the [proptest::prop_oneof!] macro for more information about weight
+/// args.
+///
+/// * `version` - A valid version string, which always has at least two
+///   separators.
+/// * `weight_of_swap` - (default: 1) Weight for a
+///   [SeparatorFaultKind::Swap].
+/// * `weight_of_duplicate` - (default: 1) Weight for a
+///   [SeparatorFaultKind::Duplicate].
+/// * `weight_of_remove` - (default: 1) Weight for a
+///   [SeparatorFaultKind::Remove].
+pub fn arb_separator_faults(
+    version: &str,
+    weight_of_swap: Option<u32>,
+    weight_of_duplicate: Option<u32>,
+    weight_of_remove: Option<u32>,
+) -> impl Strategy<Value = (String, SeparatorFault)> {
+    let weight_of_swap = weight_of_swap.unwrap_or(1);
+    let weight_of_duplicate = weight_of_duplicate.unwrap_or(1);
+    let weight_of_remove = weight_of_remove.unwrap_or(1);
+
+    let offsets = separator_offsets(version);
+    assert!(
+        offsets.len() >= 2,
+        "`{version}` has fewer than two separators to fault"
+    );
+
+    let kind = prop_oneof![
+        weight_of_swap => prop::sample::select(SEPARATORS.to_vec()).prop_map(SeparatorFaultKind::Swap),
+        weight_of_duplicate => Just(SeparatorFaultKind::Duplicate),
+        weight_of_remove => Just(SeparatorFaultKind::Remove),
+    ];
+
+    let version = version.to_string();
+    (prop::sample::select(offsets), kind).prop_map(move |((offset, separator), kind)| {
+        // A swap for the same separator is no fault at all, so move along to
+        // the next one.
+        let kind = match kind {
+            SeparatorFaultKind::Swap(c) if c == separator => {
+                let i = SEPARATORS.iter().position(|s| *s == c).unwrap();
+                SeparatorFaultKind::Swap(SEPARATORS[(i + 1) % SEPARATORS.len()])
+            }
+            kind => kind,
+        };
+
+        let fault = SeparatorFault {
+            kind,
+            offset,
+            separator,
+        };
+        (fault.apply(&version), fault)
+    })
+}
+
+/// Characters which are never part of a version string.
+const ILLEGAL_CHARACTERS: [char; 9] = ['_', '!', '@', '/', ' ', '#', '$', ',', '\u{e9}'];
+
+/// A `MAJOR.MINOR.PATCH` as it's three components.
+fn arb_core() -> impl Strategy<Value = [String; 3]> {
+    any::<[u64; 3]>().prop_map(|core| core.map(|c| c.to_string()))
+}
+
+/// A numeric identifier with a leading zero, such as `007`.
+fn arb_leading_zero() -> impl Strategy<Value = String> {
+    (1..=u64::MAX).prop_map(|n| format!("0{n}"))
+}
+
+/// A leading zero in one of the `MAJOR.MINOR.PATCH` components, or in a
+/// numeric Pre-Release identifier.
+fn arb_invalid_leading_zero() -> impl Strategy<Value = String> {
+    prop_oneof![
+        (arb_core(), 0..3usize, arb_leading_zero()).prop_map(|(mut core, i, zero)| {
+            core[i] = zero;
+            core.join(".")
+        }),
+        (arb_core(), arb_pre_release_string(), arb_leading_zero())
+            .prop_map(|(core, pre, zero)| format!("{}-{pre}.{zero}", core.join("."))),
+    ]
+}
+
+/// Only `MAJOR` or `MAJOR.MINOR`, sometimes followed by a Pre-Release.
+fn arb_invalid_missing_component() -> impl Strategy<Value = String> {
+    (
+        arb_core(),
+        1..3usize,
+        prop::option::of(arb_pre_release_string()),
+    )
+        .prop_map(|(core, len, pre)| {
+            let core = core[..len].join(".");
+            match pre {
+                Some(pre) => format!("{core}-{pre}"),
+                None => core,
+            }
+        })
+}
+
+/// A valid version string with an illegal character inserted anywhere.
+fn arb_invalid_character() -> impl Strategy<Value = String> {
+    (
+        arb_version(),
+        any::<prop::sample::Index>(),
+        prop::sample::select(ILLEGAL_CHARACTERS.to_vec()),
+    )
+        .prop_map(|(v, i, c)| {
+            let mut s = v.to_string();
+            s.insert(i.index(s.len() + 1), c);
+            s
+        })
+}
+
+/// An empty identifier inserted anywhere into a Pre-Release or Build
+/// Metadata, which may then be all there is of it (`1.2.3-`).
+fn arb_invalid_empty_identifier() -> impl Strategy<Value = String> {
+    let identifiers = prop_oneof![
+        prop::option::of(arb_pre_release_string()).prop_map(|pre| ('-', pre)),
+        prop::option::of(arb_build_metadata_string()).prop_map(|build| ('+', build)),
+    ];
+
+    (arb_core(), identifiers, any::<prop::sample::Index>()).prop_map(
+        |(core, (separator, identifiers), i)| {
+            let mut identifiers: Vec<&str> = match &identifiers {
+                Some(s) => s.split('.').collect(),
+                None => Vec::new(),
+            };
+            identifiers.insert(i.index(identifiers.len() + 1), "");
+            format!("{}{separator}{}", core.join("."), identifiers.join("."))
+        },
+    )
+}
+
+/// Provides strings which are never a valid version, for testing that parsers
+/// reject hostile input.
+///
+/// Each is close to a valid version, with one of:
+///
+/// * A leading zero in a numeric component or Pre-Release identifier.
+/// * A missing `MINOR` or `PATCH`.
+/// * An illegal character.
+/// * An empty Pre-Release or Build Metadata identifier.
+/// * A trailing `.`.
+pub fn arb_invalid_semver() -> impl Strategy<Value = String> {
+    prop_oneof![
+        arb_invalid_leading_zero(),
+        arb_invalid_missing_component(),
+        arb_invalid_character(),
+        arb_invalid_empty_identifier(),
+        arb_version().prop_map(|v| format!("{v}.")),
+    ]
+}
+
+/// A single mutation of a version string, from [arb_version_mutation].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Mutation {
+    /// A separator was faulted.
+    Separator(SeparatorFault),
+
+    /// A `0` was inserted before the numeric token at the byte offset.
+    LeadingZero(usize),
+
+    /// The character at the byte offset was replaced with an illegal one.
+    Replace {
+        offset: usize,
+        original: char,
+        replacement: char,
+    },
+}
+
+impl fmt::Display for Mutation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Mutation::Separator(fault) => fault.fmt(f),
+            Mutation::LeadingZero(offset) => write!(f, "inserted a leading `0` at {offset}"),
+            Mutation::Replace {
+                offset,
+                original,
+                replacement,
+            } => write!(f, "replaced `{original}` at {offset} with `{replacement}`"),
+        }
+    }
+}
+
+impl Mutation {
+    /// Applies this mutation to `version`, which must be the string it was
+    /// generated for.
+    pub fn apply(&self, version: &str) -> String {
+        match self {
+            Mutation::Separator(fault) => fault.apply(version),
+            Mutation::LeadingZero(offset) => {
+                let (before, after) = version.split_at(*offset);
+                format!("{before}0{after}")
+            }
+            Mutation::Replace {
+                offset,
+                original,
+                replacement,
+            } => {
+                let (before, after) = version.split_at(*offset);
+                let after = &after[original.len_utf8()..];
+                format!("{before}{replacement}{after}")
+            }
+        }
+    }
+}
+
+/// The byte offsets of every token of a version string which is only digits.
+fn numeric_token_offsets(version: &str) -> Vec<usize> {
+    let separators = separator_offsets(version);
+    let starts = std::iter::once(0).chain(separators.iter().map(|(i, c)| i + c.len_utf8()));
+    let ends = separators.iter().map(|(i, _)| *i).chain([version.len()]);
+
+    starts
+        .zip(ends)
+        .filter(|(start, end)| {
+            start < end && version[*start..*end].bytes().all(|b| b.is_ascii_digit())
+        })
+        .map(|(start, _)| start)
+        .collect()
+}
+
+/// Provides a single mutation of `version`, as the mutated string and a
+/// description of the mutation, for testing the error messages and recovery
+/// logic of lenient parsers.
+///
+/// Either a separator is faulted (see [arb_separator_faults]), a leading `0`
+/// is inserted into a numeric token, or a character that isn't a separator is
+/// replaced with one that's illegal anywhere in a version. Like
+/// [arb_separator_faults], the mutated string is not guaranteed to be
+/// invalid, since leading zeros are allowed in Build Metadata.
+///
+/// * `version` - A valid version string.
+pub fn arb_version_mutation(version: &str) -> impl Strategy<Value = (String, Mutation)> {
+    let separators = arb_separator_faults(version, None, None, None)
+        .prop_map(|(_, fault)| Mutation::Separator(fault));
+    let leading_zero =
+        prop::sample::select(numeric_token_offsets(version)).prop_map(Mutation::LeadingZero);
+
+    let separator_offsets = separator_offsets(version);
+    let others: Vec<(usize, char)> = version
+        .char_indices()
+        .filter(|c| !separator_offsets.contains(c))
+        .collect();
+    let replace = (
+        prop::sample::select(others),
+        prop::sample::select(ILLEGAL_CHARACTERS.to_vec()),
+    )
+        .prop_map(|((offset, original), replacement)| Mutation::Replace {
+            offset,
+            original,
+            replacement,
+        });
+
+    let version = version.to_string();
+    prop_oneof![separators, leading_zero, replace]
+        .prop_map(move |mutation| (mutation.apply(&version), mutation))
+}
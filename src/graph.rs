@@ -0,0 +1,284 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+//!
+//! Dependency graphs of packages, and their resolutions, for fuzzing resolvers
+//! and lockfile tooling end to end.
+use proptest::prelude::*;
+use proptest::sample::Index;
+use semver::{Version, VersionReq};
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::registry::arb_package_name;
+
+/// The dependencies of a package version, as a map of package name to the
+/// requirement on it.
+pub type Dependencies = BTreeMap<String, VersionReq>;
+
+/// A dependency graph, of the requirements of a root package, and the
+/// published versions of every package with their own dependencies.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DependencyGraph {
+    /// The dependencies of the root package.
+    pub root: Dependencies,
+
+    /// Every package, by name, with it's published versions, each with it's
+    /// dependencies.
+    pub packages: BTreeMap<String, BTreeMap<Version, Dependencies>>,
+
+    /// A solution, as the version picked for every package needed, when one
+    /// is guaranteed to exist.
+    pub solution: Option<BTreeMap<String, Version>>,
+}
+
+/// Entropy for a package of [arb_dependency_graph].
+#[derive(Clone, Debug)]
+struct PackageDraw {
+    versions: BTreeSet<Version>,
+    chosen: Index,
+
+    /// For each version, it's dependencies as the package (from the next
+    /// layer), a form of requirement, and the version it's written around.
+    edges: Vec<Vec<(Index, u8, Index)>>,
+}
+
+/// The most versions each package of [arb_dependency_graph] publishes.
+const MAX_VERSIONS_PER_PACKAGE: usize = 4;
+
+fn arb_package_draw(max_fan_out: usize) -> impl Strategy<Value = PackageDraw> {
+    let version = (0..3u64, 0..4u64, 0..4u64)
+        .prop_map(|(major, minor, patch)| Version::new(major, minor, patch));
+    let edge = (any::<Index>(), any::<u8>(), any::<Index>());
+
+    (
+        prop::collection::btree_set(version, 1..=MAX_VERSIONS_PER_PACKAGE),
+        any::<Index>(),
+        prop::collection::vec(
+            prop::collection::vec(edge, 0..=max_fan_out),
+            MAX_VERSIONS_PER_PACKAGE,
+        ),
+    )
+        .prop_map(|(versions, chosen, edges)| PackageDraw {
+            versions,
+            chosen,
+            edges,
+        })
+}
+
+/// A requirement matching `v`, in one of several forms.
+fn req_matching(v: &Version, form: u8) -> VersionReq {
+    let req = match form % 7 {
+        0 => format!("^{v}"),
+        1 => format!("~{v}"),
+        2 => format!("={v}"),
+        3 => format!(">={v}"),
+        4 => format!("<={v}"),
+        5 => format!(">={v}, <{}.0.0", v.major + 1),
+        _ => format!("{}.*", v.major),
+    };
+    VersionReq::parse(&req).unwrap()
+}
+
+/// Provides a [DependencyGraph], which is either guaranteed to be solvable,
+/// alongside a solution, or guaranteed to conflict, for fuzzing PubGrub or
+/// MVS style resolvers end to end.
+///
+/// Packages are in layers below the root, which depends on every package of
+/// the first layer, and each version of a package only depends on packages
+/// of the next layer, so the graph never has a cycle. Every package publishes
+/// up to 4 small release versions, so requirements often match several.
+///
+/// A solvable graph has a version of every package, whose dependencies each
+/// match the version of their package, forming the solution. A conflicting
+/// graph either has the root require a version newer than any published, or
+/// has the root require an exact version of one package, while every version
+/// of another package it requires needs a newer one (the only dependency
+/// within a layer).
+///
+/// NOTE(canardleteer): Like PubGrub and MVS, a solution picks a single
+///                     version of each package, unlike Cargo, which may pick
+///                     one of each compatibility class.
+///
+/// * `max_depth` - The maximum number of layers, which is at least `1`.
+/// * `max_fan_out` - The maximum number of packages in each layer, and of
+///   dependencies of each version, which is at least `1`.
+/// * `solvable` - Whether a solution is guaranteed to exist, rather than
+///   guaranteed not to.
+pub fn arb_dependency_graph(
+    max_depth: usize,
+    max_fan_out: usize,
+    solvable: bool,
+) -> impl Strategy<Value = DependencyGraph> {
+    assert!(max_depth >= 1, "there is at least one layer");
+    assert!(max_fan_out >= 1, "every layer has at least one package");
+
+    let layers = prop::collection::vec(
+        prop::collection::vec(arb_package_draw(max_fan_out), 1..=max_fan_out),
+        1..=max_depth,
+    );
+    let root = prop::collection::vec(any::<u8>(), max_fan_out);
+    let conflict = (any::<bool>(), any::<Index>(), any::<Index>());
+
+    (layers, root, conflict).prop_map(move |(layers, root, conflict)| {
+        let name = |layer: usize, i: usize| format!("pkg-{layer}-{i}");
+        let chosen = |draw: &PackageDraw| {
+            let versions = draw.versions.iter().collect::<Vec<_>>();
+            versions[draw.chosen.index(versions.len())].clone()
+        };
+
+        let mut packages = BTreeMap::new();
+        let mut picks = BTreeMap::new();
+        for (layer, draws) in layers.iter().enumerate() {
+            for (i, draw) in draws.iter().enumerate() {
+                let picked = chosen(draw);
+                let mut versions = BTreeMap::new();
+                for (v, edges) in draw.versions.iter().zip(&draw.edges) {
+                    let mut deps = Dependencies::new();
+                    if let Some(next) = layers.get(layer + 1) {
+                        for (target, form, around) in edges {
+                            let j = target.index(next.len());
+                            let around = if *v == picked {
+                                chosen(&next[j])
+                            } else {
+                                let published = next[j].versions.iter().collect::<Vec<_>>();
+                                published[around.index(published.len())].clone()
+                            };
+                            deps.insert(name(layer + 1, j), req_matching(&around, *form));
+                        }
+                    }
+                    versions.insert(v.clone(), deps);
+                }
+                packages.insert(name(layer, i), versions);
+                picks.insert(name(layer, i), picked);
+            }
+        }
+
+        let mut root_deps = layers[0]
+            .iter()
+            .zip(root)
+            .enumerate()
+            .map(|(i, (draw, form))| (name(0, i), req_matching(&chosen(draw), form)))
+            .collect::<Dependencies>();
+
+        if !solvable {
+            let (diamond, x, y) = conflict;
+            let first = &layers[0];
+            let x = x.index(first.len());
+            if diamond && first.len() >= 2 {
+                let y = (x + 1 + y.index(first.len() - 1)) % first.len();
+                let picked = chosen(&first[x]);
+                root_deps.insert(
+                    name(0, x),
+                    VersionReq::parse(&format!("={picked}")).unwrap(),
+                );
+                let newer = VersionReq::parse(&format!(">{picked}")).unwrap();
+                for deps in packages.get_mut(&name(0, y)).unwrap().values_mut() {
+                    deps.insert(name(0, x), newer.clone());
+                }
+            } else {
+                let newest = first[x].versions.last().unwrap();
+                root_deps.insert(
+                    name(0, x),
+                    VersionReq::parse(&format!(">{newest}")).unwrap(),
+                );
+            }
+
+            return DependencyGraph {
+                root: root_deps,
+                packages,
+                solution: None,
+            };
+        }
+
+        // The solution is every chosen version reachable from the root.
+        let mut solution = BTreeMap::new();
+        let mut pending = root_deps.keys().cloned().collect::<Vec<_>>();
+        while let Some(package) = pending.pop() {
+            if solution.contains_key(&package) {
+                continue;
+            }
+            let picked = picks[&package].clone();
+            pending.extend(packages[&package][&picked].keys().cloned());
+            solution.insert(package, picked);
+        }
+
+        DependencyGraph {
+            root: root_deps,
+            packages,
+            solution: Some(solution),
+        }
+    })
+}
+
+/// A manifest's requirements, and the lock set resolved from them, from
+/// [arb_lockfile_fixture].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LockfileFixture {
+    /// Each requirement, by package name, in no particular order. A package
+    /// may be required more than once.
+    pub manifest: Vec<(String, VersionReq)>,
+
+    /// The versions pinned for each package, sorted by [Ord].
+    pub lock: BTreeMap<String, Vec<Version>>,
+}
+
+/// A requirement matching `v`, but no version with a different `MAJOR`, in
+/// one of several forms.
+fn req_within_major(v: &Version, form: u8) -> VersionReq {
+    let req = match form % 7 {
+        0 => format!("^{v}"),
+        1 => format!("={v}"),
+        2 => format!("~{v}"),
+        3 => format!(">={v}, <{}.0.0", v.major + 1),
+        4 => format!("{}.*", v.major),
+        5 => format!("{}", v.major),
+        _ => format!(">={v}, <={v}"),
+    };
+    VersionReq::parse(&req).unwrap()
+}
+
+/// Provides a consistent [LockfileFixture], where every requirement of the
+/// manifest is matched by exactly one version pinned for it's package, and
+/// every pinned version is matched by some requirement, for testing lockfile
+/// verification and update code against ground truth.
+///
+/// Like Cargo, a package may have several versions pinned, each with a
+/// different `MAJOR` (which is never `0`), and each required at least once.
+///
+/// * `max_packages` - The maximum number of packages, which is at least `1`.
+pub fn arb_lockfile_fixture(max_packages: usize) -> impl Strategy<Value = LockfileFixture> {
+    assert!(max_packages >= 1, "there is at least one package");
+
+    let pin = (0..10u64, 0..10u64, prop::collection::vec(any::<u8>(), 1..3));
+    let pins = prop::collection::btree_map(1..10u64, pin, 1..=3);
+
+    prop::collection::btree_map(arb_package_name(), pins, 1..=max_packages)
+        .prop_flat_map(|packages| {
+            let mut manifest = Vec::new();
+            let mut lock = BTreeMap::new();
+            for (name, pins) in packages {
+                let mut versions = Vec::new();
+                for (major, (minor, patch, forms)) in pins {
+                    let v = Version::new(major, minor, patch);
+                    for form in forms {
+                        manifest.push((name.clone(), req_within_major(&v, form)));
+                    }
+                    versions.push(v);
+                }
+                lock.insert(name, versions);
+            }
+            (Just(manifest).prop_shuffle(), Just(lock))
+        })
+        .prop_map(|(manifest, lock)| LockfileFixture { manifest, lock })
+}
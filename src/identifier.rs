@@ -0,0 +1,318 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+//!
+//! Strategies for the single dot separated identifiers that make up a
+//! Pre-Release or Build Metadata.
+use proptest::collection::SizeRange;
+use proptest::prelude::*;
+use semver::{BuildMetadata, Prerelease};
+use std::fmt;
+use std::ops::Range;
+
+/// The length of identifiers, when not otherwise specified.
+pub(crate) const DEFAULT_IDENTIFIER_LEN: Range<usize> = 1..9;
+
+const DIGITS: &[u8] = b"0123456789";
+const NON_DIGITS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ-";
+const ALPHANUMERIC: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ-";
+const HEX: &[u8] = b"0123456789abcdef";
+
+/// Provides a single identifier, which is valid as both a Pre-Release and a
+/// Build Metadata identifier, for composing into layouts this crate doesn't
+/// otherwise anticipate.
+///
+/// Numeric identifiers never have a leading zero, since that's only valid in
+/// Build Metadata. Alphanumeric identifiers always contain at least one
+/// non-digit.
+///
+/// See the [proptest::prop_oneof!] macro for more information about weight
+/// args.
+///
+/// * `weight_of_numeric` - (default: 1) Weight for this to be a numeric
+///   identifier, such as `0` or `42`.
+/// * `weight_of_alphanumeric` - (default: 1) Weight for this to be an
+///   alphanumeric identifier, such as `rc` or `0x-1`.
+pub fn arb_identifier(
+    weight_of_numeric: Option<u32>,
+    weight_of_alphanumeric: Option<u32>,
+) -> impl Strategy<Value = String> {
+    let weight_of_numeric = weight_of_numeric.unwrap_or(1);
+    let weight_of_alphanumeric = weight_of_alphanumeric.unwrap_or(1);
+
+    prop_oneof![
+        weight_of_numeric => arb_numeric_identifier_of_len(DEFAULT_IDENTIFIER_LEN),
+        weight_of_alphanumeric => arb_alphanumeric_identifier_of_len(DEFAULT_IDENTIFIER_LEN),
+    ]
+    .boxed()
+}
+
+prop_compose! {
+    /// Provides dot separated identifiers (no `-` or `+` prefix), biased
+    /// heavily toward leading, trailing, and consecutive hyphens, which are
+    /// all legal, and valid as both a Pre-Release and Build Metadata.
+    ///
+    /// For example, as a Pre-Release, `--a.-b-` makes `1.0.0---a.-b-`.
+    ///
+    /// Every identifier contains at least one hyphen, which also makes it
+    /// alphanumeric.
+    pub fn arb_hyphenated_identifiers()(
+        identifiers in prop::collection::vec(arb_hyphenated_identifier(), 1..4)
+    ) -> String {
+        identifiers.join(".")
+    }
+}
+
+fn arb_hyphenated_identifier() -> impl Strategy<Value = String> {
+    let hyphens = prop_oneof![1 => Just(0usize), 2 => 1usize..4];
+    let core = prop::collection::vec(prop::sample::select(ALPHANUMERIC), 0..4);
+
+    (
+        hyphens.clone(),
+        core.clone(),
+        hyphens.clone(),
+        core,
+        hyphens,
+    )
+        .prop_map(|(leading, first, consecutive, second, trailing)| {
+            // There must always be at least one hyphen.
+            let leading = if leading + consecutive + trailing == 0 {
+                1
+            } else {
+                leading
+            };
+
+            let mut s = "-".repeat(leading);
+            s.extend(first.into_iter().map(char::from));
+            s.push_str(&"-".repeat(consecutive));
+            s.extend(second.into_iter().map(char::from));
+            s.push_str(&"-".repeat(trailing));
+            s
+        })
+}
+
+/// A numeric identifier, without a leading zero, of some length in `len`.
+pub(crate) fn arb_numeric_identifier_of_len(len: impl Into<SizeRange>) -> BoxedStrategy<String> {
+    let len: SizeRange = len.into();
+    let len = len.start().max(1)..len.end_excl().max(2);
+
+    (
+        prop::sample::select(DIGITS),
+        prop::sample::select(&DIGITS[1..]),
+        prop::collection::vec(prop::sample::select(DIGITS), len),
+    )
+        .prop_map(|(only, first, rest)| {
+            if rest.len() == 1 {
+                // A single digit may be a zero.
+                char::from(only).to_string()
+            } else {
+                std::iter::once(first)
+                    .chain(rest.into_iter().skip(1))
+                    .map(char::from)
+                    .collect()
+            }
+        })
+        .boxed()
+}
+
+/// An alphanumeric identifier, containing at least one non-digit, of some
+/// length in `len`.
+pub(crate) fn arb_alphanumeric_identifier_of_len(
+    len: impl Into<SizeRange>,
+) -> BoxedStrategy<String> {
+    let len: SizeRange = len.into();
+    let len = len.start().max(1)..len.end_excl().max(2);
+
+    (
+        prop::collection::vec(prop::sample::select(ALPHANUMERIC), len),
+        any::<prop::sample::Index>(),
+        prop::sample::select(NON_DIGITS),
+    )
+        .prop_map(|(mut chars, index, non_digit)| {
+            if chars.iter().all(u8::is_ascii_digit) {
+                let i = index.index(chars.len());
+                chars[i] = non_digit;
+            }
+            chars.into_iter().map(char::from).collect()
+        })
+        .boxed()
+}
+
+/// A Pre-Release identifier, of some length in `len`, which is numeric with a
+/// probability of `probability_of_numeric`.
+pub(crate) fn arb_pre_release_identifier_of_len(
+    len: impl Into<SizeRange>,
+    probability_of_numeric: f64,
+) -> BoxedStrategy<String> {
+    let len: SizeRange = len.into();
+
+    prop::bool::weighted(probability_of_numeric)
+        .prop_flat_map(move |numeric| {
+            if numeric {
+                arb_numeric_identifier_of_len(len.clone())
+            } else {
+                arb_alphanumeric_identifier_of_len(len.clone())
+            }
+        })
+        .boxed()
+}
+
+/// A Build Metadata identifier, of some length in `len`, where leading zeros
+/// are allowed.
+pub(crate) fn arb_build_metadata_identifier_of_len(
+    len: impl Into<SizeRange>,
+) -> BoxedStrategy<String> {
+    let len: SizeRange = len.into();
+    let len = len.start().max(1)..len.end_excl().max(2);
+
+    prop::collection::vec(prop::sample::select(ALPHANUMERIC), len)
+        .prop_map(|chars| chars.into_iter().map(char::from).collect())
+        .boxed()
+}
+
+/// A single Pre-Release identifier, from [arb_prerelease_identifier].
+///
+/// The derived [Ord] is the precedence of the spec: numeric identifiers
+/// compare numerically, alphanumeric identifiers compare lexically in ASCII
+/// order, and numeric identifiers always have a lower precedence than
+/// alphanumeric ones. A [Vec] of identifiers then compares like a non-empty
+/// Pre-Release, where more identifiers have a higher precedence when all
+/// those before them are equal.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PrereleaseIdentifier {
+    Numeric(u64),
+
+    /// Always contains at least one non-digit.
+    AlphaNumeric(String),
+}
+
+impl fmt::Display for PrereleaseIdentifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PrereleaseIdentifier::Numeric(n) => n.fmt(f),
+            PrereleaseIdentifier::AlphaNumeric(s) => s.fmt(f),
+        }
+    }
+}
+
+/// The [semver::Prerelease] of `identifiers`, which is empty when there are
+/// none.
+pub fn prerelease_from_identifiers(identifiers: &[PrereleaseIdentifier]) -> Prerelease {
+    let joined = identifiers
+        .iter()
+        .map(|i| i.to_string())
+        .collect::<Vec<_>>()
+        .join(".");
+    Prerelease::new(&joined).unwrap()
+}
+
+/// Provides a single Pre-Release identifier, as either kind, for precise
+/// control over identifiers and differential testing of precedence.
+///
+/// Numeric identifiers are often small, so equal identifiers are common.
+pub fn arb_prerelease_identifier() -> impl Strategy<Value = PrereleaseIdentifier> {
+    prop_oneof![
+        prop_oneof![0..4u64, any::<u64>()].prop_map(PrereleaseIdentifier::Numeric),
+        prop_oneof![
+            prop::sample::select(vec!["alpha", "beta", "rc", "a", "A", "-"]).prop_map(String::from),
+            arb_alphanumeric_identifier_of_len(DEFAULT_IDENTIFIER_LEN),
+        ]
+        .prop_map(PrereleaseIdentifier::AlphaNumeric),
+    ]
+}
+
+/// Provides the identifiers of a Pre-Release, which compare like one (see
+/// [PrereleaseIdentifier]) when there is at least one.
+///
+/// * `count` - The number of identifiers, such as `1..4`. Note that an empty
+///   [Vec] has the lowest precedence, while an empty Pre-Release has the
+///   highest.
+pub fn arb_prerelease_identifiers(
+    count: impl Into<SizeRange>,
+) -> impl Strategy<Value = Vec<PrereleaseIdentifier>> {
+    prop::collection::vec(arb_prerelease_identifier(), count)
+}
+
+/// The characters of Build Metadata identifiers, from
+/// [arb_build_metadata_identifiers].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IdentifierCharset {
+    /// Any character allowed in an identifier: ASCII letters, digits and
+    /// `-`.
+    #[default]
+    Alphanumeric,
+
+    /// Lowercase hexadecimal digits, like a git commit hash.
+    Hex,
+
+    /// Decimal digits, like a timestamp or build number, where leading zeros
+    /// are allowed.
+    Digits,
+}
+
+impl IdentifierCharset {
+    fn chars(self) -> &'static [u8] {
+        match self {
+            IdentifierCharset::Alphanumeric => ALPHANUMERIC,
+            IdentifierCharset::Hex => HEX,
+            IdentifierCharset::Digits => DIGITS,
+        }
+    }
+}
+
+/// Provides Build Metadata with control over it's identifiers, such as
+/// hexadecimal identifiers of exactly `40` characters.
+///
+/// * `identifiers` - The number of identifiers, where `0` is an empty
+///   [semver::BuildMetadata].
+/// * `identifier_len` - The length of each identifier, which is at least `1`.
+/// * `charset` - The characters of each identifier.
+pub fn arb_build_metadata_identifiers(
+    identifiers: impl Into<SizeRange>,
+    identifier_len: impl Into<SizeRange>,
+    charset: IdentifierCharset,
+) -> impl Strategy<Value = BuildMetadata> {
+    let len: SizeRange = identifier_len.into();
+    let len = len.start().max(1)..len.end_excl().max(2);
+    let identifier = prop::collection::vec(prop::sample::select(charset.chars()), len)
+        .prop_map(|chars| chars.into_iter().map(char::from).collect::<String>());
+
+    prop::collection::vec(identifier, identifiers)
+        .prop_map(|identifiers| BuildMetadata::new(&identifiers.join(".")).unwrap())
+}
+
+/// Provides Build Metadata of a single git commit hash, either abbreviated
+/// (`7` or `12` characters) or in full (`40` characters).
+pub fn arb_build_metadata_git_sha() -> impl Strategy<Value = BuildMetadata> {
+    prop_oneof![Just(7usize), Just(12), Just(40)]
+        .prop_flat_map(|len| arb_build_metadata_identifiers(1, len, IdentifierCharset::Hex))
+}
+
+/// Provides Build Metadata of a single `YYYYMMDDhhmmss` timestamp, which is
+/// always a valid date and time between the years `2000` and `2099`.
+pub fn arb_build_metadata_timestamp() -> impl Strategy<Value = BuildMetadata> {
+    (
+        2000..2100u32,
+        1..=12u32,
+        1..=28u32,
+        0..24u32,
+        0..60u32,
+        0..60u32,
+    )
+        .prop_map(|(year, month, day, hour, minute, second)| {
+            let stamp = format!("{year}{month:02}{day:02}{hour:02}{minute:02}{second:02}");
+            BuildMetadata::new(&stamp).unwrap()
+        })
+}
@@ -0,0 +1,947 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+//!
+//! Decomposition of a [semver::VersionReq] into the intervals of versions it
+//! matches, usable as a reference oracle for interval based code.
+//!
+//! Versions are ordered by precedence (see [Version::cmp_precedence]), so Build
+//! Metadata is never significant to any interval here.
+use proptest::prelude::*;
+use proptest::strategy::Union;
+use semver::{Comparator, Op, Prerelease, Version, VersionReq};
+use std::cmp::Ordering;
+use std::ops::Bound;
+
+use crate::precedence::{
+    arb_version_above, arb_version_below, arb_version_in_interval, extend_pre_release, next_triple,
+    previous_triple, triple, version_of,
+};
+use crate::requirement::{arb_comparator_anchored_to, arb_matching_req_version_pair};
+use crate::{arb_semver_prerelease, arb_version, arb_version_req, arb_version_weighted};
+
+/// A run of versions, by precedence, between two bounds.
+///
+/// A [semver::VersionReq] will only ever match a Pre-Release version if one of
+/// its comparators names the same `MAJOR.MINOR.PATCH` with a Pre-Release, so
+/// most intervals only contain the release versions between their bounds.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VersionInterval {
+    pub lower: Bound<Version>,
+    pub upper: Bound<Version>,
+
+    /// When `false`, only release versions between the bounds are members of
+    /// this interval. When `true`, every version between the bounds is a
+    /// member.
+    pub includes_prerelease: bool,
+}
+
+impl VersionInterval {
+    /// Returns `true` if `version` is a member of this interval.
+    pub fn contains(&self, version: &Version) -> bool {
+        if !self.includes_prerelease && !version.pre.is_empty() {
+            return false;
+        }
+
+        let above_lower = match &self.lower {
+            Bound::Included(l) => l.cmp_precedence(version) != Ordering::Greater,
+            Bound::Excluded(l) => l.cmp_precedence(version) == Ordering::Less,
+            Bound::Unbounded => true,
+        };
+        let below_upper = match &self.upper {
+            Bound::Included(u) => u.cmp_precedence(version) != Ordering::Less,
+            Bound::Excluded(u) => u.cmp_precedence(version) == Ordering::Greater,
+            Bound::Unbounded => true,
+        };
+
+        above_lower && below_upper
+    }
+}
+
+/// Decomposes a [semver::VersionReq] into a normalized list of disjoint
+/// [VersionInterval], such that `req.matches(v)` is `true` exactly when some
+/// interval contains `v`.
+///
+/// The list is normalized as follows:
+///
+/// * No interval is empty.
+/// * There is at most one interval of release versions, and it's bounds are
+///   always `Included(release)` and either `Excluded(release)` or `Unbounded`.
+/// * Every other interval includes Pre-Release versions, and lies entirely
+///   within the Pre-Releases of a single `MAJOR.MINOR.PATCH`.
+/// * Intervals are sorted by their lower bound.
+///
+/// NOTE(canardleteer): Only comparators reachable by parsing a string are
+///                     supported, which means a comparator without a `minor`
+///                     has it's `patch` and `pre` ignored.
+pub fn decompose_version_req(req: &VersionReq) -> Vec<VersionInterval> {
+    let mut releases: Span = (Bound::Unbounded, Bound::Unbounded);
+    let mut pre_releases: Option<Span> = Some((Bound::Unbounded, Bound::Unbounded));
+    let mut pre_release_triples = Vec::new();
+
+    for c in &req.comparators {
+        let (r, p) = comparator_spans(c);
+        releases = intersect(releases, r);
+        pre_releases = match (pre_releases, p) {
+            (Some(a), Some(b)) => Some(intersect(a, b)),
+            _ => None,
+        };
+
+        if let (Some(minor), Some(patch)) = (c.minor, c.patch) {
+            if !c.pre.is_empty() {
+                pre_release_triples.push((c.major, minor, patch));
+            }
+        }
+    }
+
+    let mut intervals = Vec::new();
+    intervals.extend(release_interval(releases));
+
+    if let Some(pre_releases) = pre_releases {
+        pre_release_triples.sort_unstable();
+        pre_release_triples.dedup();
+
+        for (major, minor, patch) in pre_release_triples {
+            let window = (
+                Bound::Included(lowest_pre_release(major, minor, patch)),
+                Bound::Excluded(Version::new(major, minor, patch)),
+            );
+            let (lower, upper) = intersect(pre_releases.clone(), window);
+            if is_non_empty_pre_release_span(&lower, &upper) {
+                intervals.push(VersionInterval {
+                    lower,
+                    upper,
+                    includes_prerelease: true,
+                });
+            }
+        }
+    }
+
+    intervals.sort_by(|a, b| cmp_lower(&a.lower, &b.lower));
+    intervals
+}
+
+prop_compose! {
+    /// Creates a [semver::VersionReq] of some maximum number of `Comparator`s,
+    /// alongside it's decomposition from [decompose_version_req].
+    ///
+    /// See notes in [arb_version_req].
+    ///
+    /// * `max_comparators` - Should always be less than or equal to
+    ///   [crate::MAX_COMPARATORS_IN_VERSION_REQ_STRING].
+    pub fn arb_version_req_with_intervals(max_comparators: usize)(req in arb_version_req(max_comparators)) -> (VersionReq, Vec<VersionInterval>) {
+        let intervals = decompose_version_req(&req);
+        (req, intervals)
+    }
+}
+
+/// The region, induced by a [semver::VersionReq], that a version was sampled
+/// from by [arb_version_stratified].
+///
+/// Indexes refer to the comparators of the requirement. A version in a
+/// comparator's region is positioned relative to that comparator alone, so it
+/// may or may not also be matched by the whole requirement.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum VersionRegion {
+    /// Matched by the requirement.
+    Inside,
+
+    /// Lower precedence than the version named by the comparator.
+    Below(usize),
+
+    /// Higher precedence than the version named by the comparator.
+    Above(usize),
+
+    /// A Pre-Release of the `MAJOR.MINOR.PATCH` named by the comparator.
+    PreReleaseAdjacent(usize),
+}
+
+/// Samples a version from every region induced by the comparators of `req`,
+/// so every region is covered by every test case, rather than by chance.
+///
+/// Missing `minor` and `patch` components of a comparator are treated as `0`
+/// when naming it's version.
+///
+/// Regions that can't contain any version (such as [VersionRegion::Inside] for
+/// a requirement that matches nothing, or anything below `0.0.0-0`) are
+/// omitted.
+pub fn arb_version_stratified(
+    req: &VersionReq,
+) -> impl Strategy<Value = Vec<(VersionRegion, Version)>> {
+    let mut regions = Vec::new();
+
+    let inside = decompose_version_req(req)
+        .iter()
+        .filter_map(arb_version_in_interval)
+        .collect::<Vec<_>>();
+    if !inside.is_empty() {
+        regions.push(
+            Union::new(inside)
+                .prop_map(|v| (VersionRegion::Inside, v))
+                .boxed(),
+        );
+    }
+
+    for (i, c) in req.comparators.iter().enumerate() {
+        let named = Version {
+            major: c.major,
+            minor: c.minor.unwrap_or(0),
+            patch: c.patch.unwrap_or(0),
+            pre: c.pre.clone(),
+            build: semver::BuildMetadata::EMPTY,
+        };
+
+        if let Some(below) = arb_version_below(&named) {
+            regions.push(
+                below
+                    .prop_map(move |v| (VersionRegion::Below(i), v))
+                    .boxed(),
+            );
+        }
+
+        if let Some(above) = arb_version_above(&named) {
+            regions.push(
+                above
+                    .prop_map(move |v| (VersionRegion::Above(i), v))
+                    .boxed(),
+            );
+        }
+
+        regions.push(
+            arb_semver_prerelease()
+                .prop_map(move |pre| {
+                    let mut v = Version::new(named.major, named.minor, named.patch);
+                    v.pre = pre;
+                    (VersionRegion::PreReleaseAdjacent(i), v)
+                })
+                .boxed(),
+        );
+    }
+
+    regions
+}
+
+/// Provides a non-empty range of versions by precedence, as a pair of
+/// [std::ops::Bound], following Rust range semantics (so every Pre-Release
+/// between the bounds is in range).
+///
+/// Bounded versions never have Build Metadata, and see
+/// [version_req_from_bounds] for the nearest [semver::VersionReq].
+pub fn arb_version_bounds() -> impl Strategy<Value = (Bound<Version>, Bound<Version>)> {
+    let witness = arb_version_weighted(0.25, 0.5).prop_map(|mut v| {
+        v.build = semver::BuildMetadata::EMPTY;
+        v
+    });
+
+    // Both bounds are placed around a version known to be in range.
+    witness.prop_flat_map(|w| {
+        let mut lower = vec![
+            Just(Bound::Unbounded).boxed(),
+            Just(Bound::Included(w.clone())).boxed(),
+        ];
+        if let Some(below) = arb_version_below(&w) {
+            lower.push(below.clone().prop_map(Bound::Included).boxed());
+            lower.push(below.prop_map(Bound::Excluded).boxed());
+        }
+
+        let mut upper = vec![
+            Just(Bound::Unbounded).boxed(),
+            Just(Bound::Included(w.clone())).boxed(),
+        ];
+        if let Some(above) = arb_version_above(&w) {
+            upper.push(above.clone().prop_map(Bound::Included).boxed());
+            upper.push(above.prop_map(Bound::Excluded).boxed());
+        }
+
+        (Union::new(lower), Union::new(upper))
+    })
+}
+
+/// The [semver::VersionReq] nearest to a range of versions by precedence,
+/// with a `>=` or `>` comparator for the lower bound, and a `<=` or `<`
+/// comparator for the upper bound.
+///
+/// The requirement matches exactly the release versions in range, but only
+/// those Pre-Releases in range that share a `MAJOR.MINOR.PATCH` with a bound
+/// that is itself a Pre-Release, per the rules of [semver::VersionReq].
+pub fn version_req_from_bounds(lower: &Bound<Version>, upper: &Bound<Version>) -> VersionReq {
+    let comparator = |op: Op, v: &Version| Comparator {
+        op,
+        major: v.major,
+        minor: Some(v.minor),
+        patch: Some(v.patch),
+        pre: v.pre.clone(),
+    };
+
+    let lower = match lower {
+        Bound::Included(v) => Some(comparator(Op::GreaterEq, v)),
+        Bound::Excluded(v) => Some(comparator(Op::Greater, v)),
+        Bound::Unbounded => None,
+    };
+    let upper = match upper {
+        Bound::Included(v) => Some(comparator(Op::LessEq, v)),
+        Bound::Excluded(v) => Some(comparator(Op::Less, v)),
+        Bound::Unbounded => None,
+    };
+
+    VersionReq {
+        comparators: lower.into_iter().chain(upper).collect(),
+    }
+}
+
+/// Provides versions matched by `req`, by solving it's intervals (see
+/// [decompose_version_req]) rather than by filtering, or `None` if `req`
+/// matches nothing.
+///
+/// Every interval is equally likely to be sampled from, so Pre-Releases are
+/// common when `req` allows any. Versions never have Build Metadata.
+pub fn arb_version_matching(req: &VersionReq) -> Option<BoxedStrategy<Version>> {
+    let inside = decompose_version_req(req)
+        .iter()
+        .filter_map(arb_version_in_interval)
+        .collect::<Vec<_>>();
+
+    if inside.is_empty() {
+        None
+    } else {
+        Some(Union::new(inside).boxed())
+    }
+}
+
+/// Provides a satisfiable [semver::VersionReq], alongside a version it
+/// matches, as a constructive witness of it's satisfiability.
+///
+/// Half of the requirements are built around the witness (see
+/// [crate::arb_matching_req_version_pair]), and half are like
+/// [arb_version_req], with up to 2 comparators, solved for a witness with
+/// [arb_version_matching] (discarding those matching nothing).
+pub fn arb_satisfiable_version_req() -> impl Strategy<Value = (VersionReq, Version)> {
+    let solved = arb_version_req(2)
+        .prop_filter_map("the requirement must match something", |req| {
+            arb_version_matching(&req).map(|witness| (req, witness))
+        })
+        .prop_flat_map(|(req, witness)| (Just(req), witness));
+
+    prop_oneof![arb_matching_req_version_pair(), solved]
+}
+
+/// Versions at and immediately around `v`: it's release, the releases on
+/// either side of it, it's lowest Pre-Release, and the Pre-Release just above
+/// it.
+fn versions_around(v: &Version) -> Vec<Version> {
+    let t = triple(v);
+    let mut around = vec![
+        v.clone(),
+        version_of(t, None),
+        version_of(t, Some(Prerelease::new("0").unwrap())),
+    ];
+    around.extend(previous_triple(t).map(|t| version_of(t, None)));
+    around.extend(next_triple(t).map(|t| version_of(t, None)));
+    if !v.pre.is_empty() {
+        around.push(version_of(t, Some(extend_pre_release(&v.pre, "0"))));
+    }
+    around
+}
+
+/// Provides versions not matched by `req`, biased toward those just outside
+/// it's intervals (see [decompose_version_req]), such as one `PATCH` below a
+/// `>=`, the bound of a `<`, or a Pre-Release of a matched
+/// `MAJOR.MINOR.PATCH`, or `None` if `req` matches everything.
+///
+/// Boundary versions are found around every comparator and every bound of
+/// every interval. Other versions are sampled anywhere, and replaced by a
+/// boundary version when they happen to match, so nothing is rejected.
+/// Versions never have Build Metadata.
+pub fn arb_version_not_matching(req: &VersionReq) -> Option<BoxedStrategy<Version>> {
+    let named = req.comparators.iter().map(|c| Version {
+        major: c.major,
+        minor: c.minor.unwrap_or(0),
+        patch: c.patch.unwrap_or(0),
+        pre: c.pre.clone(),
+        build: semver::BuildMetadata::EMPTY,
+    });
+    let bounds = decompose_version_req(req)
+        .into_iter()
+        .flat_map(|interval| [interval.lower, interval.upper])
+        .filter_map(|bound| match bound {
+            Bound::Included(v) | Bound::Excluded(v) => Some(v),
+            Bound::Unbounded => None,
+        });
+    let fixed = [
+        Version::new(0, 0, 0),
+        version_of((0, 0, 0), Some(Prerelease::new("0").unwrap())),
+        version_of((1, 0, 0), Some(Prerelease::new("0").unwrap())),
+        Version::new(u64::MAX, u64::MAX, u64::MAX),
+    ];
+
+    let mut boundary = named
+        .chain(bounds)
+        .flat_map(|v| versions_around(&v))
+        .chain(fixed)
+        .filter(|v| !req.matches(v))
+        .collect::<Vec<_>>();
+    boundary.sort();
+    boundary.dedup();
+
+    let fallback = boundary.first()?.clone();
+    let req = req.clone();
+    let anywhere = arb_version().prop_map(move |mut v| {
+        v.build = semver::BuildMetadata::EMPTY;
+        if req.matches(&v) {
+            fallback.clone()
+        } else {
+            v
+        }
+    });
+
+    Some(prop_oneof![3 => prop::sample::select(boundary), 1 => anywhere].boxed())
+}
+
+pub(crate) type Span = (Bound<Version>, Bound<Version>);
+
+/// The spans of release versions, and Pre-Release versions, matched by a
+/// single comparator, ignoring the requirement wide Pre-Release rule.
+pub(crate) fn comparator_spans(c: &Comparator) -> (Span, Option<Span>) {
+    let (Some(minor), Some(patch)) = (c.minor, c.patch) else {
+        return partial_comparator_spans(c);
+    };
+
+    let v = Version {
+        major: c.major,
+        minor,
+        patch,
+        pre: c.pre.clone(),
+        build: semver::BuildMetadata::EMPTY,
+    };
+
+    let span = match c.op {
+        Op::Exact | Op::Wildcard => (Bound::Included(v.clone()), Bound::Included(v)),
+        Op::Greater => (Bound::Excluded(v), Bound::Unbounded),
+        Op::GreaterEq => (Bound::Included(v), Bound::Unbounded),
+        Op::Less => (Bound::Unbounded, Bound::Excluded(v)),
+        Op::LessEq => (Bound::Unbounded, Bound::Included(v)),
+        Op::Tilde => (
+            Bound::Included(v),
+            excluded_or_unbounded(next_minor(c.major, minor)),
+        ),
+        Op::Caret => {
+            let upper = if c.major > 0 {
+                next_major(c.major)
+            } else if minor > 0 {
+                next_minor(c.major, minor)
+            } else {
+                next_patch(c.major, minor, patch)
+            };
+            (Bound::Included(v), excluded_or_unbounded(upper))
+        }
+        _ => (Bound::Unbounded, Bound::Unbounded),
+    };
+
+    (span.clone(), Some(span))
+}
+
+fn partial_comparator_spans(c: &Comparator) -> (Span, Option<Span>) {
+    // The "zone" is every version starting with the `MAJOR` or `MAJOR.MINOR`
+    // of the comparator.
+    let (zone_lower, zone_upper) = match c.minor {
+        Some(minor) => (
+            lowest_pre_release(c.major, minor, 0),
+            next_minor(c.major, minor),
+        ),
+        None => (lowest_pre_release(c.major, 0, 0), next_major(c.major)),
+    };
+    let zone = (
+        Bound::Included(zone_lower.clone()),
+        excluded_or_unbounded(zone_upper.clone()),
+    );
+    let above_zone = zone_upper.map(|u| (Bound::Included(u), Bound::Unbounded));
+    let below_zone = (Bound::Unbounded, Bound::Excluded(zone_lower.clone()));
+
+    match c.op {
+        Op::Exact | Op::Wildcard | Op::Tilde => (zone, None),
+        Op::Greater => match above_zone {
+            Some(above_zone) => (above_zone.clone(), Some(above_zone)),
+            None => (empty_span(), None),
+        },
+        Op::GreaterEq => ((Bound::Included(zone_lower), Bound::Unbounded), above_zone),
+        Op::Less => (below_zone.clone(), Some(below_zone)),
+        Op::LessEq => ((Bound::Unbounded, zone.1), Some(below_zone)),
+        Op::Caret => match c.minor {
+            Some(_) if c.major > 0 => {
+                let span = (
+                    Bound::Included(zone_lower),
+                    excluded_or_unbounded(next_major(c.major)),
+                );
+                (span.clone(), Some(span))
+            }
+            _ => (zone.clone(), Some(zone)),
+        },
+        _ => (zone.clone(), Some(zone)),
+    }
+}
+
+/// The lowest possible version for a `MAJOR.MINOR.PATCH`, which is it's
+/// Pre-Release of `0`.
+fn lowest_pre_release(major: u64, minor: u64, patch: u64) -> Version {
+    Version {
+        major,
+        minor,
+        patch,
+        pre: Prerelease::new("0").unwrap(),
+        build: semver::BuildMetadata::EMPTY,
+    }
+}
+
+fn next_major(major: u64) -> Option<Version> {
+    Some(lowest_pre_release(major.checked_add(1)?, 0, 0))
+}
+
+fn next_minor(major: u64, minor: u64) -> Option<Version> {
+    match minor.checked_add(1) {
+        Some(minor) => Some(lowest_pre_release(major, minor, 0)),
+        None => next_major(major),
+    }
+}
+
+fn next_patch(major: u64, minor: u64, patch: u64) -> Option<Version> {
+    match patch.checked_add(1) {
+        Some(patch) => Some(lowest_pre_release(major, minor, patch)),
+        None => next_minor(major, minor),
+    }
+}
+
+/// The release version immediately after `v`, if any.
+fn next_release(v: &Version) -> Option<Version> {
+    next_patch(v.major, v.minor, v.patch).map(|v| Version::new(v.major, v.minor, v.patch))
+}
+
+fn excluded_or_unbounded(v: Option<Version>) -> Bound<Version> {
+    v.map(Bound::Excluded).unwrap_or(Bound::Unbounded)
+}
+
+fn empty_span() -> Span {
+    let v = Version::new(0, 0, 0);
+    (Bound::Excluded(v.clone()), Bound::Excluded(v))
+}
+
+pub(crate) fn cmp_lower(a: &Bound<Version>, b: &Bound<Version>) -> Ordering {
+    match (a, b) {
+        (Bound::Unbounded, Bound::Unbounded) => Ordering::Equal,
+        (Bound::Unbounded, _) => Ordering::Less,
+        (_, Bound::Unbounded) => Ordering::Greater,
+        (Bound::Included(a), Bound::Included(b)) | (Bound::Excluded(a), Bound::Excluded(b)) => {
+            a.cmp_precedence(b)
+        }
+        (Bound::Included(a), Bound::Excluded(b)) => a.cmp_precedence(b).then(Ordering::Less),
+        (Bound::Excluded(a), Bound::Included(b)) => a.cmp_precedence(b).then(Ordering::Greater),
+    }
+}
+
+pub(crate) fn cmp_upper(a: &Bound<Version>, b: &Bound<Version>) -> Ordering {
+    match (a, b) {
+        (Bound::Unbounded, Bound::Unbounded) => Ordering::Equal,
+        (Bound::Unbounded, _) => Ordering::Greater,
+        (_, Bound::Unbounded) => Ordering::Less,
+        (Bound::Included(a), Bound::Included(b)) | (Bound::Excluded(a), Bound::Excluded(b)) => {
+            a.cmp_precedence(b)
+        }
+        (Bound::Included(a), Bound::Excluded(b)) => a.cmp_precedence(b).then(Ordering::Greater),
+        (Bound::Excluded(a), Bound::Included(b)) => a.cmp_precedence(b).then(Ordering::Less),
+    }
+}
+
+fn intersect(a: Span, b: Span) -> Span {
+    let lower = match cmp_lower(&a.0, &b.0) {
+        Ordering::Less => b.0,
+        _ => a.0,
+    };
+    let upper = match cmp_upper(&a.1, &b.1) {
+        Ordering::Greater => b.1,
+        _ => a.1,
+    };
+    (lower, upper)
+}
+
+/// Normalizes a span to the release versions within it, if there are any.
+fn release_interval((lower, upper): Span) -> Option<VersionInterval> {
+    // The first release version within the span.
+    let first = match lower {
+        Bound::Unbounded => Version::new(0, 0, 0),
+        Bound::Included(v) if v.pre.is_empty() => v,
+        Bound::Excluded(v) if v.pre.is_empty() => next_release(&v)?,
+        Bound::Included(v) | Bound::Excluded(v) => Version::new(v.major, v.minor, v.patch),
+    };
+
+    // The first release version after the span.
+    let after = match upper {
+        Bound::Unbounded => None,
+        Bound::Excluded(v) if v.pre.is_empty() => Some(v),
+        Bound::Included(v) if v.pre.is_empty() => next_release(&v),
+        Bound::Included(v) | Bound::Excluded(v) => Some(Version::new(v.major, v.minor, v.patch)),
+    };
+
+    if let Some(after) = &after {
+        if first.cmp_precedence(after) != Ordering::Less {
+            return None;
+        }
+    }
+
+    Some(VersionInterval {
+        lower: Bound::Included(first),
+        upper: excluded_or_unbounded(after),
+        includes_prerelease: false,
+    })
+}
+
+/// Checks a span already clipped to the Pre-Releases of a single
+/// `MAJOR.MINOR.PATCH` for any members.
+fn is_non_empty_pre_release_span(lower: &Bound<Version>, upper: &Bound<Version>) -> bool {
+    match (lower, upper) {
+        (Bound::Included(l), Bound::Included(u)) => l.cmp_precedence(u) != Ordering::Greater,
+        (Bound::Included(l), Bound::Excluded(u)) | (Bound::Excluded(l), Bound::Included(u)) => {
+            l.cmp_precedence(u) == Ordering::Less
+        }
+        (Bound::Excluded(l), Bound::Excluded(u)) => {
+            if l.cmp_precedence(u) != Ordering::Less || l.pre.is_empty() {
+                return false;
+            }
+
+            // The version immediately after a Pre-Release is the same
+            // Pre-Release, with an additional `0` identifier.
+            let mut next = l.clone();
+            next.pre = Prerelease::new(&format!("{}.0", l.pre)).unwrap();
+            next.cmp_precedence(u) == Ordering::Less
+        }
+        // Both bounds are always clipped.
+        _ => false,
+    }
+}
+
+/// Normalizes a lower bound within the Pre-Releases of a `MAJOR.MINOR.PATCH`
+/// to be inclusive, since nothing lies between a Pre-Release `x` and `x.0`.
+fn inclusive_lower(bound: &Bound<Version>) -> Bound<Version> {
+    match bound {
+        Bound::Excluded(v) if !v.pre.is_empty() => {
+            Bound::Included(version_of(triple(v), Some(extend_pre_release(&v.pre, "0"))))
+        }
+        bound => bound.clone(),
+    }
+}
+
+/// Normalizes an upper bound within the Pre-Releases of a `MAJOR.MINOR.PATCH`
+/// to be exclusive, for the same reason as [inclusive_lower].
+fn exclusive_upper(bound: &Bound<Version>) -> Bound<Version> {
+    match bound {
+        Bound::Included(v) if !v.pre.is_empty() => {
+            Bound::Excluded(version_of(triple(v), Some(extend_pre_release(&v.pre, "0"))))
+        }
+        bound => bound.clone(),
+    }
+}
+
+/// Returns `true` if every member of `a` is a member of `b`.
+fn interval_within(a: &VersionInterval, b: &VersionInterval) -> bool {
+    (b.includes_prerelease || !a.includes_prerelease)
+        && cmp_lower(&inclusive_lower(&b.lower), &inclusive_lower(&a.lower)) != Ordering::Greater
+        && cmp_upper(&exclusive_upper(&a.upper), &exclusive_upper(&b.upper)) != Ordering::Greater
+}
+
+/// Returns `true` if comparator `a` subsumes comparator `b`, such that every
+/// version matched by `a` is also matched by `b`, using the intervals of each
+/// (see [decompose_version_req]).
+///
+/// A comparator that matches nothing (such as `<0.0.0`) implies every
+/// comparator.
+pub fn implies(a: &Comparator, b: &Comparator) -> bool {
+    let intervals = |c: &Comparator| {
+        decompose_version_req(&VersionReq {
+            comparators: vec![c.clone()],
+        })
+    };
+    let b = intervals(b);
+
+    intervals(a)
+        .iter()
+        .all(|a| b.iter().any(|b| interval_within(a, b)))
+}
+
+/// Provides a pair of comparators `(a, b)`, alongside whether `a` implies `b`
+/// (see [implies]), for testing constraint simplification.
+///
+/// Both comparators name versions at or around the same version, or just it's
+/// `MAJOR` or `MAJOR.MINOR`, so both labels are common.
+pub fn arb_comparator_implication_pair() -> impl Strategy<Value = (Comparator, Comparator, bool)> {
+    arb_version_weighted(0.25, 0.5)
+        .prop_flat_map(|v| {
+            let around = versions_around(&v);
+            (
+                prop::sample::select(around).prop_flat_map(arb_comparator_anchored_to),
+                arb_comparator_anchored_to(v),
+            )
+        })
+        .prop_map(|(a, b)| {
+            let implied = implies(&a, &b);
+            (a, b, implied)
+        })
+}
+
+/// A set of versions, as a union of half-open [VersionInterval]s, for
+/// expressing intersection, union and containment of requirements, which
+/// can't be expressed against raw comparator lists.
+///
+/// Intervals are normalized, such that every interval is non-empty, and has
+/// an `Included` lower bound and an `Excluded` or `Unbounded` upper bound.
+/// Intervals are sorted by their lower bound, and never overlap or touch
+/// another interval of the same kind, so equal sets are always equal values.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct VersionSet {
+    intervals: Vec<VersionInterval>,
+}
+
+impl VersionSet {
+    /// The versions matched by `req` (see [decompose_version_req]).
+    pub fn from_version_req(req: &VersionReq) -> Self {
+        Self::normalized(decompose_version_req(req))
+    }
+
+    /// Normalizes intervals of the kinds found by [decompose_version_req].
+    fn normalized(intervals: Vec<VersionInterval>) -> Self {
+        let (pre_releases, releases): (Vec<_>, Vec<_>) = intervals
+            .into_iter()
+            .map(|interval| VersionInterval {
+                lower: inclusive_lower(&interval.lower),
+                upper: exclusive_upper(&interval.upper),
+                includes_prerelease: interval.includes_prerelease,
+            })
+            .filter(|interval| !is_empty_half_open(interval))
+            .partition(|interval| interval.includes_prerelease);
+
+        let mut intervals = merge_half_open(releases);
+        intervals.extend(merge_half_open(pre_releases));
+        intervals.sort_by(|a, b| cmp_lower(&a.lower, &b.lower));
+        Self { intervals }
+    }
+
+    /// The normalized intervals of this set.
+    pub fn intervals(&self) -> &[VersionInterval] {
+        &self.intervals
+    }
+
+    /// Returns `true` if no version is a member of this set.
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Returns `true` if `version` is a member of this set.
+    pub fn contains(&self, version: &Version) -> bool {
+        self.intervals.iter().any(|i| i.contains(version))
+    }
+
+    /// The versions which are a member of either set.
+    pub fn union(&self, other: &VersionSet) -> VersionSet {
+        Self::normalized(
+            self.intervals
+                .iter()
+                .chain(&other.intervals)
+                .cloned()
+                .collect(),
+        )
+    }
+
+    /// The versions which are a member of both sets.
+    pub fn intersection(&self, other: &VersionSet) -> VersionSet {
+        let mut intervals = Vec::new();
+        for a in &self.intervals {
+            for b in &other.intervals {
+                if a.includes_prerelease != b.includes_prerelease {
+                    continue;
+                }
+                let (lower, upper) = intersect(
+                    (a.lower.clone(), a.upper.clone()),
+                    (b.lower.clone(), b.upper.clone()),
+                );
+                intervals.push(VersionInterval {
+                    lower,
+                    upper,
+                    includes_prerelease: a.includes_prerelease,
+                });
+            }
+        }
+        Self::normalized(intervals)
+    }
+
+    /// Returns `true` if every member of this set is a member of `other`.
+    pub fn is_subset(&self, other: &VersionSet) -> bool {
+        // NOTE(canardleteer): Since intervals never touch, an interval within
+        //                     the union of `other` is within one of them.
+        self.intervals
+            .iter()
+            .all(|a| other.intervals.iter().any(|b| interval_within(a, b)))
+    }
+
+    /// Requirements, one per interval, where a version is a member of this set
+    /// exactly when some requirement matches it (see
+    /// [version_req_from_bounds]).
+    ///
+    /// A [semver::VersionReq] is only ever an intersection of comparators, so
+    /// a union generally needs more than one of them.
+    pub fn to_version_reqs(&self) -> Vec<VersionReq> {
+        self.intervals
+            .iter()
+            .map(|i| version_req_from_bounds(&i.lower, &i.upper))
+            .collect()
+    }
+}
+
+/// Returns `true` if a half-open interval has no members.
+fn is_empty_half_open(interval: &VersionInterval) -> bool {
+    match (&interval.lower, &interval.upper) {
+        (Bound::Included(l), Bound::Excluded(u)) => l.cmp_precedence(u) != Ordering::Less,
+        _ => false,
+    }
+}
+
+/// Merges overlapping or touching half-open intervals of a single kind.
+fn merge_half_open(mut intervals: Vec<VersionInterval>) -> Vec<VersionInterval> {
+    intervals.sort_by(|a, b| cmp_lower(&a.lower, &b.lower));
+
+    let mut merged: Vec<VersionInterval> = Vec::new();
+    for interval in intervals {
+        if let Some(last) = merged.last_mut() {
+            let touches = match (&last.upper, &interval.lower) {
+                (Bound::Excluded(u), Bound::Included(l)) => {
+                    l.cmp_precedence(u) != Ordering::Greater
+                }
+                _ => true,
+            };
+            if touches {
+                if cmp_upper(&interval.upper, &last.upper) == Ordering::Greater {
+                    last.upper = interval.upper;
+                }
+                continue;
+            }
+        }
+        merged.push(interval);
+    }
+    merged
+}
+
+/// Small requirements over versions with components below `3`, so the sets
+/// they match often overlap.
+fn arb_small_version_req() -> impl Strategy<Value = VersionReq> {
+    let version = (
+        0..3u64,
+        0..3u64,
+        0..3u64,
+        prop::sample::select(vec!["", "", "0", "alpha"]),
+    )
+        .prop_map(|(major, minor, patch, pre)| {
+            version_of(
+                (major, minor, patch),
+                (!pre.is_empty()).then(|| Prerelease::new(pre).unwrap()),
+            )
+        });
+
+    prop::collection::vec(version.prop_flat_map(arb_comparator_anchored_to), 1..3)
+        .prop_map(|comparators| VersionReq { comparators })
+}
+
+/// Provides a [VersionSet], as the union of up to `max_requirements` small
+/// requirements, alongside those requirements.
+///
+/// Requirements name versions with components below `3`, so sets from
+/// separate calls often intersect, or contain one another.
+pub fn arb_version_set(
+    max_requirements: usize,
+) -> impl Strategy<Value = (VersionSet, Vec<VersionReq>)> {
+    prop::collection::vec(arb_small_version_req(), 0..=max_requirements).prop_map(|reqs| {
+        let set = reqs
+            .iter()
+            .map(VersionSet::from_version_req)
+            .fold(VersionSet::default(), |set, other| set.union(&other));
+        (set, reqs)
+    })
+}
+
+/// How the versions matched by two requirements relate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum IntersectionKind {
+    /// No version is matched by both.
+    Disjoint,
+
+    /// Some versions are matched by both, and each matches some the other
+    /// doesn't.
+    Overlapping,
+
+    /// Both match exactly the same versions.
+    Equal,
+
+    /// The first matches every version the second does, and more.
+    Contains,
+
+    /// The second matches every version the first does, and more.
+    ContainedBy,
+}
+
+impl IntersectionKind {
+    /// How the versions matched by `a` and `b` relate, using their
+    /// [VersionSet]s.
+    pub fn of(a: &VersionReq, b: &VersionReq) -> Self {
+        let (a, b) = (
+            VersionSet::from_version_req(a),
+            VersionSet::from_version_req(b),
+        );
+        match (b.is_subset(&a), a.is_subset(&b)) {
+            (true, true) => IntersectionKind::Equal,
+            (true, false) => IntersectionKind::Contains,
+            (false, true) => IntersectionKind::ContainedBy,
+            (false, false) if a.intersection(&b).is_empty() => IntersectionKind::Disjoint,
+            (false, false) => IntersectionKind::Overlapping,
+        }
+    }
+}
+
+/// Provides a pair of satisfiable requirements, alongside how the versions
+/// they match relate, for testing range intersection.
+///
+/// Requirements name versions with components below `3`, so every kind is
+/// common. Sometimes one requirement is the other with an extra comparator,
+/// so it's contained by (or equal to) the other. Labels are from
+/// [IntersectionKind::of], so are exact.
+pub fn arb_version_req_intersection_pair(
+) -> impl Strategy<Value = (VersionReq, VersionReq, IntersectionKind)> {
+    let satisfiable = || {
+        arb_small_version_req().prop_filter("the requirement must match something", |req| {
+            !decompose_version_req(req).is_empty()
+        })
+    };
+    let narrowed = (satisfiable(), satisfiable(), any::<bool>())
+        .prop_map(|(a, extra, swap)| {
+            let mut b = a.clone();
+            b.comparators.extend(extra.comparators);
+            if swap {
+                (b, a)
+            } else {
+                (a, b)
+            }
+        })
+        .prop_filter("the requirement must match something", |(a, b)| {
+            !decompose_version_req(a).is_empty() && !decompose_version_req(b).is_empty()
+        });
+
+    prop_oneof![3 => (satisfiable(), satisfiable()), 1 => narrowed].prop_map(|(a, b)| {
+        let kind = IntersectionKind::of(&a, &b);
+        (a, b, kind)
+    })
+}
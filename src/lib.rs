@@ -23,6 +23,76 @@ use proptest_derive::Arbitrary;
 use semver::{Version, VersionReq};
 use std::fmt;
 
+pub mod arbitrary;
+pub mod arena;
+pub mod buckets;
+pub mod builder;
+pub mod comparator;
+pub mod constants;
+pub mod dialects;
+pub mod edges;
+pub mod encodings;
+pub mod faults;
+pub mod graph;
+pub mod identifier;
+pub mod interval;
+pub mod limits;
+pub mod names;
+pub mod pairs;
+pub mod pairwise;
+pub mod precedence;
+#[cfg(feature = "serde")]
+pub mod profile;
+pub mod registry;
+pub mod report;
+pub mod repro;
+pub mod reproducible;
+pub mod requirement;
+pub mod scenarios;
+pub mod sequences;
+#[cfg(feature = "serde")]
+mod serialize;
+pub mod shape;
+pub mod shrink;
+pub mod smoke;
+pub mod spans;
+pub mod testkit;
+pub mod weights;
+
+pub use arbitrary::*;
+pub use arena::*;
+pub use buckets::*;
+pub use builder::*;
+pub use comparator::*;
+pub use constants::*;
+pub use dialects::*;
+pub use edges::*;
+pub use encodings::*;
+pub use faults::*;
+pub use graph::*;
+pub use identifier::*;
+pub use interval::*;
+pub use limits::*;
+pub use names::*;
+pub use pairs::*;
+pub use pairwise::*;
+pub use precedence::*;
+#[cfg(feature = "serde")]
+pub use profile::*;
+pub use registry::*;
+pub use report::*;
+pub use repro::*;
+pub use reproducible::*;
+pub use requirement::*;
+pub use scenarios::*;
+pub use sequences::*;
+pub use shape::*;
+pub use shrink::*;
+pub use smoke::*;
+pub use spans::*;
+pub use testkit::*;
+pub use weights::*;
+
 /// Regex for Semantic Version 2.0.0, directly from the spec, with 2 changes:
 ///
 /// * ASCII Only Restriction
@@ -64,8 +134,8 @@ pub const MAX_COMPARATORS_IN_VERSION_REQ_STRING: usize = 32;
 
 // These should be used as choices when Option is None. Currently it is
 // effectively hard coded in places.
-const DEFAULT_PROBABILITY_OF_PRE_RELEASE: f64 = 0.5;
-const DEFAULT_PROBABILITY_OF_BUILD_METADATA: f64 = 0.5;
+pub(crate) const DEFAULT_PROBABILITY_OF_PRE_RELEASE: f64 = 0.5;
+pub(crate) const DEFAULT_PROBABILITY_OF_BUILD_METADATA: f64 = 0.5;
 
 prop_compose! {
     /// Arbitrary Semantic Versioning 2.0.0 String.
@@ -142,7 +212,7 @@ prop_compose! {
 ///
 /// Since "Wildcard is often useless for testing `VersionReq`, by default it's weight is very weak.
 ///
-/// I'm sure someone will eventually want all kinds broken out with weights.
+/// See [arb_semver_op_weighted] for every kind broken out with weights.
 ///
 /// * `default_weight` - The "weight" of picking every other option, except [semver::Op::Wildcard].
 /// * `wildcard_weight` - The "weight" of picking [semver::Op::Wildcard], against the sum of all "default_weight" types.
@@ -153,15 +223,108 @@ pub fn arb_semver_op(
     let default_weight = default_weight.unwrap_or(5);
     let wildcard_weight = wildcard_weight.unwrap_or(1);
 
+    arb_semver_op_weighted(&OpWeights {
+        wildcard: wildcard_weight,
+        ..OpWeights::uniform(default_weight)
+    })
+}
+
+/// The weight of picking each kind of [semver::Op], or [ComparatorOp].
+///
+/// See the [proptest::prop_oneof!] macro for more information about weights.
+///
+/// With the `serde` feature, this is `Serialize` and `Deserialize`, where
+/// missing fields are their [OpWeights::default].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default, deny_unknown_fields)
+)]
+pub struct OpWeights {
+    pub exact: u32,
+    pub greater: u32,
+    pub greater_eq: u32,
+    pub less: u32,
+    pub less_eq: u32,
+    pub tilde: u32,
+    pub caret: u32,
+
+    /// Only used for [semver::Op]. A [ComparatorOp] has no wildcard, since
+    /// that's the shape of a [FullComparator] instead.
+    pub wildcard: u32,
+}
+
+impl OpWeights {
+    /// A mix reflecting requirements in the wild, as seen on crates.io, where
+    /// caret (including a bare `1.2.3`) is dominant, exact is rare, and
+    /// wildcards are very rare.
+    ///
+    /// For a [FullComparator], pair this with a low `weight_of_wildcard_minor`
+    /// and `weight_of_wildcard_patch` in [arb_full_comparator_with_ops].
+    pub const CRATES_IO: OpWeights = OpWeights {
+        exact: 10,
+        greater: 3,
+        greater_eq: 40,
+        less: 10,
+        less_eq: 3,
+        tilde: 30,
+        caret: 900,
+        wildcard: 4,
+    };
+
+    /// Every kind, including [semver::Op::Wildcard], with the same weight.
+    pub const fn uniform(weight: u32) -> Self {
+        OpWeights {
+            exact: weight,
+            greater: weight,
+            greater_eq: weight,
+            less: weight,
+            less_eq: weight,
+            tilde: weight,
+            caret: weight,
+            wildcard: weight,
+        }
+    }
+}
+
+impl Default for OpWeights {
+    /// The weights used by [arb_semver_op], when not otherwise specified.
+    fn default() -> Self {
+        OpWeights {
+            wildcard: 1,
+            ..OpWeights::uniform(5)
+        }
+    }
+}
+
+/// Provides a [semver::Op], with every kind broken out with weights.
+pub fn arb_semver_op_weighted(weights: &OpWeights) -> BoxedStrategy<semver::Op> {
+    prop_oneof! [
+        weights.exact => Just(semver::Op::Exact),
+        weights.greater => Just(semver::Op::Greater),
+        weights.greater_eq => Just(semver::Op::GreaterEq),
+        weights.less => Just(semver::Op::Less),
+        weights.less_eq => Just(semver::Op::LessEq),
+        weights.tilde => Just(semver::Op::Tilde),
+        weights.caret => Just(semver::Op::Caret),
+        weights.wildcard => Just(semver::Op::Wildcard),
+    ]
+    .boxed()
+}
+
+/// Provides a [ComparatorOp], with every kind broken out with weights.
+///
+/// [OpWeights::wildcard] is ignored.
+pub fn arb_comparator_op(weights: &OpWeights) -> BoxedStrategy<ComparatorOp> {
     prop_oneof! [
-        default_weight => Just(semver::Op::Exact),
-        default_weight => Just(semver::Op::Greater),
-        default_weight => Just(semver::Op::GreaterEq),
-        default_weight => Just(semver::Op::Less),
-        default_weight => Just(semver::Op::LessEq),
-        default_weight => Just(semver::Op::Tilde),
-        default_weight => Just(semver::Op::Caret),
-        wildcard_weight => Just(semver::Op::Wildcard),
+        weights.exact => Just(ComparatorOp::Exact),
+        weights.greater => Just(ComparatorOp::Greater),
+        weights.greater_eq => Just(ComparatorOp::GreaterEq),
+        weights.less => Just(ComparatorOp::Less),
+        weights.less_eq => Just(ComparatorOp::LessEq),
+        weights.tilde => Just(ComparatorOp::Tilde),
+        weights.caret => Just(ComparatorOp::Caret),
     ]
     .boxed()
 }
@@ -401,6 +564,23 @@ pub fn arb_full_comparator(
     weight_of_plain: Option<u32>,
     weight_of_wildcard_minor: Option<u32>,
     weight_of_wildcard_patch: Option<u32>,
+) -> impl Strategy<Value = FullComparator> {
+    arb_full_comparator_with_ops(
+        &OpWeights::default(),
+        weight_of_plain,
+        weight_of_wildcard_minor,
+        weight_of_wildcard_patch,
+    )
+}
+
+/// Provides some kind of [semver::Comparator] "thing", like
+/// [arb_full_comparator], with the operator picked by `ops` (see
+/// [arb_comparator_op]).
+pub fn arb_full_comparator_with_ops(
+    ops: &OpWeights,
+    weight_of_plain: Option<u32>,
+    weight_of_wildcard_minor: Option<u32>,
+    weight_of_wildcard_patch: Option<u32>,
 ) -> impl Strategy<Value = FullComparator> {
     // We weight heavily on non-wildcard cases by default, since they "test less".
 
@@ -410,16 +590,16 @@ pub fn arb_full_comparator(
 
     prop_oneof![
         weight_of_wildcard_minor => (
-            any::<ComparatorOp>(),
+            arb_comparator_op(ops),
             any::<u64>(),
         ).prop_map(|(op, major)| FullComparator::WildcardMinor(op, major)),
         weight_of_wildcard_patch => (
-            any::<ComparatorOp>(),
+            arb_comparator_op(ops),
             any::<u64>(),
             any::<u64>(),
         ).prop_map(|(op, major, minor)| FullComparator::WildcardPatch(op, major, minor)),
         weight_of_plain => (
-            any::<ComparatorOp>(),
+            arb_comparator_op(ops),
             any::<u64>(),
             any::<u64>(),
             any::<u64>(),
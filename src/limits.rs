@@ -0,0 +1,229 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+//!
+//! Probes of the internal limits of the `semver` crate, labeled with whether
+//! or not `semver` is expected to accept them.
+use proptest::prelude::*;
+use semver::{BuildMetadata, Prerelease, Version};
+
+use crate::identifier::{
+    arb_alphanumeric_identifier_of_len, arb_build_metadata_identifier_of_len,
+    arb_numeric_identifier_of_len, arb_pre_release_identifier_of_len,
+};
+
+/// The lengths, in bytes, at which [semver::Prerelease] and
+/// [semver::BuildMetadata] change their internal representation.
+///
+/// Strings up to `8` bytes are stored inline, and longer strings are stored on
+/// the heap behind a base-128 varint length, which grows a byte at `128` and
+/// again at `16384` bytes. The next threshold (`2097152` bytes) is too costly
+/// to probe on every test case, and is left out.
+///
+/// None of these are limits on what is accepted, so if a probe at any of these
+/// lengths is ever rejected, something has changed upstream.
+pub const SEMVER_IDENTIFIER_LENGTH_THRESHOLDS: [usize; 3] = [8, 127, 16383];
+
+/// Where in a version an [IdentifierLimitProbe] is placed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IdentifierPosition {
+    Major,
+    Minor,
+    Patch,
+    PreRelease,
+    BuildMetadata,
+}
+
+/// An identifier sized at a limit of the `semver` crate.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IdentifierLimitProbe {
+    pub position: IdentifierPosition,
+
+    /// The identifier being probed, without any `-` or `+` prefix.
+    pub identifier: String,
+
+    /// A complete version string, with the identifier in it's position.
+    pub version: String,
+
+    /// Whether [semver::Version::parse] is expected to accept `version`.
+    pub accepted: bool,
+}
+
+/// Provides identifiers sized right at, and just over, the internal limits of
+/// the `semver` crate, labeled with whether they are expected to be accepted.
+///
+/// * Pre-Release and Build Metadata identifiers are sized at, and one byte
+///   over, each of [SEMVER_IDENTIFIER_LENGTH_THRESHOLDS], and are always
+///   accepted. This includes numeric Pre-Release identifiers, which unlike
+///   `MAJOR.MINOR.PATCH` are not limited to [u64].
+/// * `MAJOR.MINOR.PATCH` components are sized around the digits of
+///   [u64::MAX], and are accepted only when they fit in a [u64].
+pub fn arb_identifier_limit_probe() -> impl Strategy<Value = IdentifierLimitProbe> {
+    let lengths = SEMVER_IDENTIFIER_LENGTH_THRESHOLDS
+        .iter()
+        .flat_map(|t| [*t, t + 1])
+        .collect::<Vec<_>>();
+
+    let long_identifier = (
+        prop::sample::select(lengths),
+        prop::sample::select(vec![
+            IdentifierPosition::PreRelease,
+            IdentifierPosition::BuildMetadata,
+        ]),
+        any::<bool>(),
+    )
+        .prop_flat_map(|(len, position, numeric)| {
+            (Just(position), arb_identifier_of_len(len, numeric))
+        })
+        .prop_map(|(position, identifier)| {
+            let version = match position {
+                IdentifierPosition::PreRelease => format!("1.0.0-{identifier}"),
+                _ => format!("1.0.0+{identifier}"),
+            };
+            IdentifierLimitProbe {
+                position,
+                identifier,
+                version,
+                accepted: true,
+            }
+        });
+
+    let u64_max_digits = u64::MAX.to_string().len();
+    let numeric_component = (
+        prop::sample::select(vec![
+            IdentifierPosition::Major,
+            IdentifierPosition::Minor,
+            IdentifierPosition::Patch,
+        ]),
+        prop_oneof![
+            Just(u64::MAX.to_string()),
+            Just((u64::MAX as u128 + 1).to_string()),
+            arb_identifier_of_len(u64_max_digits - 1, true),
+            arb_identifier_of_len(u64_max_digits, true),
+            arb_identifier_of_len(u64_max_digits + 1, true),
+        ],
+    )
+        .prop_map(|(position, identifier)| {
+            let version = match position {
+                IdentifierPosition::Major => format!("{identifier}.0.0"),
+                IdentifierPosition::Minor => format!("0.{identifier}.0"),
+                _ => format!("0.0.{identifier}"),
+            };
+            IdentifierLimitProbe {
+                position,
+                accepted: identifier.parse::<u64>().is_ok(),
+                identifier,
+                version,
+            }
+        });
+
+    prop_oneof![long_identifier, numeric_component]
+}
+
+/// A single identifier of exactly `len` bytes, which is either numeric without
+/// a leading zero, or alphanumeric.
+fn arb_identifier_of_len(len: usize, numeric: bool) -> BoxedStrategy<String> {
+    if numeric {
+        arb_numeric_identifier_of_len(len..=len)
+    } else {
+        arb_alphanumeric_identifier_of_len(len..=len)
+    }
+}
+
+/// The number of bytes the `semver` crate allocates on the heap for a
+/// Pre-Release or Build Metadata of `len` bytes: nothing when it's stored
+/// inline, and otherwise the string behind it's varint length (see
+/// [SEMVER_IDENTIFIER_LENGTH_THRESHOLDS]).
+fn identifiers_heap_bytes(len: usize) -> usize {
+    if len <= SEMVER_IDENTIFIER_LENGTH_THRESHOLDS[0] {
+        0
+    } else {
+        let mut varint = 1;
+        while len >> (7 * varint) > 0 {
+            varint += 1;
+        }
+        len + varint
+    }
+}
+
+/// The number of bytes a [semver::Version] allocates on the heap, not counting
+/// any overhead of the allocator itself.
+pub fn version_heap_bytes(version: &Version) -> usize {
+    identifiers_heap_bytes(version.pre.len()) + identifiers_heap_bytes(version.build.len())
+}
+
+/// Dot separated identifiers, of at most `max_len` bytes in total.
+///
+/// A target length is drawn first, so lengths spread across `0..=max_len`,
+/// and only about as many identifiers as reach it are generated.
+fn arb_identifiers_within(
+    max_len: usize,
+    identifier: BoxedStrategy<String>,
+) -> impl Strategy<Value = String> {
+    (0..=max_len).prop_flat_map(move |target| {
+        // Each identifier and it's `.` is at least `2` bytes, and usually
+        // more than `4`, so this usually reaches `target`.
+        prop::collection::vec(identifier.clone(), target.div_ceil(4)).prop_map(move |identifiers| {
+            let mut joined = String::new();
+            for i in identifiers {
+                let len = joined.len() + usize::from(!joined.is_empty()) + i.len();
+                if len > target {
+                    break;
+                }
+                if !joined.is_empty() {
+                    joined.push('.');
+                }
+                joined.push_str(&i);
+            }
+            joined
+        })
+    })
+}
+
+/// The most bytes a Pre-Release or Build Metadata can be, while allocating at
+/// most `budget` bytes on the heap.
+fn max_len_within(budget: usize) -> usize {
+    (SEMVER_IDENTIFIER_LENGTH_THRESHOLDS[0]..=budget)
+        .rev()
+        .find(|len| identifiers_heap_bytes(*len) <= budget)
+        .unwrap_or(SEMVER_IDENTIFIER_LENGTH_THRESHOLDS[0])
+}
+
+/// Provides versions which allocate at most `max_bytes` on the heap (see
+/// [version_heap_bytes]), for long running soak tests that shouldn't
+/// accumulate huge values unrelated to the code under test.
+///
+/// The budget is split evenly between the Pre-Release and Build Metadata,
+/// where the length of each is spread across what it's share allows. Each
+/// may still be up to `8` bytes, which is stored inline, even when the
+/// budget is `0`.
+///
+/// * `max_bytes` - The most bytes each version may allocate on the heap.
+pub fn arb_version_bounded_mem(max_bytes: usize) -> impl Strategy<Value = Version> {
+    let pre_len = max_len_within(max_bytes / 2);
+    let build_len = max_len_within(max_bytes - max_bytes / 2);
+
+    (
+        any::<(u64, u64, u64)>(),
+        arb_identifiers_within(pre_len, arb_pre_release_identifier_of_len(1..9, 0.5)),
+        arb_identifiers_within(build_len, arb_build_metadata_identifier_of_len(1..9)),
+    )
+        .prop_map(|((major, minor, patch), pre, build)| Version {
+            major,
+            minor,
+            patch,
+            pre: Prerelease::new(&pre).unwrap(),
+            build: BuildMetadata::new(&build).unwrap(),
+        })
+}
@@ -0,0 +1,134 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+//!
+//! Package names to accompany versions, for registry shaped fixtures.
+use proptest::prelude::*;
+
+/// The maximum length of a crates.io package name.
+pub const MAX_CRATE_NAME_LEN: usize = 64;
+
+/// The maximum length of an npm package name, including any scope.
+pub const MAX_NPM_PACKAGE_NAME_LEN: usize = 214;
+
+/// Well formed names crates.io reserves, and so won't publish, compared
+/// ignoring ASCII case and treating `-` and `_` as equal.
+///
+/// These are the Windows device names, and crates of the Rust distribution,
+/// which isn't every name crates.io reserves.
+pub const RESERVED_CRATE_NAMES: &[&str] = &[
+    "alloc",
+    "aux",
+    "com1",
+    "com2",
+    "com3",
+    "com4",
+    "com5",
+    "com6",
+    "com7",
+    "com8",
+    "com9",
+    "con",
+    "core",
+    "lpt1",
+    "lpt2",
+    "lpt3",
+    "lpt4",
+    "lpt5",
+    "lpt6",
+    "lpt7",
+    "lpt8",
+    "lpt9",
+    "nul",
+    "proc_macro",
+    "prn",
+    "std",
+    "test",
+];
+
+/// An npm package name, or scope, without the `@`.
+const NPM_NAME_REGEX: &str = "[a-z0-9-][a-z0-9._~-]{0,49}";
+
+prop_compose! {
+    /// Provides a valid crates.io package name, of ASCII alphanumerics, `-`
+    /// and `_`, starting with a letter, and at most [MAX_CRATE_NAME_LEN]
+    /// long.
+    pub fn arb_crate_name()(name in "[a-zA-Z][a-zA-Z0-9_-]{0,63}") -> String {
+        name
+    }
+}
+
+/// Returns `true` if `name` is one of [RESERVED_CRATE_NAMES].
+pub fn is_reserved_crate_name(name: &str) -> bool {
+    let name = name.to_ascii_lowercase().replace('-', "_");
+    RESERVED_CRATE_NAMES.contains(&name.as_str())
+}
+
+/// Provides crate names like [arb_crate_name], which are sometimes reserved
+/// by crates.io (see [RESERVED_CRATE_NAMES]), or never are.
+///
+/// * `probability_of_reserved` - The probability of a reserved name (in any
+///   case, and with `-` or `_`), from `0.0` to `1.0`. When `0.0`, reserved
+///   names are avoided entirely.
+pub fn arb_crate_name_with_reserved(probability_of_reserved: f64) -> impl Strategy<Value = String> {
+    let reserved =
+        (prop::sample::select(RESERVED_CRATE_NAMES), any::<u64>()).prop_map(|(name, case)| {
+            name.chars()
+                .enumerate()
+                .map(|(i, c)| match c {
+                    '_' if case & (1 << i) != 0 => '-',
+                    c if case & (1 << i) != 0 => c.to_ascii_uppercase(),
+                    c => c,
+                })
+                .collect::<String>()
+        });
+    let unreserved = arb_crate_name().prop_filter("the name must not be reserved", |name| {
+        !is_reserved_crate_name(name)
+    });
+
+    (
+        prop::bool::weighted(probability_of_reserved),
+        reserved,
+        unreserved,
+    )
+        .prop_map(
+            |(is_reserved, reserved, unreserved)| {
+                if is_reserved {
+                    reserved
+                } else {
+                    unreserved
+                }
+            },
+        )
+}
+
+prop_compose! {
+    /// Provides a valid npm package name, which is sometimes scoped (such as
+    /// `@scope/name`).
+    ///
+    /// Names and scopes are lowercase, URL safe, and never start with a `.` or
+    /// `_`. Names are kept well under [MAX_NPM_PACKAGE_NAME_LEN].
+    ///
+    /// * `probability_of_scope` - Follows [proptest::option::Probability]
+    ///   rules.
+    pub fn arb_npm_package_name(probability_of_scope: f64)(
+        scope in prop::option::weighted(probability_of_scope, NPM_NAME_REGEX),
+        name in NPM_NAME_REGEX,
+    ) -> String {
+        match scope {
+            Some(scope) => format!("@{scope}/{name}"),
+            None => name,
+        }
+    }
+}
@@ -0,0 +1,349 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+//!
+//! Pairs of values, with their expected ordering, targeting specific rules of
+//! precedence from the spec.
+use proptest::prelude::*;
+use semver::{BuildMetadata, Prerelease, Version};
+use std::cmp::Ordering;
+
+use crate::identifier::arb_numeric_identifier_of_len;
+use crate::precedence::arb_version_above;
+use crate::{
+    arb_identifier, arb_option_semver_build_metadata, arb_semver_build_metadata, arb_version,
+};
+
+/// An alphanumeric identifier, which always contains at least one ASCII
+/// letter.
+fn arb_lettered_identifier() -> impl Strategy<Value = String> {
+    (
+        arb_identifier(None, None),
+        any::<prop::sample::Index>(),
+        prop::char::ranges(vec!['a'..='z', 'A'..='Z'].into()),
+    )
+        .prop_map(|(identifier, index, letter)| {
+            let mut chars = identifier.chars().collect::<Vec<_>>();
+            chars.insert(index.index(chars.len() + 1), letter);
+            chars.into_iter().collect()
+        })
+}
+
+prop_compose! {
+    /// Provides a pair of Pre-Release strings (no `-` prefix), which are
+    /// identical except for the ASCII case of at least one letter, with the
+    /// ordering of the first to the second by precedence.
+    ///
+    /// Identifiers with letters are compared lexically in ASCII sort order, so
+    /// an uppercase letter always has a lower precedence than it's lowercase
+    /// letter (`RC.1` < `rc.1`).
+    pub fn arb_pre_release_case_variants()(
+        before in prop::collection::vec(arb_identifier(None, None), 0..3),
+        lettered in arb_lettered_identifier(),
+        after in prop::collection::vec(arb_identifier(None, None), 0..3),
+        flips in prop::collection::vec(any::<bool>(), 64),
+        forced in any::<prop::sample::Index>(),
+    ) -> (String, String, Ordering) {
+        let a = before
+            .into_iter()
+            .chain(std::iter::once(lettered))
+            .chain(after)
+            .collect::<Vec<_>>()
+            .join(".");
+
+        let letters = a
+            .char_indices()
+            .filter(|(_, c)| c.is_ascii_alphabetic())
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+        let forced = letters[forced.index(letters.len())];
+
+        let b = a
+            .char_indices()
+            .map(|(i, c)| {
+                if i == forced || flips[i % flips.len()] {
+                    flip_ascii_case(c)
+                } else {
+                    c
+                }
+            })
+            .collect::<String>();
+
+        // Only letters differ, so every differing identifier is compared
+        // lexically, and the first differing letter decides.
+        let ordering = a
+            .chars()
+            .zip(b.chars())
+            .find(|(x, y)| x != y)
+            .map(|(x, y)| x.cmp(&y))
+            .unwrap();
+        (a, b, ordering)
+    }
+}
+
+prop_compose! {
+    /// Provides a pair of Pre-Release strings (no `-` prefix), which are
+    /// identical except for a single identifier, which is numeric in one
+    /// (`1`), and alphanumeric in the other (`1a`), with the ordering of the
+    /// first to the second by precedence.
+    ///
+    /// Numeric identifiers always have a lower precedence than alphanumeric
+    /// identifiers, regardless of their digits, so the numeric side is always
+    /// the lower one.
+    pub fn arb_ident_kind_boundary_pair()(
+        before in prop::collection::vec(arb_identifier(None, None), 0..3),
+        numeric in arb_numeric_identifier_of_len(1..8),
+        extra in prop::char::ranges(vec!['a'..='z', 'A'..='Z', '-'..='-'].into()),
+        position in any::<prop::sample::Index>(),
+        after in prop::collection::vec(arb_identifier(None, None), 0..3),
+        swap in any::<bool>(),
+    ) -> (String, String, Ordering) {
+        let mut alphanumeric = numeric.clone();
+        alphanumeric.insert(position.index(numeric.len() + 1), extra);
+
+        let join = |identifier: String| {
+            before
+                .iter()
+                .cloned()
+                .chain(std::iter::once(identifier))
+                .chain(after.iter().cloned())
+                .collect::<Vec<_>>()
+                .join(".")
+        };
+        let (numeric, alphanumeric) = (join(numeric), join(alphanumeric));
+
+        if swap {
+            (alphanumeric, numeric, Ordering::Greater)
+        } else {
+            (numeric, alphanumeric, Ordering::Less)
+        }
+    }
+}
+
+prop_compose! {
+    /// Provides a pair of Pre-Release strings (no `-` prefix), where one is a
+    /// strict prefix of the other by identifiers (`alpha` vs `alpha.1`), with
+    /// the ordering of the first to the second by precedence.
+    ///
+    /// When all preceding identifiers are equal, a larger set of fields has a
+    /// higher precedence, so the shorter side is always the lower one.
+    pub fn arb_ident_count_pair()(
+        shared in prop::collection::vec(arb_identifier(None, None), 1..4),
+        extra in prop::collection::vec(arb_identifier(None, None), 1..3),
+        swap in any::<bool>(),
+    ) -> (String, String, Ordering) {
+        let shorter = shared.join(".");
+        let longer = shared.into_iter().chain(extra).collect::<Vec<_>>().join(".");
+
+        if swap {
+            (longer, shorter, Ordering::Greater)
+        } else {
+            (shorter, longer, Ordering::Less)
+        }
+    }
+}
+
+fn flip_ascii_case(c: char) -> char {
+    if c.is_ascii_lowercase() {
+        c.to_ascii_uppercase()
+    } else {
+        c.to_ascii_lowercase()
+    }
+}
+
+/// A pair of numeric identifiers `(low, high)`, where `low < high` by value,
+/// but `low` sorts after `high` as a string (`19` vs `100`).
+fn arb_numeric_trap() -> impl Strategy<Value = (String, String)> {
+    (
+        "([1-9][0-9]{0,2})?",
+        0..9u8,
+        1..=9u8,
+        "[0-9]{0,2}",
+        1..4usize,
+        "[0-9]{3}",
+    )
+        .prop_map(|(prefix, a, b, low_rest, extra, padding)| {
+            // Without a prefix, the differing digit leads, so can't be `0`.
+            let a = if prefix.is_empty() { a.max(1) } else { a };
+            let b = b.max(a + 1);
+
+            let low = format!("{prefix}{b}{low_rest}");
+            let high_rest = format!("{low_rest}{}", &padding[..extra]);
+            let high = format!("{prefix}{a}{high_rest}");
+            (low, high)
+        })
+}
+
+/// The shapes of pair generated by [arb_lexicographic_trap_pair].
+#[derive(Clone, Copy, Debug)]
+enum Trap {
+    Major,
+    Minor,
+    Patch,
+    PreRelease,
+    Release,
+}
+
+prop_compose! {
+    /// Provides a pair of versions, where the ordering of their strings
+    /// disagrees with the ordering by precedence, with the ordering of the
+    /// first to the second by precedence.
+    ///
+    /// This catches code which sorts versions as strings, for example:
+    ///
+    /// * A numeric component with more digits (`1.9.0` < `1.10.0`).
+    /// * A numeric Pre-Release identifier with more digits (`1.0.0-rc.2` <
+    ///   `1.0.0-rc.10`).
+    /// * A release being a string prefix of it's Pre-Release (`1.0.0-rc.1` <
+    ///   `1.0.0`).
+    ///
+    /// The strings never compare as equal, and the versions never have Build
+    /// Metadata.
+    pub fn arb_lexicographic_trap_pair()(
+        trap in prop_oneof![
+            Just(Trap::Major),
+            Just(Trap::Minor),
+            Just(Trap::Patch),
+            Just(Trap::PreRelease),
+            Just(Trap::Release),
+        ],
+        (low, high) in arb_numeric_trap(),
+        triple in (0..100u64, 0..100u64, 0..100u64),
+        before in prop::collection::vec(arb_lettered_identifier(), 1..3),
+        after in prop::collection::vec(arb_identifier(None, None), 0..2),
+        swap in any::<bool>(),
+    ) -> (Version, Version, Ordering) {
+        let (major, minor, patch) = triple;
+        let build = |major: u64, minor: u64, patch: u64, pre: &str| Version {
+            major,
+            minor,
+            patch,
+            pre: Prerelease::new(pre).unwrap(),
+            build: BuildMetadata::EMPTY,
+        };
+        let (low, high): (u64, u64) = (low.parse().unwrap(), high.parse().unwrap());
+        let pre = |n: Option<u64>| {
+            before
+                .iter()
+                .cloned()
+                .chain(n.map(|n| n.to_string()))
+                .chain(after.iter().cloned())
+                .collect::<Vec<_>>()
+                .join(".")
+        };
+
+        let (lower, higher) = match trap {
+            Trap::Major => (build(low, minor, patch, ""), build(high, minor, patch, "")),
+            Trap::Minor => (build(major, low, patch, ""), build(major, high, patch, "")),
+            Trap::Patch => (build(major, minor, low, ""), build(major, minor, high, "")),
+            Trap::PreRelease => (
+                build(major, minor, patch, &pre(Some(low))),
+                build(major, minor, patch, &pre(Some(high))),
+            ),
+            Trap::Release => (
+                build(major, minor, patch, &pre(None)),
+                build(major, minor, patch, ""),
+            ),
+        };
+
+        if swap {
+            (higher, lower, Ordering::Greater)
+        } else {
+            (lower, higher, Ordering::Less)
+        }
+    }
+}
+
+/// Build Metadata greater than `build` by [Ord].
+fn arb_build_metadata_above(build: &BuildMetadata) -> BoxedStrategy<BuildMetadata> {
+    if build.is_empty() {
+        arb_semver_build_metadata().boxed()
+    } else {
+        let build = build.clone();
+        arb_identifier(None, None)
+            .prop_map(move |extra| BuildMetadata::new(&format!("{build}.{extra}")).unwrap())
+            .boxed()
+    }
+}
+
+/// A pair of versions, where the first is less than the second by [Ord].
+fn arb_ordered_version_pair() -> impl Strategy<Value = (Version, Version)> {
+    arb_version().prop_flat_map(|a| {
+        // Only the Build Metadata differs.
+        let same_precedence = {
+            let a = a.clone();
+            arb_build_metadata_above(&a.build).prop_map(move |build| {
+                let b = Version {
+                    build,
+                    ..a.clone()
+                };
+                (a.clone(), b)
+            })
+        }
+        .boxed();
+
+        match arb_version_above(&a) {
+            Some(above) => prop_oneof![
+                1 => same_precedence,
+                3 => (above, arb_option_semver_build_metadata(0.25)).prop_map(move |(mut b, build)| {
+                    b.build = build.unwrap_or(BuildMetadata::EMPTY);
+                    (a.clone(), b)
+                }),
+            ]
+            .boxed(),
+            None => same_precedence,
+        }
+    })
+}
+
+/// Provides a pair of versions `(a, b)`, where `a.cmp(&b)` is `ordering`, for
+/// testing custom comparators, sorts and database collations.
+///
+/// Unequal pairs mostly differ by precedence (including Pre-Releases of the
+/// same `MAJOR.MINOR.PATCH`, see [Version::cmp_precedence]), and sometimes
+/// only by Build Metadata, which [Ord] compares when precedence is equal.
+///
+/// * `ordering` - The ordering of `a` to `b`.
+pub fn arb_version_pair_ordered(ordering: Ordering) -> impl Strategy<Value = (Version, Version)> {
+    match ordering {
+        Ordering::Less => arb_ordered_version_pair().boxed(),
+        Ordering::Greater => arb_ordered_version_pair().prop_map(|(a, b)| (b, a)).boxed(),
+        Ordering::Equal => arb_version().prop_map(|a| (a.clone(), a)).boxed(),
+    }
+}
+
+/// Provides a pair of versions `(a, b)` with equal precedence (see
+/// [Version::cmp_precedence]), but different Build Metadata, for testing that
+/// ordering ignores Build Metadata while display preserves it.
+///
+/// Either version's Build Metadata may be empty, but never both. Since only
+/// Build Metadata differs, `a != b`, and [Ord] never orders them as equal.
+pub fn arb_precedence_equal_pair() -> impl Strategy<Value = (Version, Version)> {
+    (arb_version(), any::<bool>())
+        .prop_flat_map(|(a, swap)| {
+            (
+                Just(a.clone()),
+                arb_build_metadata_above(&a.build),
+                Just(swap),
+            )
+        })
+        .prop_map(|(a, build, swap)| {
+            let b = Version { build, ..a.clone() };
+            if swap {
+                (b, a)
+            } else {
+                (a, b)
+            }
+        })
+}
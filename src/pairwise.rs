@@ -0,0 +1,107 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+//!
+//! A small, deterministic set of versions covering every pair of values of
+//! some version features, so that even a short run is guaranteed some
+//! combinatorial coverage.
+//!
+//! Use it with [proptest::sample::select], or iterate it directly.
+use semver::{BuildMetadata, Prerelease, Version};
+
+/// Which component of a version sits at [u64::MAX].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BoundaryComponent {
+    None,
+    Minor,
+    Patch,
+}
+
+/// The features of a version from [pairwise_versions].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct VersionFeatures {
+    pub has_pre_release: bool,
+    pub has_build_metadata: bool,
+
+    /// When `true`, `MAJOR` is `0`.
+    pub zero_major: bool,
+
+    pub boundary: BoundaryComponent,
+}
+
+impl VersionFeatures {
+    /// The version with these features.
+    pub fn version(&self) -> Version {
+        let at = |c: BoundaryComponent, otherwise: u64| {
+            if self.boundary == c {
+                u64::MAX
+            } else {
+                otherwise
+            }
+        };
+
+        Version {
+            major: if self.zero_major { 0 } else { 1 },
+            minor: at(BoundaryComponent::Minor, 2),
+            patch: at(BoundaryComponent::Patch, 3),
+            pre: if self.has_pre_release {
+                Prerelease::new("alpha.1").unwrap()
+            } else {
+                Prerelease::EMPTY
+            },
+            build: if self.has_build_metadata {
+                BuildMetadata::new("build.5").unwrap()
+            } else {
+                BuildMetadata::EMPTY
+            },
+        }
+    }
+}
+
+/// A covering array of strength 2 over (Pre-Release, Build Metadata, zero
+/// `MAJOR`, [BoundaryComponent]), in `(pre, build, zero)` order.
+///
+/// Within each [BoundaryComponent], the two rows are complements of each
+/// other, and across them, every pair of the boolean features takes every
+/// combination.
+const COVERING_ARRAY: [(bool, bool, bool, BoundaryComponent); 6] = [
+    (false, false, false, BoundaryComponent::None),
+    (true, true, true, BoundaryComponent::None),
+    (false, true, true, BoundaryComponent::Minor),
+    (true, false, false, BoundaryComponent::Minor),
+    (true, false, true, BoundaryComponent::Patch),
+    (false, true, false, BoundaryComponent::Patch),
+];
+
+/// Provides the versions, with their [VersionFeatures], covering every pair of
+/// values of any two features, in the same order every time.
+///
+/// Six versions suffice, where covering every combination would take
+/// twenty-four.
+pub fn pairwise_versions() -> Vec<(VersionFeatures, Version)> {
+    COVERING_ARRAY
+        .iter()
+        .map(
+            |&(has_pre_release, has_build_metadata, zero_major, boundary)| {
+                let features = VersionFeatures {
+                    has_pre_release,
+                    has_build_metadata,
+                    zero_major,
+                    boundary,
+                };
+                (features, features.version())
+            },
+        )
+        .collect()
+}
@@ -0,0 +1,273 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+//!
+//! Strategies for versions positioned by precedence relative to other
+//! versions, solved directly rather than by filtering.
+use proptest::prelude::*;
+use proptest::strategy::Union;
+use semver::{Prerelease, Version};
+use std::ops::Bound;
+
+use crate::{arb_option_semver_prerelease, arb_pre_release_string, VersionInterval};
+
+pub(crate) type Triple = (u64, u64, u64);
+
+pub(crate) const MAX_TRIPLE: Triple = (u64::MAX, u64::MAX, u64::MAX);
+
+/// Entropy for picking a number within a range, biased toward the start of
+/// the range so that boundaries are well covered.
+fn arb_offset() -> impl Strategy<Value = u64> {
+    prop_oneof![0u64..4, any::<u64>()]
+}
+
+pub(crate) fn arb_offsets() -> impl Strategy<Value = Triple> {
+    (arb_offset(), arb_offset(), arb_offset())
+}
+
+/// Picks a number in `low..=high` with `offset`.
+fn pick(low: u64, high: u64, offset: u64) -> u64 {
+    let span = (high - low) as u128 + 1;
+    low + ((offset as u128) % span) as u64
+}
+
+/// Picks a `MAJOR.MINOR.PATCH` in `lower..=upper`, where `lower <= upper`.
+pub(crate) fn triple_between(lower: Triple, upper: Triple, offsets: Triple) -> Triple {
+    let major = pick(lower.0, upper.0, offsets.0);
+
+    let minor_low = if major == lower.0 { lower.1 } else { 0 };
+    let minor_high = if major == upper.0 { upper.1 } else { u64::MAX };
+    let minor = pick(minor_low, minor_high, offsets.1);
+
+    let patch_low = if (major, minor) == (lower.0, lower.1) {
+        lower.2
+    } else {
+        0
+    };
+    let patch_high = if (major, minor) == (upper.0, upper.1) {
+        upper.2
+    } else {
+        u64::MAX
+    };
+    let patch = pick(patch_low, patch_high, offsets.2);
+
+    (major, minor, patch)
+}
+
+pub(crate) fn triple(v: &Version) -> Triple {
+    (v.major, v.minor, v.patch)
+}
+
+pub(crate) fn previous_triple((major, minor, patch): Triple) -> Option<Triple> {
+    if let Some(patch) = patch.checked_sub(1) {
+        Some((major, minor, patch))
+    } else if let Some(minor) = minor.checked_sub(1) {
+        Some((major, minor, u64::MAX))
+    } else {
+        Some((major.checked_sub(1)?, u64::MAX, u64::MAX))
+    }
+}
+
+pub(crate) fn next_triple((major, minor, patch): Triple) -> Option<Triple> {
+    if let Some(patch) = patch.checked_add(1) {
+        Some((major, minor, patch))
+    } else if let Some(minor) = minor.checked_add(1) {
+        Some((major, minor, 0))
+    } else {
+        Some((major.checked_add(1)?, 0, 0))
+    }
+}
+
+pub(crate) fn version_of((major, minor, patch): Triple, pre: Option<Prerelease>) -> Version {
+    let mut v = Version::new(major, minor, patch);
+    v.pre = pre.unwrap_or(Prerelease::EMPTY);
+    v
+}
+
+/// Appends identifiers to a Pre-Release, which always raises it's precedence.
+pub(crate) fn extend_pre_release(pre: &Prerelease, extra: &str) -> Prerelease {
+    Prerelease::new(&format!("{pre}.{extra}")).unwrap()
+}
+
+/// Versions with a `MAJOR.MINOR.PATCH` in `lower..=upper`, that are sometimes
+/// a Pre-Release.
+fn arb_version_with_triple_between(lower: Triple, upper: Triple) -> BoxedStrategy<Version> {
+    (arb_offsets(), arb_option_semver_prerelease(0.5))
+        .prop_map(move |(offsets, pre)| version_of(triple_between(lower, upper, offsets), pre))
+        .boxed()
+}
+
+/// Pre-Releases with a lower precedence than `pre`, if there are any.
+fn arb_pre_release_below(pre: &Prerelease) -> Option<BoxedStrategy<Prerelease>> {
+    let identifiers: Vec<String> = pre.split('.').map(String::from).collect();
+    let mut options = Vec::new();
+
+    // Any proper prefix has fewer fields, and so a lower precedence.
+    if identifiers.len() > 1 {
+        let prefixes = (1..identifiers.len())
+            .map(|len| Prerelease::new(&identifiers[..len].join(".")).unwrap())
+            .collect::<Vec<_>>();
+        options.push(prop::sample::select(prefixes).boxed());
+    }
+
+    let first = &identifiers[0];
+    if first.bytes().all(|b| b.is_ascii_digit()) {
+        // Numeric identifiers are compared numerically, and may exceed u64.
+        let n = first.parse::<u64>().unwrap_or(u64::MAX);
+        if n > 0 {
+            options.push(
+                (0..n)
+                    .prop_map(|m| Prerelease::new(&m.to_string()).unwrap())
+                    .boxed(),
+            );
+        }
+    } else {
+        // Numeric identifiers always have lower precedence than alphanumeric
+        // identifiers.
+        options.push(
+            any::<u64>()
+                .prop_map(|m| Prerelease::new(&m.to_string()).unwrap())
+                .boxed(),
+        );
+    }
+
+    if options.is_empty() {
+        None
+    } else {
+        Some(Union::new(options).boxed())
+    }
+}
+
+/// Provides versions with a strictly lower precedence than `v`, for testing
+/// "only consider versions older than `v`" code paths, such as downgrade
+/// protection, or `None` if `v` is `0.0.0-0` (which nothing is below).
+///
+/// Pre-Releases of `v`'s own `MAJOR.MINOR.PATCH` are below it, when `v` is a
+/// release (such as `1.2.3-alpha` below `1.2.3`), or when they have a lower
+/// precedence than it's Pre-Release (such as `1.2.3-1` or `1.2.3-alpha`
+/// below `1.2.3-alpha.1`). Versions never have Build Metadata.
+pub fn arb_version_below(v: &Version) -> Option<BoxedStrategy<Version>> {
+    let mut options = Vec::new();
+    let t = triple(v);
+
+    if let Some(previous) = previous_triple(t) {
+        options.push(arb_version_with_triple_between((0, 0, 0), previous));
+    }
+
+    if v.pre.is_empty() {
+        options.push(
+            arb_pre_release_string()
+                .prop_map(move |pre| version_of(t, Some(Prerelease::new(&pre).unwrap())))
+                .boxed(),
+        );
+    } else if let Some(pre) = arb_pre_release_below(&v.pre) {
+        options.push(pre.prop_map(move |pre| version_of(t, Some(pre))).boxed());
+    }
+
+    if options.is_empty() {
+        None
+    } else {
+        Some(Union::new(options).boxed())
+    }
+}
+
+/// Provides versions with a strictly higher precedence than `v`, for testing
+/// upgrade-only enforcement and anti-rollback checks, or `None` if `v` is
+/// `MAX.MAX.MAX` (which nothing is above).
+///
+/// When `v` is a Pre-Release, it's own release is above it (such as `1.2.3`
+/// above `1.2.3-alpha`), as are longer Pre-Releases of it (such as
+/// `1.2.3-alpha.1`). Versions never have Build Metadata.
+pub fn arb_version_above(v: &Version) -> Option<BoxedStrategy<Version>> {
+    let mut options = Vec::new();
+    let t = triple(v);
+
+    if let Some(next) = next_triple(t) {
+        options.push(arb_version_with_triple_between(next, MAX_TRIPLE));
+    }
+
+    if !v.pre.is_empty() {
+        let pre = v.pre.clone();
+        options.push(Just(version_of(t, None)).boxed());
+        options.push(
+            arb_pre_release_string()
+                .prop_map(move |extra| version_of(t, Some(extend_pre_release(&pre, &extra))))
+                .boxed(),
+        );
+    }
+
+    if options.is_empty() {
+        None
+    } else {
+        Some(Union::new(options).boxed())
+    }
+}
+
+/// Versions which are members of `interval`, if there are any.
+pub(crate) fn arb_version_in_interval(
+    interval: &VersionInterval,
+) -> Option<BoxedStrategy<Version>> {
+    // The lowest member of the interval.
+    let lowest = match &interval.lower {
+        Bound::Unbounded if interval.includes_prerelease => {
+            version_of((0, 0, 0), Some(Prerelease::new("0").unwrap()))
+        }
+        Bound::Unbounded => Version::new(0, 0, 0),
+        Bound::Included(v) if interval.includes_prerelease || v.pre.is_empty() => v.clone(),
+        Bound::Excluded(v) if interval.includes_prerelease && !v.pre.is_empty() => {
+            version_of(triple(v), Some(extend_pre_release(&v.pre, "0")))
+        }
+        Bound::Excluded(v) if v.pre.is_empty() => version_of(next_triple(triple(v))?, None),
+        Bound::Included(v) | Bound::Excluded(v) => version_of(triple(v), None),
+    };
+
+    if !interval.contains(&lowest) {
+        return None;
+    }
+
+    if interval.includes_prerelease {
+        // Members are the Pre-Releases of a single `MAJOR.MINOR.PATCH`, so we
+        // only wander a little above the lowest member.
+        let interval = interval.clone();
+        let pre = lowest.pre.clone();
+        return Some(
+            prop_oneof![
+                Just(lowest.clone()),
+                arb_pre_release_string().prop_map(move |extra| {
+                    let v = version_of(triple(&lowest), Some(extend_pre_release(&pre, &extra)));
+                    if interval.contains(&v) {
+                        v
+                    } else {
+                        lowest.clone()
+                    }
+                }),
+            ]
+            .boxed(),
+        );
+    }
+
+    let highest = match &interval.upper {
+        Bound::Unbounded => MAX_TRIPLE,
+        Bound::Included(v) if v.pre.is_empty() => triple(v),
+        Bound::Excluded(v) if v.pre.is_empty() => previous_triple(triple(v))?,
+        Bound::Included(v) | Bound::Excluded(v) => previous_triple(triple(v))?,
+    };
+    let lowest = triple(&lowest);
+
+    Some(
+        arb_offsets()
+            .prop_map(move |offsets| version_of(triple_between(lowest, highest, offsets), None))
+            .boxed(),
+    )
+}
@@ -0,0 +1,255 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+//!
+//! Loading generation profiles (such as a [Shape], [OpWeights], or any of the
+//! `*Params` of [crate::arbitrary]) from TOML or JSON, so a team can check a
+//! profile into their repo instead of hard-coding numbers in tests, and
+//! writing them back out.
+//!
+//! This requires the `serde` feature, and checks a profile can be generated
+//! from when it's loaded, rather than panicking once it's used.
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fmt;
+use std::ops::Range;
+
+use crate::shape::MAX_DIGITS;
+use crate::{
+    try_arb_full_comparator_vec, try_arb_full_comparator_with_ops, BuildMetadataParams,
+    ComparatorVecParams, FullComparatorParams, OpWeights, PrereleaseParams, Shape, VersionParams,
+    MAX_COMPARATORS_IN_VERSION_REQ_STRING,
+};
+
+/// Why a profile couldn't be loaded.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProfileError(String);
+
+impl fmt::Display for ProfileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid profile: {}", self.0)
+    }
+}
+
+impl std::error::Error for ProfileError {}
+
+fn error<T>(message: impl Into<String>) -> Result<T, ProfileError> {
+    Err(ProfileError(message.into()))
+}
+
+fn from_toml<T: DeserializeOwned>(s: &str) -> Result<T, ProfileError> {
+    toml::from_str(s).map_err(|e| ProfileError(e.message().to_string()))
+}
+
+fn from_json<T: DeserializeOwned>(s: &str) -> Result<T, ProfileError> {
+    // NOTE(canardleteer): `serde` would otherwise also accept an array of
+    //                     every field, in order.
+    let value =
+        serde_json::from_str::<serde_json::Value>(s).map_err(|e| ProfileError(e.to_string()))?;
+    if !value.is_object() {
+        return error("expected a single object");
+    }
+    T::deserialize(value).map_err(|e| ProfileError(e.to_string()))
+}
+
+// NOTE(canardleteer): A profile is only numbers and `[start, end]` pairs, so
+//                     neither of these can fail.
+fn to_toml<T: Serialize>(value: &T) -> String {
+    toml::to_string(value).expect("a profile is valid TOML")
+}
+
+fn to_json<T: Serialize>(value: &T) -> String {
+    serde_json::to_string_pretty(value).expect("a profile is valid JSON")
+}
+
+/// Checks `range` starts at `1` or more, where it's already non-empty (see
+/// [crate::serialize]).
+fn check_start(key: &str, range: &Range<usize>) -> Result<(), ProfileError> {
+    if range.start < 1 {
+        error(format!("`{key}` must start at 1 or more"))
+    } else {
+        Ok(())
+    }
+}
+
+impl Shape {
+    /// Loads a [Shape] from TOML, where missing keys keep their default,
+    /// such as:
+    ///
+    /// ```toml
+    /// has_pre = 0.2
+    /// pre_idents = [1, 3]
+    /// digits = [1, 3]
+    /// ```
+    pub fn from_toml_str(s: &str) -> Result<Self, ProfileError> {
+        from_toml::<Self>(s)?.checked()
+    }
+
+    /// Loads a [Shape] from JSON, like [Shape::from_toml_str], such as
+    /// `{"has_pre": 0.2, "pre_idents": [1, 3]}`.
+    pub fn from_json_str(s: &str) -> Result<Self, ProfileError> {
+        from_json::<Self>(s)?.checked()
+    }
+
+    /// Writes this [Shape] as TOML, which [Shape::from_toml_str] loads.
+    pub fn to_toml_string(&self) -> String {
+        to_toml(self)
+    }
+
+    /// Writes this [Shape] as JSON, which [Shape::from_json_str] loads.
+    pub fn to_json_string(&self) -> String {
+        to_json(self)
+    }
+
+    /// Checks this can be used with [crate::arb_version_shaped].
+    fn checked(self) -> Result<Self, ProfileError> {
+        check_start("pre_idents", &self.pre_idents)?;
+        check_start("build_idents", &self.build_idents)?;
+        check_start("digits", &self.digits)?;
+        if self.digits.start > MAX_DIGITS {
+            return error(format!("`digits` must start at {MAX_DIGITS} or less"));
+        }
+        Ok(self)
+    }
+}
+
+impl OpWeights {
+    /// Loads [OpWeights] from TOML, where missing keys keep their
+    /// [OpWeights::default], such as:
+    ///
+    /// ```toml
+    /// caret = 900
+    /// tilde = 30
+    /// ```
+    pub fn from_toml_str(s: &str) -> Result<Self, ProfileError> {
+        from_toml::<Self>(s)?.checked()
+    }
+
+    /// Loads [OpWeights] from JSON, like [OpWeights::from_toml_str], such as
+    /// `{"caret": 900, "tilde": 30}`.
+    pub fn from_json_str(s: &str) -> Result<Self, ProfileError> {
+        from_json::<Self>(s)?.checked()
+    }
+
+    /// Writes these [OpWeights] as TOML, which [OpWeights::from_toml_str]
+    /// loads.
+    pub fn to_toml_string(&self) -> String {
+        to_toml(self)
+    }
+
+    /// Writes these [OpWeights] as JSON, which [OpWeights::from_json_str]
+    /// loads.
+    pub fn to_json_string(&self) -> String {
+        to_json(self)
+    }
+
+    /// Checks these can be sampled from by [crate::arb_semver_op_weighted].
+    fn checked(self) -> Result<Self, ProfileError> {
+        match self.check_semver_op() {
+            Ok(()) => Ok(self),
+            Err(e) => error(e.to_string()),
+        }
+    }
+}
+
+/// Implements loading and writing a `*Params` profile, like [Shape], given a
+/// `checked` method for what it's `serde` representation doesn't already
+/// check.
+macro_rules! params_profile {
+    ($params:ident) => {
+        impl $params {
+            #[doc = concat!("Loads [", stringify!($params), "] from TOML, where missing keys keep their default.")]
+            pub fn from_toml_str(s: &str) -> Result<Self, ProfileError> {
+                from_toml::<Self>(s)?.checked()
+            }
+
+            #[doc = concat!("Loads [", stringify!($params), "] from JSON, where missing keys keep their default.")]
+            pub fn from_json_str(s: &str) -> Result<Self, ProfileError> {
+                from_json::<Self>(s)?.checked()
+            }
+
+            #[doc = concat!("Writes this [", stringify!($params), "] as TOML.")]
+            pub fn to_toml_string(&self) -> String {
+                to_toml(self)
+            }
+
+            #[doc = concat!("Writes this [", stringify!($params), "] as JSON.")]
+            pub fn to_json_string(&self) -> String {
+                to_json(self)
+            }
+        }
+    };
+}
+
+params_profile!(PrereleaseParams);
+params_profile!(BuildMetadataParams);
+params_profile!(VersionParams);
+params_profile!(FullComparatorParams);
+params_profile!(ComparatorVecParams);
+
+// NOTE(canardleteer): Identifier counts and lengths, and probabilities, are
+//                     checked by their `serde` representation.
+impl PrereleaseParams {
+    fn checked(self) -> Result<Self, ProfileError> {
+        Ok(self)
+    }
+}
+
+impl BuildMetadataParams {
+    fn checked(self) -> Result<Self, ProfileError> {
+        Ok(self)
+    }
+}
+
+impl VersionParams {
+    fn checked(self) -> Result<Self, ProfileError> {
+        Ok(self)
+    }
+}
+
+impl FullComparatorParams {
+    /// Checks these can be sampled from by
+    /// [crate::arb_full_comparator_with_ops].
+    fn checked(self) -> Result<Self, ProfileError> {
+        match try_arb_full_comparator_with_ops(
+            &self.ops,
+            self.weight_of_plain,
+            self.weight_of_wildcard_minor,
+            self.weight_of_wildcard_patch,
+        ) {
+            Ok(_) => Ok(self),
+            Err(e) => error(e.to_string()),
+        }
+    }
+}
+
+impl ComparatorVecParams {
+    /// Checks these can be sampled from by [crate::arb_full_comparator_vec],
+    /// and parsed as a [semver::VersionReq].
+    fn checked(self) -> Result<Self, ProfileError> {
+        if self.max_comparators > MAX_COMPARATORS_IN_VERSION_REQ_STRING {
+            return error(format!(
+                "`max_comparators` must be {MAX_COMPARATORS_IN_VERSION_REQ_STRING} or less"
+            ));
+        }
+        match try_arb_full_comparator_vec(
+            self.max_comparators,
+            self.weight_of_wildcard,
+            self.weight_of_comparator_list,
+        ) {
+            Ok(_) => Ok(self),
+            Err(e) => error(e.to_string()),
+        }
+    }
+}
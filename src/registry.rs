@@ -0,0 +1,469 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+//!
+//! Whole registries of packages and their published versions, for one-call
+//! setup of integration-style tests of registry services.
+use proptest::prelude::*;
+use proptest::strategy::Union;
+use semver::{BuildMetadata, Version, VersionReq};
+use std::collections::BTreeMap;
+
+use crate::{arb_version, arb_version_req, arb_version_weighted};
+
+/// A package name, of lowercase letters, digits, `-` and `_`, starting with a
+/// letter.
+pub(crate) fn arb_package_name() -> impl Strategy<Value = String> {
+    "[a-z][a-z0-9_-]{0,15}"
+}
+
+/// Provides a registry, as a map of package name to it's published versions,
+/// each both as a `String` and as a [semver::Version].
+///
+/// Every package has at least one version, and versions are sorted and
+/// de-duplicated (by [Ord], so versions only differing in Build Metadata are
+/// both kept). The string of each version always parses to it's
+/// [semver::Version].
+///
+/// * `max_packages` - The maximum number of packages, which is at least `1`.
+/// * `max_versions` - The maximum number of versions per package, which is at
+///   least `1`.
+pub fn arb_registry(
+    max_packages: usize,
+    max_versions: usize,
+) -> impl Strategy<Value = BTreeMap<String, Vec<(String, Version)>>> {
+    let versions = prop::collection::btree_set(arb_version(), 1..=max_versions).prop_map(|set| {
+        set.into_iter()
+            .map(|v| (v.to_string(), v))
+            .collect::<Vec<_>>()
+    });
+
+    prop::collection::btree_map(arb_package_name(), versions, 1..=max_packages)
+}
+
+/// Picks one of `candidates` half of the time, if there are any.
+fn arb_sometimes_one_of(candidates: Vec<Version>) -> BoxedStrategy<Option<Version>> {
+    if candidates.is_empty() {
+        Just(None).boxed()
+    } else {
+        prop::option::of(prop::sample::select(candidates)).boxed()
+    }
+}
+
+/// Provides npm-style dist-tags, as a map of tag to the version it points at,
+/// for testing tag resolution.
+///
+/// Every tag points at a member of `versions`, and:
+///
+/// * `latest` always points at a release (never a Pre-Release), and is only
+///   missing when there are no releases.
+/// * `next`, when present, points at a version with a higher precedence than
+///   `latest`.
+/// * `beta`, when present, points at a Pre-Release.
+pub fn arb_dist_tags(versions: &[Version]) -> impl Strategy<Value = BTreeMap<String, Version>> {
+    let releases = versions
+        .iter()
+        .filter(|v| v.pre.is_empty())
+        .cloned()
+        .collect::<Vec<_>>();
+    let pre_releases = versions
+        .iter()
+        .filter(|v| !v.pre.is_empty())
+        .cloned()
+        .collect::<Vec<_>>();
+
+    let latest = if releases.is_empty() {
+        Just(None).boxed()
+    } else {
+        prop::sample::select(releases).prop_map(Some).boxed()
+    };
+
+    let versions = versions.to_vec();
+    (latest, arb_sometimes_one_of(pre_releases))
+        .prop_flat_map(move |(latest, beta)| {
+            let newer = versions
+                .iter()
+                .filter(|v| match &latest {
+                    Some(latest) => v.cmp_precedence(latest).is_gt(),
+                    None => true,
+                })
+                .cloned()
+                .collect();
+            (Just(latest), arb_sometimes_one_of(newer), Just(beta))
+        })
+        .prop_map(|(latest, next, beta)| {
+            [("latest", latest), ("next", next), ("beta", beta)]
+                .into_iter()
+                .filter_map(|(tag, v)| Some((tag.to_string(), v?)))
+                .collect()
+        })
+}
+
+/// A published version, which may have been yanked.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PublishedVersion {
+    pub version: Version,
+    pub yanked: bool,
+}
+
+/// Provides a version history, sorted by precedence, where the version with
+/// the highest precedence is always yanked (and often several in a row),
+/// alongside the highest version which isn't yanked, if any.
+///
+/// Versions are distinct by precedence, sometimes a Pre-Release, and never
+/// have Build Metadata. Versions below the yanked run are sometimes yanked
+/// too.
+///
+/// * `max_versions` - The maximum number of versions, which is at least `1`.
+pub fn arb_history_with_yanked_latest(
+    max_versions: usize,
+) -> impl Strategy<Value = (Vec<PublishedVersion>, Option<Version>)> {
+    let version = arb_version_weighted(0.25, 0.5).prop_map(|mut v| {
+        v.build = BuildMetadata::EMPTY;
+        v
+    });
+
+    prop::collection::btree_set(version, 1..=max_versions)
+        .prop_flat_map(|versions| {
+            let len = versions.len();
+            (
+                Just(versions),
+                1..=len,
+                prop::collection::vec(prop::bool::weighted(0.25), len),
+            )
+        })
+        .prop_map(|(versions, run, yanked)| {
+            let len = versions.len();
+            let history = versions
+                .into_iter()
+                .zip(yanked)
+                .enumerate()
+                .map(|(i, (version, yanked))| PublishedVersion {
+                    version,
+                    yanked: yanked || i >= len - run,
+                })
+                .collect::<Vec<_>>();
+
+            let fallback = history
+                .iter()
+                .rev()
+                .find(|p| !p.yanked)
+                .map(|p| p.version.clone());
+            (history, fallback)
+        })
+}
+
+/// Provides a registry like [arb_registry], as a map of package name to it's
+/// published versions, where each version is sometimes yanked.
+///
+/// Every package has at least one version, and versions are sorted and
+/// de-duplicated by [Ord]. Every version of a package may be yanked.
+///
+/// * `max_packages` - The maximum number of packages, which is at least `1`.
+/// * `max_versions` - The maximum number of versions per package, which is at
+///   least `1`.
+/// * `probability_of_yanked` - The probability each version is yanked, from
+///   `0.0` to `1.0`.
+pub fn arb_registry_with_yanked(
+    max_packages: usize,
+    max_versions: usize,
+    probability_of_yanked: f64,
+) -> impl Strategy<Value = BTreeMap<String, Vec<PublishedVersion>>> {
+    let versions = prop::collection::btree_set(arb_version(), 1..=max_versions)
+        .prop_flat_map(move |set| {
+            let len = set.len();
+            (
+                Just(set),
+                prop::collection::vec(prop::bool::weighted(probability_of_yanked), len),
+            )
+        })
+        .prop_map(|(set, yanked)| {
+            set.into_iter()
+                .zip(yanked)
+                .map(|(version, yanked)| PublishedVersion { version, yanked })
+                .collect::<Vec<_>>()
+        });
+
+    prop::collection::btree_map(arb_package_name(), versions, 1..=max_packages)
+}
+
+/// What happened to a package in an event of a log from
+/// [arb_multi_package_event_log].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RegistryEventKind {
+    Publish(Version),
+    Yank(Version),
+}
+
+/// A single event of a log from [arb_multi_package_event_log].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RegistryEvent {
+    pub package: String,
+    pub kind: RegistryEventKind,
+}
+
+/// Provides an interleaved log of publish and yank events across packages,
+/// alongside the final state of each package (versions sorted by precedence),
+/// for testing log compaction and materialized views.
+///
+/// Each package publishes it's versions mostly in increasing order of
+/// precedence, with the occasional out of order publish (like a backport).
+/// A version is only ever published once, and only a published version which
+/// isn't already yanked is ever yanked. Versions never have Build Metadata.
+///
+/// * `packages` - The number of packages, which is at least `1`.
+/// * `events` - The number of events.
+pub fn arb_multi_package_event_log(
+    packages: usize,
+    events: usize,
+) -> impl Strategy<Value = (Vec<RegistryEvent>, BTreeMap<String, Vec<PublishedVersion>>)> {
+    assert!(packages >= 1, "there are no packages to log events for");
+
+    let version = arb_version_weighted(0.25, 0.5).prop_map(|mut v| {
+        v.build = BuildMetadata::EMPTY;
+        v
+    });
+    // Every package has enough versions to publish in every event.
+    let history = (
+        prop::collection::btree_set(version, events),
+        prop::collection::vec(prop::bool::weighted(0.1), events),
+    )
+        .prop_map(|(versions, swaps)| {
+            let mut versions = versions.into_iter().collect::<Vec<_>>();
+            for (i, swap) in swaps.into_iter().enumerate().skip(1) {
+                if swap && i < versions.len() {
+                    versions.swap(i - 1, i);
+                }
+            }
+            versions
+        });
+
+    let step = (
+        any::<prop::sample::Index>(),
+        prop::bool::weighted(0.25),
+        any::<prop::sample::Index>(),
+    );
+
+    (
+        prop::collection::btree_map(arb_package_name(), history, packages),
+        prop::collection::vec(step, events),
+    )
+        .prop_map(|(histories, steps)| {
+            let names = histories.keys().cloned().collect::<Vec<_>>();
+            let mut pending = histories
+                .into_values()
+                .map(|h| h.into_iter())
+                .collect::<Vec<_>>();
+            let mut state = names.iter().map(|_| Vec::new()).collect::<Vec<_>>();
+
+            let mut log = Vec::new();
+            for (package, yank, target) in steps {
+                let p = package.index(names.len());
+                let published: &mut Vec<PublishedVersion> = &mut state[p];
+
+                let unyanked = published
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, v)| !v.yanked)
+                    .map(|(i, _)| i)
+                    .collect::<Vec<_>>();
+                let kind = if yank && !unyanked.is_empty() {
+                    let i = unyanked[target.index(unyanked.len())];
+                    published[i].yanked = true;
+                    RegistryEventKind::Yank(published[i].version.clone())
+                } else {
+                    let version = pending[p].next().unwrap();
+                    published.push(PublishedVersion {
+                        version: version.clone(),
+                        yanked: false,
+                    });
+                    RegistryEventKind::Publish(version)
+                };
+
+                log.push(RegistryEvent {
+                    package: names[p].clone(),
+                    kind,
+                });
+            }
+
+            let state = names
+                .into_iter()
+                .zip(state)
+                .map(|(name, mut published)| {
+                    published.sort_by(|a, b| a.version.cmp_precedence(&b.version));
+                    (name, published)
+                })
+                .collect();
+            (log, state)
+        })
+}
+
+/// Provides a batch of `(package, requirement)` lookup queries, with a
+/// Zipf-like popularity skew, for testing rate-limiting and cache eviction.
+///
+/// Queries are drawn from a pool of distinct queries, where the query at rank
+/// `i` (from `0`) is picked roughly `1 / (i + 1)` as often as the most popular
+/// one, so a few queries are repeated a lot and most are rare. Shrinking
+/// tends towards a batch of the single most popular query.
+///
+/// * `max` - The maximum number of queries in a batch, and the maximum number
+///   of distinct queries, which is at least `1`.
+pub fn arb_version_query_batch(max: usize) -> impl Strategy<Value = Vec<(String, VersionReq)>> {
+    assert!(max >= 1, "a batch has at least one query");
+
+    prop::collection::vec((arb_package_name(), arb_version_req(3)), 1..=max).prop_flat_map(
+        move |pool| {
+            let mut seen = std::collections::BTreeSet::new();
+            let pool = pool
+                .into_iter()
+                .filter(|(name, req)| seen.insert((name.clone(), req.to_string())))
+                .collect::<Vec<_>>();
+
+            // Scaled to the pool, so the least popular query still has a
+            // weight of at least `16`, and rounding stays small.
+            let scale = 16 * pool.len();
+            let ranks = Union::new_weighted(
+                (0..pool.len())
+                    .map(|i| ((scale / (i + 1)) as u32, Just(i)))
+                    .collect(),
+            );
+            prop::collection::vec(ranks, 1..=max)
+                .prop_map(move |ranks| ranks.into_iter().map(|i| pool[i].clone()).collect())
+        },
+    )
+}
+
+/// The maximum number of versions a primary registry has in a [MirrorPair].
+const MAX_MIRRORED_VERSIONS: usize = 16;
+
+/// A version of a [MirrorPair], without Build Metadata.
+fn arb_mirrored_version() -> impl Strategy<Value = Version> {
+    arb_version_weighted(0.25, 0.5).prop_map(|mut v| {
+        v.build = BuildMetadata::EMPTY;
+        v
+    })
+}
+
+/// How the mirror of a [MirrorPair] differs from it's primary, with each
+/// field sorted by precedence.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MirrorDiff {
+    /// Versions on the primary, which the mirror is missing.
+    pub missing: Vec<Version>,
+
+    /// Versions on the mirror, which aren't on the primary.
+    pub extra: Vec<Version>,
+
+    /// Versions on both, which are yanked on only one of them.
+    pub yanked_differs: Vec<Version>,
+}
+
+impl MirrorDiff {
+    /// Whether the mirror is in sync with it's primary.
+    pub fn is_empty(&self) -> bool {
+        self.missing.is_empty() && self.extra.is_empty() && self.yanked_differs.is_empty()
+    }
+}
+
+/// A primary registry's versions of a package, and a mirror of them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MirrorPair {
+    /// Sorted by precedence, never empty.
+    pub primary: Vec<PublishedVersion>,
+
+    /// Sorted by precedence.
+    pub mirror: Vec<PublishedVersion>,
+
+    pub diff: MirrorDiff,
+}
+
+/// Provides the versions of a package on a primary registry, and on a mirror
+/// that lags or diverges from it, with the diff between them, for testing
+/// mirror reconciliation jobs.
+///
+/// The mirror is sometimes in sync, but may be missing the latest versions
+/// of the primary, have extra yanked versions the primary doesn't, or
+/// disagree about whether a version is yanked. Versions are distinct by
+/// precedence, and never have Build Metadata.
+pub fn arb_mirror_pair() -> impl Strategy<Value = MirrorPair> {
+    (
+        prop::collection::btree_set(arb_mirrored_version(), 1..=MAX_MIRRORED_VERSIONS),
+        prop::collection::btree_set(arb_mirrored_version(), 0..4),
+    )
+        .prop_flat_map(|(versions, extra)| {
+            let len = versions.len();
+            (
+                Just(versions),
+                Just(extra),
+                prop::collection::vec(prop::bool::weighted(0.1), len),
+                prop_oneof![Just(0), 0..len],
+                prop::collection::vec(prop::bool::weighted(0.1), len),
+            )
+        })
+        .prop_map(|(versions, extra, yanked, lag, flipped)| {
+            let primary = versions
+                .iter()
+                .zip(yanked)
+                .map(|(version, yanked)| PublishedVersion {
+                    version: version.clone(),
+                    yanked,
+                })
+                .collect::<Vec<_>>();
+
+            let mut mirror = primary[..primary.len() - lag]
+                .iter()
+                .zip(flipped)
+                .map(|(p, flipped)| PublishedVersion {
+                    version: p.version.clone(),
+                    yanked: p.yanked != flipped,
+                })
+                .chain(
+                    extra
+                        .into_iter()
+                        .filter(|v| !versions.contains(v))
+                        .map(|version| PublishedVersion {
+                            version,
+                            yanked: true,
+                        }),
+                )
+                .collect::<Vec<_>>();
+            mirror.sort_by(|a, b| a.version.cmp_precedence(&b.version));
+
+            let on_mirror = mirror
+                .iter()
+                .map(|p| (&p.version, p.yanked))
+                .collect::<BTreeMap<_, _>>();
+            let mut diff = MirrorDiff::default();
+            for p in &primary {
+                match on_mirror.get(&p.version) {
+                    None => diff.missing.push(p.version.clone()),
+                    Some(yanked) if *yanked != p.yanked => {
+                        diff.yanked_differs.push(p.version.clone())
+                    }
+                    Some(_) => {}
+                }
+            }
+            diff.extra = mirror
+                .iter()
+                .filter(|p| !versions.contains(&p.version))
+                .map(|p| p.version.clone())
+                .collect();
+
+            MirrorPair {
+                primary,
+                mirror,
+                diff,
+            }
+        })
+}
@@ -0,0 +1,250 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+//!
+//! Machine readable summaries of what the core strategies generate, so a
+//! downstream suite can notice when an upgrade of this crate changes the
+//! characteristics it implicitly depends on.
+//!
+//! [report] only covers the core string, version, comparator and
+//! requirement strategies (see it's list), and any other strategy can be
+//! summarized the same way with [report_strategy].
+//!
+//! Reports use a deterministic [proptest::test_runner::TestRunner], so two
+//! reports of the same version of this crate are always identical.
+use proptest::prelude::*;
+use proptest::strategy::ValueTree;
+use proptest::test_runner::TestRunner;
+use semver::{Version, VersionReq};
+use std::collections::BTreeMap;
+
+use crate::{
+    arb_build_metadata_string, arb_full_comparator, arb_pre_release_string, arb_semver,
+    arb_version, arb_version_req, FullComparator,
+};
+
+/// What a report needs to know about a single generated value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Sample {
+    /// The value as a string, whose length is measured.
+    pub text: String,
+
+    /// Whether the value is valid, such as parsing as a [semver::Version].
+    pub valid: bool,
+
+    /// The kind of value, such as `release` or `pre-release`.
+    pub variant: String,
+}
+
+/// A summary of the values generated by a strategy.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StrategyReport {
+    pub name: String,
+    pub samples: usize,
+    pub valid: usize,
+    pub min_len: usize,
+    pub max_len: usize,
+    pub mean_len: f64,
+
+    /// The number of samples of each variant.
+    pub variants: BTreeMap<String, usize>,
+}
+
+impl StrategyReport {
+    /// The fraction of samples which were valid.
+    pub fn validity_rate(&self) -> f64 {
+        if self.samples == 0 {
+            0.0
+        } else {
+            self.valid as f64 / self.samples as f64
+        }
+    }
+
+    /// This report as a single line JSON object.
+    pub fn to_json(&self) -> String {
+        let mut name = String::new();
+        push_json_string(&mut name, &self.name);
+        let variants = self
+            .variants
+            .iter()
+            .map(|(variant, n)| {
+                let mut s = String::new();
+                push_json_string(&mut s, variant);
+                format!("{s}: {n}")
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!(
+            "{{\"name\": {name}, \"samples\": {}, \"valid\": {}, \"validity_rate\": {}, \"min_len\": {}, \"max_len\": {}, \"mean_len\": {}, \"variants\": {{{variants}}}}}",
+            self.samples,
+            self.valid,
+            self.validity_rate(),
+            self.min_len,
+            self.max_len,
+            self.mean_len,
+        )
+    }
+}
+
+/// Writes `s` as a JSON string, escaping quotes, backslashes and control
+/// characters.
+fn push_json_string(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// Generates `samples` values of `strategy`, with a deterministic runner, and
+/// summarizes them by what `describe` says about each.
+pub fn report_strategy<S: Strategy>(
+    name: &str,
+    strategy: S,
+    samples: usize,
+    describe: impl Fn(&S::Value) -> Sample,
+) -> StrategyReport {
+    let mut runner = TestRunner::deterministic();
+    let mut report = StrategyReport {
+        name: name.to_string(),
+        samples,
+        valid: 0,
+        min_len: usize::MAX,
+        max_len: 0,
+        mean_len: 0.0,
+        variants: BTreeMap::new(),
+    };
+
+    let mut total_len = 0;
+    for _ in 0..samples {
+        let value = strategy.new_tree(&mut runner).unwrap().current();
+        let sample = describe(&value);
+
+        let len = sample.text.len();
+        total_len += len;
+        report.min_len = report.min_len.min(len);
+        report.max_len = report.max_len.max(len);
+        report.valid += usize::from(sample.valid);
+        *report.variants.entry(sample.variant).or_insert(0) += 1;
+    }
+
+    if samples == 0 {
+        report.min_len = 0;
+    } else {
+        report.mean_len = total_len as f64 / samples as f64;
+    }
+    report
+}
+
+/// The variant of a version, by which optional parts it has.
+fn version_variant(v: &Version) -> String {
+    match (v.pre.is_empty(), v.build.is_empty()) {
+        (true, true) => "release",
+        (false, true) => "pre-release",
+        (true, false) => "release+build",
+        (false, false) => "pre-release+build",
+    }
+    .to_string()
+}
+
+/// The variant of a count, in buckets of powers of two, such as
+/// `4-7 identifiers`.
+fn count_variant(n: usize, noun: &str) -> String {
+    match n {
+        0 | 1 => format!("{n} {noun}"),
+        _ => {
+            let low = 1 << n.ilog2();
+            format!("{low}-{} {noun}", 2 * low - 1)
+        }
+    }
+}
+
+fn version_sample(text: String) -> Sample {
+    let parsed = Version::parse(&text);
+    Sample {
+        variant: parsed
+            .as_ref()
+            .map_or_else(|_| "unparsable".to_string(), version_variant),
+        valid: parsed.is_ok(),
+        text,
+    }
+}
+
+/// Reports on the core strategies of this crate, with `samples` values of
+/// each: [arb_semver], [arb_version], [arb_pre_release_string],
+/// [arb_build_metadata_string], [arb_full_comparator] and [arb_version_req].
+///
+/// [arb_semver] follows the spec, which has no limit on the size of `MAJOR`,
+/// `MINOR` and `PATCH`, so it's samples are often unparsable by `semver`.
+pub fn report(samples: usize) -> Vec<StrategyReport> {
+    vec![
+        report_strategy("arb_semver", arb_semver(), samples, |s| {
+            version_sample(s.clone())
+        }),
+        report_strategy("arb_version", arb_version(), samples, |v| {
+            version_sample(v.to_string())
+        }),
+        report_strategy(
+            "arb_pre_release_string",
+            arb_pre_release_string(),
+            samples,
+            |s| Sample {
+                text: s.clone(),
+                valid: semver::Prerelease::new(s).is_ok(),
+                variant: count_variant(s.split('.').count(), "identifiers"),
+            },
+        ),
+        report_strategy(
+            "arb_build_metadata_string",
+            arb_build_metadata_string(),
+            samples,
+            |s| Sample {
+                text: s.clone(),
+                valid: semver::BuildMetadata::new(s).is_ok(),
+                variant: count_variant(s.split('.').count(), "identifiers"),
+            },
+        ),
+        report_strategy(
+            "arb_full_comparator",
+            arb_full_comparator(None, None, None),
+            samples,
+            |c| Sample {
+                text: c.to_string(),
+                valid: semver::Comparator::parse(&c.to_string()).is_ok(),
+                variant: match c {
+                    FullComparator::Plain(..) => "plain",
+                    FullComparator::WildcardMinor(..) => "wildcard-minor",
+                    FullComparator::WildcardPatch(..) => "wildcard-patch",
+                    FullComparator::Wildcard => "wildcard",
+                }
+                .to_string(),
+            },
+        ),
+        report_strategy("arb_version_req", arb_version_req(4), samples, |req| {
+            Sample {
+                text: req.to_string(),
+                valid: VersionReq::parse(&req.to_string()).is_ok(),
+                variant: count_variant(req.comparators.len(), "comparators"),
+            }
+        }),
+    ]
+}
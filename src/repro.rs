@@ -0,0 +1,142 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+//!
+//! Small, self-contained resolver scenarios, serialized compactly enough to
+//! attach to a bug report, and loaded back into test inputs.
+//!
+//! Serializing a bundle requires the `serde` feature.
+use proptest::prelude::*;
+use semver::{BuildMetadata, Version, VersionReq};
+use std::collections::BTreeMap;
+#[cfg(feature = "serde")]
+use std::fmt;
+
+use crate::{arb_realistic_version, arb_req_over};
+
+/// Why a bundle couldn't be loaded.
+#[cfg(feature = "serde")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BundleError(String);
+
+#[cfg(feature = "serde")]
+impl fmt::Display for BundleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid bundle: {}", self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl std::error::Error for BundleError {}
+
+#[cfg(feature = "serde")]
+fn error<T>(message: impl Into<String>) -> Result<T, BundleError> {
+    Err(BundleError(message.into()))
+}
+
+/// A resolver scenario: some packages and their versions, a single query for
+/// the highest version of a package matching a requirement, and it's
+/// expected answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(deny_unknown_fields)
+)]
+pub struct ReproBundle {
+    /// The versions of each package, sorted by precedence.
+    pub packages: BTreeMap<String, Vec<Version>>,
+
+    /// The package queried, which is always one of [ReproBundle::packages].
+    pub package: String,
+    pub req: VersionReq,
+
+    /// The version of [ReproBundle::package] with the highest precedence
+    /// matching [ReproBundle::req], if any.
+    pub expected: Option<Version>,
+}
+
+#[cfg(feature = "serde")]
+impl ReproBundle {
+    /// Serializes this bundle as compact JSON, such as:
+    ///
+    /// ```json
+    /// {"packages":{"a":["1.0.0","1.2.0"]},"package":"a","req":"^1.1","expected":"1.2.0"}
+    /// ```
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("a bundle is valid JSON")
+    }
+
+    /// Loads a bundle serialized by [ReproBundle::to_json].
+    ///
+    /// The expected answer is loaded as written, rather than recomputed, so
+    /// a bundle can record the answer a resolver should have given.
+    pub fn from_json(s: &str) -> Result<Self, BundleError> {
+        let bundle: ReproBundle =
+            serde_json::from_str(s).map_err(|e| BundleError(e.to_string()))?;
+        if !bundle.packages.contains_key(&bundle.package) {
+            return error(format!("`{}` is not one of `packages`", bundle.package));
+        }
+        Ok(bundle)
+    }
+}
+
+/// A package name of a [ReproBundle], short enough to read at a glance.
+fn arb_bundle_package_name() -> impl Strategy<Value = String> {
+    "[a-z][a-z0-9-]{0,7}"
+}
+
+/// Versions of a package in a [ReproBundle], sorted by precedence.
+fn arb_bundle_versions() -> impl Strategy<Value = Vec<Version>> {
+    let version = arb_realistic_version().prop_map(|mut v| {
+        v.build = BuildMetadata::EMPTY;
+        v
+    });
+    prop::collection::btree_set(version, 1..8).prop_map(|set| set.into_iter().collect())
+}
+
+/// Provides a small resolver scenario, of a few packages and their versions,
+/// and a single query with it's expected answer, for testing resolvers and
+/// attaching to bug reports (see `ReproBundle::to_json` and
+/// `ReproBundle::from_json`, with the `serde` feature).
+///
+/// Versions look like those actually published (see
+/// [crate::arb_realistic_version]), without Build Metadata, and the
+/// requirement of the query is written against the versions of the queried
+/// package (see [crate::arb_req_over]), so it usually has an answer.
+pub fn arb_repro_bundle() -> impl Strategy<Value = ReproBundle> {
+    prop::collection::btree_map(arb_bundle_package_name(), arb_bundle_versions(), 1..4)
+        .prop_flat_map(|packages| {
+            let names = packages.keys().cloned().collect::<Vec<_>>();
+            let packages_for_query = packages.clone();
+            let query = prop::sample::select(names).prop_flat_map(move |package| {
+                let req = arb_req_over(&packages_for_query[&package]);
+                (Just(package), req)
+            });
+            (Just(packages), query)
+        })
+        .prop_map(|(packages, (package, req))| {
+            let expected = packages[&package]
+                .iter()
+                .filter(|v| req.matches(v))
+                .max_by(|a, b| a.cmp_precedence(b))
+                .cloned();
+            ReproBundle {
+                packages,
+                package,
+                req,
+                expected,
+            }
+        })
+}
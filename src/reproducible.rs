@@ -0,0 +1,78 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+//!
+//! Versions stamped by reproducible builds, where everything but
+//! `MAJOR.MINOR.PATCH` is derived from the build's inputs.
+use proptest::prelude::*;
+use semver::{BuildMetadata, Prerelease, Version};
+use std::fmt::Write;
+
+/// The 64 bit FNV-1a hash of `bytes`, which (unlike [std::hash::Hasher]
+/// implementations) is stable across Rust releases and platforms.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, b| {
+        (hash ^ *b as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// `bytes` as lowercase hexadecimal.
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::new(), |mut s, b| {
+        let _ = write!(s, "{b:02x}");
+        s
+    })
+}
+
+/// The Pre-Release and Build Metadata stamped from `seed_material` by
+/// [arb_reproducible_version].
+///
+/// The Pre-Release is `repro.g` followed by the hexadecimal FNV-1a hash of
+/// `seed_material` (the `g` keeps it from ever being numeric, like `git
+/// describe`). The Build Metadata is `seed_material` itself, in hexadecimal,
+/// or the hash when there is no seed material.
+pub fn reproducible_stamp(seed_material: &[u8]) -> (Prerelease, BuildMetadata) {
+    let hash = format!("{:016x}", fnv1a(seed_material));
+    let build = if seed_material.is_empty() {
+        hash.clone()
+    } else {
+        hex(seed_material)
+    };
+
+    (
+        Prerelease::new(&format!("repro.g{hash}")).unwrap(),
+        BuildMetadata::new(&build).unwrap(),
+    )
+}
+
+/// Provides versions as stamped by a reproducible build, for testing
+/// version stamping code.
+///
+/// `MAJOR.MINOR.PATCH` is arbitrary, but the Pre-Release and Build Metadata
+/// are always the same for the same `seed_material` (see
+/// [reproducible_stamp]).
+///
+/// * `seed_material` - The inputs of the build, such as a hash of it's
+///   sources.
+pub fn arb_reproducible_version(seed_material: &[u8]) -> impl Strategy<Value = Version> {
+    let (pre, build) = reproducible_stamp(seed_material);
+
+    any::<(u64, u64, u64)>().prop_map(move |(major, minor, patch)| Version {
+        major,
+        minor,
+        patch,
+        pre: pre.clone(),
+        build: build.clone(),
+    })
+}
@@ -0,0 +1,805 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+//!
+//! Strategies for [semver::VersionReq] with some known structure, beyond what
+//! [crate::arb_version_req] offers.
+use proptest::prelude::*;
+use proptest::strategy::Union;
+use semver::{Comparator, Op, Prerelease, Version, VersionReq};
+use std::ops::Bound;
+
+use crate::interval::{cmp_lower, cmp_upper, comparator_spans};
+use crate::precedence::{
+    arb_offsets, arb_version_above, arb_version_below, extend_pre_release, next_triple,
+    previous_triple, triple, triple_between, version_of, Triple, MAX_TRIPLE,
+};
+use crate::{
+    arb_full_comparator, arb_semver_op, arb_semver_prerelease, arb_version, decompose_version_req,
+};
+
+/// Why a comparator in a requirement from [arb_req_with_duplicates] is
+/// redundant.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RedundancyKind {
+    /// An exact repeat of another comparator.
+    Duplicate,
+
+    /// Matches every version the other comparator does (and more), so it
+    /// never changes what the requirement matches.
+    Subsumed,
+}
+
+/// Describes a redundant comparator, in a requirement from
+/// [arb_req_with_duplicates].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Redundancy {
+    /// The index of the redundant comparator.
+    pub index: usize,
+
+    /// The index of the comparator which makes it redundant, which is never
+    /// itself redundant.
+    pub of: usize,
+
+    /// Why the comparator is redundant.
+    pub kind: RedundancyKind,
+}
+
+/// Provides a [semver::VersionReq] that intentionally repeats comparators, or
+/// includes comparators subsumed by others, alongside a description of every
+/// redundant comparator.
+///
+/// Removing every redundant comparator never changes what the requirement
+/// matches. Subsumed comparators never have a Pre-Release, so they never
+/// change which Pre-Release versions the requirement is willing to match.
+///
+/// * `max_comparators` - The maximum number of comparators, which is at least
+///   `2`, and should always be less than or equal to
+///   [crate::MAX_COMPARATORS_IN_VERSION_REQ_STRING].
+pub fn arb_req_with_duplicates(
+    max_comparators: usize,
+) -> impl Strategy<Value = (VersionReq, Vec<Redundancy>)> {
+    assert!(max_comparators >= 2, "there is no room for a redundancy");
+    let max_base = max_comparators / 2;
+
+    prop::collection::vec(arb_full_comparator(None, None, None), 1..=max_base)
+        .prop_flat_map(move |base| {
+            let base = base
+                .iter()
+                .map(|c| Comparator::parse(&c.to_string()).unwrap())
+                .collect::<Vec<_>>();
+
+            let of = base.clone();
+            let redundant =
+                (0..base.len()).prop_flat_map(move |i| (Just(i), arb_redundant_comparator(&of[i])));
+            let max_redundant = max_comparators - base.len();
+
+            (
+                Just(base),
+                prop::collection::vec(redundant, 1..=max_redundant),
+            )
+        })
+        .prop_flat_map(|(base, redundant)| {
+            // Each entry is the comparator, and its redundancy, if it is one.
+            let entries = base
+                .into_iter()
+                .enumerate()
+                .map(|(i, c)| (c, i, None))
+                .chain(
+                    redundant
+                        .into_iter()
+                        .map(|(of, (c, kind))| (c, of, Some(kind))),
+                )
+                .collect::<Vec<_>>();
+
+            Just(entries).prop_shuffle()
+        })
+        .prop_map(|entries| {
+            // Where every comparator from the base ended up.
+            let mut position = vec![0; entries.len()];
+            for (index, (_, base, kind)) in entries.iter().enumerate() {
+                if kind.is_none() {
+                    position[*base] = index;
+                }
+            }
+
+            let redundancies = entries
+                .iter()
+                .enumerate()
+                .filter_map(|(index, (_, of, kind))| {
+                    kind.map(|kind| Redundancy {
+                        index,
+                        of: position[*of],
+                        kind,
+                    })
+                })
+                .collect();
+
+            let comparators = entries.into_iter().map(|(c, _, _)| c).collect();
+            (VersionReq { comparators }, redundancies)
+        })
+}
+
+/// A comparator made redundant by `c`.
+fn arb_redundant_comparator(c: &Comparator) -> BoxedStrategy<(Comparator, RedundancyKind)> {
+    let mut options = vec![Just((c.clone(), RedundancyKind::Duplicate)).boxed()];
+
+    // The bounds of everything `c` can match, including Pre-Releases.
+    let (releases, pre_releases) = comparator_spans(c);
+    let (mut lower, mut upper) = releases;
+    if let Some((pre_lower, pre_upper)) = pre_releases {
+        if cmp_lower(&pre_lower, &lower).is_lt() {
+            lower = pre_lower;
+        }
+        if cmp_upper(&pre_upper, &upper).is_gt() {
+            upper = pre_upper;
+        }
+    }
+
+    // `>=` any release at or below the lower bound.
+    let at_or_below = match &lower {
+        Bound::Included(v) | Bound::Excluded(v) if v.pre.is_empty() => Some(triple(v)),
+        Bound::Included(v) | Bound::Excluded(v) => previous_triple(triple(v)),
+        Bound::Unbounded => None,
+    };
+    if let Some(highest) = at_or_below {
+        options.push(arb_subsuming_comparator(Op::GreaterEq, (0, 0, 0), highest));
+    }
+
+    // `<` any release above the upper bound.
+    let above = match &upper {
+        Bound::Excluded(v) => Some(triple(v)),
+        Bound::Included(v) if !v.pre.is_empty() => Some(triple(v)),
+        Bound::Included(v) => next_triple(triple(v)),
+        Bound::Unbounded => None,
+    };
+    if let Some(lowest) = above {
+        options.push(arb_subsuming_comparator(Op::Less, lowest, MAX_TRIPLE));
+    }
+
+    Union::new(options).boxed()
+}
+
+fn arb_subsuming_comparator(
+    op: Op,
+    lower: Triple,
+    upper: Triple,
+) -> BoxedStrategy<(Comparator, RedundancyKind)> {
+    arb_offsets()
+        .prop_map(move |offsets| {
+            let (major, minor, patch) = triple_between(lower, upper, offsets);
+            let c = Comparator {
+                op,
+                major,
+                minor: Some(minor),
+                patch: Some(patch),
+                pre: Prerelease::EMPTY,
+            };
+            (c, RedundancyKind::Subsumed)
+        })
+        .boxed()
+}
+
+/// Provides `n` requirements whose sets of matching versions form a chain by
+/// strict inclusion, shuffled, alongside their order from loosest to
+/// strictest (as indexes into the requirements).
+///
+/// Each requirement is of the form `>=LOWER, <UPPER`, where the bounds of a
+/// stricter requirement lie strictly within the bounds of a looser one, and no
+/// requirement is empty. None have a Pre-Release, so only release versions
+/// ever match.
+///
+/// * `n` - The number of requirements.
+pub fn arb_reqs_ordered_by_restrictiveness(
+    n: usize,
+) -> impl Strategy<Value = (Vec<VersionReq>, Vec<usize>)> {
+    (
+        prop::collection::btree_set(arb_offsets(), 2 * n),
+        Just((0..n).collect::<Vec<_>>()).prop_shuffle(),
+    )
+        .prop_map(move |(bounds, order)| {
+            let bounds = bounds.into_iter().collect::<Vec<_>>();
+            let comparator = |op, (major, minor, patch): Triple| Comparator {
+                op,
+                major,
+                minor: Some(minor),
+                patch: Some(patch),
+                pre: Prerelease::EMPTY,
+            };
+
+            // The requirement at `order[i]` is the `i`th loosest.
+            let mut reqs = vec![VersionReq::STAR; n];
+            for (i, at) in order.iter().enumerate() {
+                reqs[*at] = VersionReq {
+                    comparators: vec![
+                        comparator(Op::GreaterEq, bounds[i]),
+                        comparator(Op::Less, bounds[2 * n - 1 - i]),
+                    ],
+                };
+            }
+
+            (reqs, order)
+        })
+}
+
+/// How the requirement strings of a [ReqCacheCase] relate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReqCacheRelation {
+    /// Textually different, but matching exactly the same versions.
+    Equivalent,
+
+    /// Textually similar (differing in a single operator or number), but
+    /// matching different versions.
+    Different,
+}
+
+/// A pair of requirement strings, labeled with how they relate, for testing
+/// caches keyed by requirement strings.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReqCacheCase {
+    pub a: String,
+    pub b: String,
+    pub relation: ReqCacheRelation,
+}
+
+/// A comparator, kept as it's parts so it can be written out in different
+/// ways.
+#[derive(Clone, Debug)]
+struct ComparatorParts {
+    op: &'static str,
+    major: u64,
+    minor: Option<u64>,
+    patch: Option<u64>,
+    pre: &'static str,
+    build: bool,
+}
+
+impl ComparatorParts {
+    fn render(&self, space_after_op: bool) -> String {
+        let space = if space_after_op && !self.op.is_empty() {
+            " "
+        } else {
+            ""
+        };
+        let mut s = format!("{}{space}{}", self.op, self.major);
+        if let Some(minor) = self.minor {
+            s.push_str(&format!(".{minor}"));
+            if let Some(patch) = self.patch {
+                s.push_str(&format!(".{patch}{}", self.pre));
+                if self.build {
+                    s.push_str("+build");
+                }
+            }
+        }
+        s
+    }
+}
+
+/// Small comparators, which frequently overlap each other.
+fn arb_comparator_parts() -> BoxedStrategy<ComparatorParts> {
+    (
+        prop::sample::select(vec!["=", ">", ">=", "<", "<=", "~", "^", ""]),
+        0u64..4,
+        prop::option::of(0u64..4),
+        prop::option::of(0u64..4),
+        prop::sample::select(vec!["", "", "-0", "-alpha"]),
+    )
+        .prop_map(|(op, major, minor, patch, pre)| ComparatorParts {
+            op,
+            major,
+            minor,
+            patch: minor.and(patch),
+            pre,
+            build: false,
+        })
+        .boxed()
+}
+
+fn render_req(comparators: &[ComparatorParts], separator: &str, space_after_op: bool) -> String {
+    comparators
+        .iter()
+        .map(|c| c.render(space_after_op))
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+fn same_meaning(a: &str, b: &str) -> bool {
+    let (a, b) = (VersionReq::parse(a).unwrap(), VersionReq::parse(b).unwrap());
+    decompose_version_req(&a) == decompose_version_req(&b)
+}
+
+/// Provides a pair of requirement strings, which are either textually
+/// different but semantically equal, or textually similar but semantically
+/// different, labeled as such.
+///
+/// Equivalent strings differ by spacing, the order of comparators, a `^` on a
+/// bare comparator, Build Metadata, or a duplicated comparator. Different
+/// strings differ in a single operator or number. Labels are checked with
+/// [decompose_version_req].
+pub fn arb_req_cache_case() -> impl Strategy<Value = ReqCacheCase> {
+    let comparators = prop::collection::vec(arb_comparator_parts(), 1..4).boxed();
+
+    let equivalent = (
+        comparators.clone(),
+        prop::sample::select(vec![",", ", ", " , "]),
+        any::<bool>(),
+        any::<bool>(),
+        any::<bool>(),
+        any::<prop::sample::Index>(),
+    )
+        .prop_flat_map(|(c, separator, space_after_op, caret, build, index)| {
+            let mut rewritten = c.clone();
+            let i = index.index(rewritten.len());
+            if caret && rewritten[i].op.is_empty() {
+                rewritten[i].op = "^";
+            }
+            if build && rewritten[i].patch.is_some() {
+                rewritten[i].build = true;
+            }
+            (
+                Just(c),
+                Just(rewritten).prop_shuffle(),
+                Just(separator),
+                Just(space_after_op),
+                Just(i),
+            )
+        })
+        .prop_map(|(c, mut rewritten, separator, space_after_op, i)| {
+            let a = render_req(&c, ", ", false);
+            let mut b = render_req(&rewritten, separator, space_after_op);
+            if a == b {
+                rewritten.push(c[i].clone());
+                b = render_req(&rewritten, separator, space_after_op);
+            }
+            ReqCacheCase {
+                a,
+                b,
+                relation: ReqCacheRelation::Equivalent,
+            }
+        });
+
+    let different = (
+        comparators,
+        any::<prop::sample::Index>(),
+        0usize..4,
+        prop::sample::select(vec!["=", ">", ">=", "<", "<=", "~", "^", ""]),
+    )
+        .prop_map(|(c, index, field, op)| {
+            let mut changed = c.clone();
+            let i = index.index(changed.len());
+            let target = &mut changed[i];
+            match field {
+                0 => target.major += 1,
+                1 if target.minor.is_some() => target.minor = target.minor.map(|n| n + 1),
+                2 if target.patch.is_some() => target.patch = target.patch.map(|n| n + 1),
+                _ => target.op = op,
+            }
+            ReqCacheCase {
+                a: render_req(&c, ", ", false),
+                b: render_req(&changed, ", ", false),
+                relation: ReqCacheRelation::Different,
+            }
+        })
+        .prop_filter("the change must change the meaning", |case| {
+            !same_meaning(&case.a, &case.b)
+        });
+
+    prop_oneof![equivalent, different]
+}
+
+/// Comparator strings which each match exactly the same versions, where a
+/// single entry may be more than one comparator.
+fn equivalent_comparators(shape: u8, major: u64, minor: u64, patch: u64) -> Vec<String> {
+    let (m, mm, p) = (major, minor, patch);
+    match shape {
+        0 => {
+            let upper = match (m, mm) {
+                (0, 0) => format!("0.0.{}", p + 1),
+                (0, _) => format!("0.{}.0", mm + 1),
+                _ => format!("{}.0.0", m + 1),
+            };
+            let mut forms = vec![
+                format!("^{m}.{mm}.{p}"),
+                format!("{m}.{mm}.{p}"),
+                format!(">={m}.{mm}.{p}, <{upper}"),
+                format!("<{upper}, >={m}.{mm}.{p}"),
+            ];
+            if (m, mm) == (0, 0) {
+                forms.push(format!("={m}.{mm}.{p}"));
+            }
+            forms
+        }
+        1 => vec![
+            format!("~{m}.{mm}.{p}"),
+            format!(">={m}.{mm}.{p}, <{m}.{}.0", mm + 1),
+        ],
+        2 => vec![
+            format!("{m}.{mm}.*"),
+            format!("{m}.{mm}.x"),
+            format!("{m}.{mm}.X"),
+            format!("~{m}.{mm}"),
+            format!("={m}.{mm}"),
+            format!(">={m}.{mm}.0, <{m}.{}.0", mm + 1),
+        ],
+        3 => vec![
+            format!("{m}.*"),
+            format!("{m}.x"),
+            format!("{m}.*.*"),
+            format!("~{m}"),
+            format!("={m}"),
+            format!("^{m}"),
+            format!("{m}"),
+            format!(">={m}.0.0, <{}.0.0", m + 1),
+        ],
+        4 => vec![
+            format!("={m}.{mm}.{p}"),
+            format!(">={m}.{mm}.{p}, <={m}.{mm}.{p}"),
+        ],
+        5 => vec![format!(">{m}.{mm}.{p}"), format!(">={m}.{mm}.{}", p + 1)],
+        _ if p > 0 => vec![format!("<{m}.{mm}.{p}"), format!("<={m}.{mm}.{}", p - 1)],
+        _ => vec![format!("<{m}.{mm}.0"), format!("<{m}.{mm}")],
+    }
+}
+
+/// Provides a pair of requirement strings, which are syntactically different,
+/// but match exactly the same versions, for testing that normalization or
+/// caching treats them identically.
+///
+/// Unlike [arb_req_cache_case], which only rewrites the text of each
+/// comparator, comparators are rewritten with different operators, such as
+/// `^1.2.3` as `>=1.2.3, <2.0.0`, `1.2.*` as `~1.2`, or `>1.2.3` as
+/// `>=1.2.4`. Only release versions are named, and each requirement is
+/// checked with [decompose_version_req].
+pub fn arb_equivalent_version_req_pair() -> impl Strategy<Value = (String, String)> {
+    let comparator = (0..7u8, 0..4u64, 0..4u64, 0..4u64)
+        .prop_map(|(shape, major, minor, patch)| equivalent_comparators(shape, major, minor, patch))
+        .prop_flat_map(|forms| {
+            let n = forms.len();
+            (Just(forms), 0..n, 1..n)
+        })
+        .prop_map(|(forms, a, offset)| {
+            (forms[a].clone(), forms[(a + offset) % forms.len()].clone())
+        });
+
+    let comparators = prop::collection::vec(comparator, 1..4)
+        .prop_flat_map(|pairs| {
+            let (a, b): (Vec<_>, Vec<_>) = pairs.into_iter().unzip();
+            (Just(a), Just(b).prop_shuffle())
+        })
+        .prop_map(|(a, b)| (a.join(", "), b.join(", ")))
+        .prop_filter("the strings must differ", |(a, b)| a != b);
+
+    prop_oneof![
+        9 => comparators,
+        1 => prop::sample::select(vec![">=0.0.0", "*", "x", "X"]).prop_flat_map(|a| {
+            prop::sample::select(vec![">=0.0.0", "*", "x", "X"])
+                .prop_filter("the strings must differ", move |b| *b != a)
+                .prop_map(move |b| (a.to_string(), b.to_string()))
+        }),
+    ]
+}
+
+/// A comparator naming `v`, or just it's `MAJOR` or `MAJOR.MINOR`.
+pub(crate) fn arb_comparator_anchored_to(v: Version) -> impl Strategy<Value = Comparator> {
+    (
+        arb_semver_op(None, Some(0)),
+        prop_oneof![4 => Just(3), 1 => Just(2), 1 => Just(1)],
+    )
+        .prop_map(move |(op, parts)| Comparator {
+            op,
+            major: v.major,
+            minor: (parts >= 2).then_some(v.minor),
+            patch: (parts >= 3).then_some(v.patch),
+            pre: if parts >= 3 {
+                v.pre.clone()
+            } else {
+                Prerelease::EMPTY
+            },
+        })
+}
+
+/// Provides a [semver::VersionReq] anchored to a known universe of published
+/// versions, the way people write requirements against an existing registry,
+/// rather than over the whole [u64] space.
+///
+/// Every comparator names a version from `universe` (or just it's `MAJOR` or
+/// `MAJOR.MINOR`). Requirements are either a single comparator, or a range of
+/// a lower bound (`>=` or `>`) and an upper bound (`<` or `<=`), where the
+/// lower bound never has a higher precedence than the upper bound.
+///
+/// * `universe` - The published versions, which is never empty.
+pub fn arb_req_over(universe: &[Version]) -> impl Strategy<Value = VersionReq> {
+    assert!(!universe.is_empty(), "there is nothing to anchor to");
+
+    let mut sorted = universe.to_vec();
+    sorted.sort_by(|a, b| a.cmp_precedence(b));
+
+    let single = prop::sample::select(sorted.clone())
+        .prop_flat_map(arb_comparator_anchored_to)
+        .prop_map(|c| VersionReq {
+            comparators: vec![c],
+        });
+
+    let len = sorted.len();
+    let range = (0..len, 0..len, any::<bool>(), any::<bool>()).prop_map(
+        move |(a, b, inclusive_lower, inclusive_upper)| {
+            let (lower, upper) = (&sorted[a.min(b)], &sorted[a.max(b)]);
+            let comparator = |op, v: &Version| Comparator {
+                op,
+                major: v.major,
+                minor: Some(v.minor),
+                patch: Some(v.patch),
+                pre: v.pre.clone(),
+            };
+            VersionReq {
+                comparators: vec![
+                    comparator(
+                        if inclusive_lower {
+                            Op::GreaterEq
+                        } else {
+                            Op::Greater
+                        },
+                        lower,
+                    ),
+                    comparator(
+                        if inclusive_upper {
+                            Op::LessEq
+                        } else {
+                            Op::Less
+                        },
+                        upper,
+                    ),
+                ],
+            }
+        },
+    );
+
+    prop_oneof![single, range]
+}
+
+/// A comparator with one of `ops`, naming all of a version from `versions`
+/// (including it's Pre-Release).
+fn arb_comparator_naming(
+    ops: Vec<Op>,
+    versions: impl Strategy<Value = Version> + 'static,
+) -> BoxedStrategy<Comparator> {
+    (prop::sample::select(ops), versions)
+        .prop_map(|(op, w)| Comparator {
+            op,
+            major: w.major,
+            minor: Some(w.minor),
+            patch: Some(w.patch),
+            pre: w.pre,
+        })
+        .boxed()
+}
+
+/// A comparator with one of `ops`, naming just the `MAJOR` or `MAJOR.MINOR`
+/// of `v`.
+fn arb_comparator_partial(ops: Vec<Op>, v: &Version) -> BoxedStrategy<Comparator> {
+    let (major, minor) = (v.major, v.minor);
+    (prop::sample::select(ops), any::<bool>())
+        .prop_map(move |(op, with_minor)| Comparator {
+            op,
+            major,
+            minor: with_minor.then_some(minor),
+            patch: None,
+            pre: Prerelease::EMPTY,
+        })
+        .boxed()
+}
+
+/// The operators which match the version a comparator names.
+fn inclusive_ops() -> Vec<Op> {
+    vec![Op::Exact, Op::GreaterEq, Op::LessEq, Op::Tilde, Op::Caret]
+}
+
+/// A comparator matching `v`, ignoring the rule that a Pre-Release is only
+/// matched by a requirement with a comparator naming it's `MAJOR.MINOR.PATCH`.
+fn arb_comparator_matching(v: Version) -> BoxedStrategy<Comparator> {
+    // NOTE(canardleteer): `semver` only matches a Pre-Release against just a
+    //                     `MAJOR` or `MAJOR.MINOR` for `^`, since the others
+    //                     compare against an empty Pre-Release.
+    let partial_ops = if v.pre.is_empty() {
+        let mut ops = inclusive_ops();
+        ops.push(Op::Wildcard);
+        ops
+    } else {
+        vec![Op::Caret]
+    };
+
+    let mut options = vec![
+        arb_comparator_naming(inclusive_ops(), Just(v.clone())),
+        arb_comparator_partial(partial_ops, &v),
+    ];
+    if let Some(below) = arb_version_below(&v) {
+        options.push(arb_comparator_naming(
+            vec![Op::Greater, Op::GreaterEq],
+            below,
+        ));
+    }
+    if let Some(above) = arb_version_above(&v) {
+        options.push(arb_comparator_naming(vec![Op::Less, Op::LessEq], above));
+    }
+
+    Union::new(options).boxed()
+}
+
+/// Provides diverse requirements which all match `v`, for testing "does my
+/// manifest accept this release" logic.
+///
+/// Every requirement has a comparator naming all of `v` (with `=`, `>=`,
+/// `<=`, `~` or `^`), so Pre-Releases are matched too. The other comparators
+/// are bounds below or above `v`, or name just it's `MAJOR` or `MAJOR.MINOR`
+/// (including wildcards like `1.*`, when `v` isn't a Pre-Release).
+pub fn arb_version_req_matching(v: &Version) -> impl Strategy<Value = VersionReq> {
+    (
+        arb_comparator_naming(inclusive_ops(), Just(v.clone())),
+        prop::collection::vec(arb_comparator_matching(v.clone()), 0..4),
+    )
+        .prop_map(|(naming, others)| std::iter::once(naming).chain(others).collect::<Vec<_>>())
+        .prop_shuffle()
+        .prop_map(|comparators| VersionReq { comparators })
+}
+
+/// Provides a [semver::VersionReq] and a [semver::Version], where the
+/// requirement always matches the version, for testing the positive paths of
+/// resolvers.
+///
+/// See [arb_version_req_matching] for the requirements.
+pub fn arb_matching_req_version_pair() -> impl Strategy<Value = (VersionReq, Version)> {
+    arb_version()
+        .prop_flat_map(|v| arb_version_req_matching(&v).prop_map(move |req| (req, v.clone())))
+}
+
+/// A comparator naming exactly the `MAJOR.MINOR.PATCH` of `v`, with a
+/// different Pre-Release.
+fn arb_comparator_other_pre_release(v: &Version) -> BoxedStrategy<Comparator> {
+    let v = v.clone();
+    arb_semver_prerelease()
+        .prop_map(move |pre| Comparator {
+            op: Op::Exact,
+            major: v.major,
+            minor: Some(v.minor),
+            patch: Some(v.patch),
+            pre: if pre == v.pre {
+                extend_pre_release(&pre, "0")
+            } else {
+                pre
+            },
+        })
+        .boxed()
+}
+
+/// A comparator which never matches `v`.
+fn arb_comparator_not_matching(v: Version) -> BoxedStrategy<Comparator> {
+    let mut options = vec![
+        arb_comparator_naming(vec![Op::Greater, Op::Less], Just(v.clone())),
+        arb_comparator_partial(vec![Op::Greater, Op::Less], &v),
+        arb_comparator_other_pre_release(&v),
+    ];
+    if let Some(below) = arb_version_below(&v) {
+        options.push(arb_comparator_naming(
+            vec![Op::Exact, Op::Less, Op::LessEq],
+            below,
+        ));
+    }
+    if let Some(above) = arb_version_above(&v) {
+        options.push(arb_comparator_naming(
+            vec![Op::Exact, Op::Greater, Op::GreaterEq, Op::Tilde, Op::Caret],
+            above,
+        ));
+    }
+
+    Union::new(options).boxed()
+}
+
+/// Provides requirements which never match `v`, biased toward excluding it
+/// by as little as possible, for testing the rejection paths of resolvers
+/// without filtering.
+///
+/// Most requirements have a comparator which alone rejects `v` (such as `>`
+/// or `<` naming it, an exact version just beside it, `>MAJOR`, or `=` with
+/// a different Pre-Release), while the other comparators each match `v`, so
+/// the rejection isn't always from the first comparator.
+///
+/// When `v` is a Pre-Release, the rest are a single comparator which never
+/// pairs it's `MAJOR.MINOR.PATCH` with a Pre-Release (such as `<=1.2.3`,
+/// `>=1.2.3` or `~1.2` for `1.2.3-alpha`), so `v` is rejected either by the
+/// bounds of the comparator, or by the Pre-Release rule.
+pub fn arb_version_req_excluding(v: &Version) -> BoxedStrategy<VersionReq> {
+    let rejecting = (
+        arb_comparator_not_matching(v.clone()),
+        prop::collection::vec(arb_comparator_matching(v.clone()), 0..4),
+    )
+        .prop_map(|(rejecting, others)| {
+            std::iter::once(rejecting).chain(others).collect::<Vec<_>>()
+        })
+        .prop_shuffle()
+        .prop_map(|comparators| VersionReq { comparators });
+
+    if v.pre.is_empty() {
+        return rejecting.boxed();
+    }
+
+    let release = version_of(triple(v), None);
+    let pre_release_mismatch = Union::new(vec![
+        arb_comparator_naming(vec![Op::LessEq, Op::GreaterEq, Op::Tilde], Just(release)),
+        arb_comparator_partial(vec![Op::Exact, Op::GreaterEq, Op::LessEq, Op::Tilde], v),
+    ])
+    .prop_map(|c| VersionReq {
+        comparators: vec![c],
+    });
+
+    prop_oneof![3 => rejecting, 1 => pre_release_mismatch].boxed()
+}
+
+/// Provides a [semver::VersionReq] and a [semver::Version], where the
+/// requirement never matches the version.
+///
+/// See [arb_version_req_excluding] for the requirements.
+pub fn arb_non_matching_req_version_pair() -> impl Strategy<Value = (VersionReq, Version)> {
+    arb_version()
+        .prop_flat_map(|v| arb_version_req_excluding(&v).prop_map(move |req| (req, v.clone())))
+}
+
+/// Provides requirements which match no version at all, such as
+/// `>2.0.0, <1.0.0`, for testing resolver error paths and "no version
+/// matches" reporting.
+///
+/// Every requirement has a comparator only matching versions above some
+/// version, and one only matching versions up to and including it (such as
+/// `>1.2.3` and `<=1.2.3`, or `^1.3.0` and `=1.2.0`), with up to two other
+/// comparators, in any order.
+pub fn arb_unsatisfiable_version_req() -> impl Strategy<Value = VersionReq> {
+    arb_version().prop_flat_map(|v| {
+        let mut above = vec![
+            arb_comparator_naming(vec![Op::Greater], Just(v.clone())),
+            arb_comparator_partial(vec![Op::Greater], &v),
+        ];
+        if let Some(w) = arb_version_above(&v) {
+            above.push(arb_comparator_naming(
+                vec![Op::Exact, Op::Greater, Op::GreaterEq, Op::Tilde, Op::Caret],
+                w,
+            ));
+        }
+
+        let mut below = vec![arb_comparator_naming(
+            vec![Op::Exact, Op::Less, Op::LessEq],
+            Just(v.clone()),
+        )];
+        // NOTE(canardleteer): `<1.2` would admit `1.2.0-beta` alongside
+        //                     `>1.2.0-alpha`, so it's only below a release.
+        if v.pre.is_empty() {
+            below.push(arb_comparator_partial(vec![Op::Less], &v));
+        }
+        if let Some(w) = arb_version_below(&v) {
+            below.push(arb_comparator_naming(
+                vec![Op::Exact, Op::Less, Op::LessEq],
+                w,
+            ));
+        }
+
+        (
+            Union::new(above),
+            Union::new(below),
+            prop::collection::vec(arb_comparator_matching(v), 0..3),
+        )
+            .prop_map(|(above, below, others)| {
+                [above, below].into_iter().chain(others).collect::<Vec<_>>()
+            })
+            .prop_shuffle()
+            .prop_map(|comparators| VersionReq { comparators })
+    })
+}
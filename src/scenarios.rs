@@ -0,0 +1,272 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+//!
+//! Scenarios from systems keyed by semver, alongside their expected outcomes.
+use proptest::prelude::*;
+use proptest::strategy::Union;
+use semver::{BuildMetadata, Version, VersionReq};
+
+use crate::{arb_version_req, arb_version_stratified, arb_version_weighted};
+
+/// A single step of a [SchemaMigrationCase].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MigrationStep {
+    /// The schema version this step migrates from.
+    pub from: Version,
+
+    /// The schema version this step migrates to.
+    pub to: Version,
+}
+
+/// A schema migration from [SchemaMigrationCase::current] to
+/// [SchemaMigrationCase::target], keyed by semver.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SchemaMigrationCase {
+    /// The version of the schema before migrating.
+    pub current: Version,
+
+    /// The version of the schema to migrate to, which always has a higher
+    /// precedence than `current`.
+    pub target: Version,
+
+    /// The steps of the migration, in the order they apply.
+    ///
+    /// When the chain is whole, each step migrates from the version the step
+    /// before it (or `current`) migrated to, and the last step migrates to
+    /// `target`.
+    pub steps: Vec<MigrationStep>,
+
+    /// When `Some`, the chain is broken, and this is the index into `steps`
+    /// where a step went missing. Either the step at this index doesn't start
+    /// where the chain left off, or it's `steps.len()`, and the chain ends
+    /// before reaching `target`.
+    pub broken: Option<usize>,
+}
+
+/// Provides a [SchemaMigrationCase], for testing migration runners keyed by
+/// semver.
+///
+/// Every version in a case is distinct by precedence, and the steps always
+/// migrate to a higher precedence than they migrate from. Versions are
+/// sometimes a Pre-Release, but never have Build Metadata.
+///
+/// * `probability_of_broken` - Follows [proptest::option::Probability] rules,
+///   for the chain having a missing step.
+pub fn arb_schema_migration_case(
+    probability_of_broken: f64,
+) -> impl Strategy<Value = SchemaMigrationCase> {
+    let version = arb_version_weighted(0.25, 0.5).prop_map(|mut v| {
+        v.build = BuildMetadata::EMPTY;
+        v
+    });
+
+    prop::collection::btree_set(version, 2..8)
+        .prop_flat_map(move |versions| {
+            let versions = versions.into_iter().collect::<Vec<_>>();
+            let missing = prop::option::weighted(probability_of_broken, 0..versions.len() - 1);
+            (Just(versions), missing)
+        })
+        .prop_map(|(versions, missing)| {
+            let mut steps = versions
+                .windows(2)
+                .map(|w| MigrationStep {
+                    from: w[0].clone(),
+                    to: w[1].clone(),
+                })
+                .collect::<Vec<_>>();
+
+            // Removing any step leaves a gap at it's index, including the last
+            // step, which leaves the chain short of the target.
+            if let Some(missing) = missing {
+                steps.remove(missing);
+            }
+
+            SchemaMigrationCase {
+                current: versions[0].clone(),
+                target: versions[versions.len() - 1].clone(),
+                steps,
+                broken: missing,
+            }
+        })
+}
+
+/// Provides a rollout window for a version gated feature, as the
+/// [semver::VersionReq] of clients it's enabled for, sample client versions,
+/// and whether the feature is expected to be enabled for each of them.
+///
+/// Client versions are sampled from every region of the requirement (see
+/// [arb_version_stratified]), so both enabled and disabled clients are
+/// common.
+///
+/// * `max_comparators` - Should always be less than or equal to
+///   [crate::MAX_COMPARATORS_IN_VERSION_REQ_STRING].
+pub fn arb_rollout_window(
+    max_comparators: usize,
+) -> impl Strategy<Value = (VersionReq, Vec<Version>, Vec<bool>)> {
+    arb_version_req(max_comparators)
+        .prop_flat_map(|req| (arb_version_stratified(&req), Just(req)))
+        .prop_map(|(sampled, req)| {
+            let clients = sampled.into_iter().map(|(_, v)| v).collect::<Vec<_>>();
+            let enabled = clients.iter().map(|v| req.matches(v)).collect();
+            (req, clients, enabled)
+        })
+}
+
+/// Host versions, the requirements plugins declare on their host, and which
+/// plugins are compatible with which hosts.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PluginCompatMatrix {
+    pub hosts: Vec<Version>,
+
+    /// The requirement each plugin declares on it's host.
+    pub plugins: Vec<VersionReq>,
+
+    /// Indexed by plugin, then host, whether the plugin is compatible with
+    /// the host.
+    pub compatible: Vec<Vec<bool>>,
+}
+
+/// Provides a [PluginCompatMatrix], for testing plugin loaders that gate on
+/// the version of their host.
+///
+/// Each host version is sampled from some region of some plugin's requirement
+/// (see [arb_version_stratified]), so both compatible and incompatible pairs
+/// are common.
+///
+/// * `hosts` - The number of host versions.
+/// * `plugins` - The number of plugins, which is at least `1`.
+pub fn arb_plugin_compat_matrix(
+    hosts: usize,
+    plugins: usize,
+) -> impl Strategy<Value = PluginCompatMatrix> {
+    assert!(plugins >= 1, "there are no plugins to sample hosts from");
+
+    prop::collection::vec(arb_version_req(3), plugins)
+        .prop_flat_map(move |plugins| {
+            let host = Union::new(plugins.iter().map(|req| {
+                arb_version_stratified(req)
+                    .prop_flat_map(prop::sample::select)
+                    .prop_map(|(_, v)| v)
+            }));
+            (prop::collection::vec(host, hosts), Just(plugins))
+        })
+        .prop_map(|(hosts, plugins)| {
+            let compatible = plugins
+                .iter()
+                .map(|req| hosts.iter().map(|v| req.matches(v)).collect())
+                .collect();
+            PluginCompatMatrix {
+                hosts,
+                plugins,
+                compatible,
+            }
+        })
+}
+
+/// The labels [arb_ci_matrix] draws it's operating systems from.
+pub const CI_OS_LABELS: &[&str] = &["linux", "macos", "windows", "freebsd"];
+
+/// A dependency version in a [CiMatrix], and the constraints it places on
+/// the cells it appears in.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CiDependency {
+    pub version: Version,
+
+    /// The toolchains this dependency version builds with, always of the
+    /// form `>=1.X.0`.
+    pub toolchain: VersionReq,
+
+    /// An operating system this dependency version doesn't support, which
+    /// may not be part of the matrix at all.
+    pub unsupported_os: Option<String>,
+}
+
+/// A single cell of a [CiMatrix], indexing into it's axes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CiCell {
+    pub toolchain: usize,
+    pub dependency: usize,
+    pub os: usize,
+
+    /// Whether the cell survives the constraints of it's dependency.
+    pub included: bool,
+}
+
+/// The axes of a CI matrix, and every cell of their expansion.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CiMatrix {
+    /// Distinct toolchain versions, in ascending order.
+    pub toolchains: Vec<Version>,
+
+    pub dependencies: Vec<CiDependency>,
+
+    /// Distinct labels from [CI_OS_LABELS].
+    pub os: Vec<String>,
+
+    /// The full cartesian product of the axes, ordered by toolchain, then
+    /// dependency, then operating system.
+    pub cells: Vec<CiCell>,
+}
+
+/// Provides a [CiMatrix], for testing CI matrix expansion tools that
+/// reason about semver constraints.
+///
+/// Toolchains and the minimum toolchain of each dependency are drawn from
+/// the same narrow range of `1.X.0` versions, so both included and excluded
+/// cells are common.
+pub fn arb_ci_matrix() -> impl Strategy<Value = CiMatrix> {
+    let toolchains = prop::collection::btree_set(60..80u64, 1..5)
+        .prop_map(|minors| minors.into_iter().map(|m| Version::new(1, m, 0)).collect());
+    let dependency = (
+        (0..4u64, 0..20u64, 0..10u64),
+        60..80u64,
+        prop::option::of(prop::sample::select(CI_OS_LABELS)),
+    )
+        .prop_map(
+            |((major, minor, patch), msrv, unsupported_os)| CiDependency {
+                version: Version::new(major, minor, patch),
+                toolchain: VersionReq::parse(&format!(">=1.{msrv}.0")).unwrap(),
+                unsupported_os: unsupported_os.map(str::to_string),
+            },
+        );
+    let os = prop::sample::subsequence(CI_OS_LABELS, 1..=CI_OS_LABELS.len())
+        .prop_map(|os| os.into_iter().map(str::to_string).collect::<Vec<_>>());
+
+    (toolchains, prop::collection::vec(dependency, 1..4), os).prop_map(
+        |(toolchains, dependencies, os): (Vec<Version>, Vec<CiDependency>, Vec<String>)| {
+            let mut cells = Vec::new();
+            for (t, toolchain) in toolchains.iter().enumerate() {
+                for (d, dependency) in dependencies.iter().enumerate() {
+                    for (o, label) in os.iter().enumerate() {
+                        cells.push(CiCell {
+                            toolchain: t,
+                            dependency: d,
+                            os: o,
+                            included: dependency.toolchain.matches(toolchain)
+                                && dependency.unsupported_os.as_ref() != Some(label),
+                        });
+                    }
+                }
+            }
+
+            CiMatrix {
+                toolchains,
+                dependencies,
+                os,
+                cells,
+            }
+        },
+    )
+}
@@ -0,0 +1,449 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+//!
+//! Sequences of versions, with some known relationship between neighbours.
+use proptest::prelude::*;
+use semver::{Prerelease, Version};
+use std::collections::BTreeMap;
+
+use crate::{arb_semver_prerelease, arb_version};
+
+/// The difference between two consecutive versions from
+/// [arb_version_delta_sequence], in exactly one component.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VersionDelta {
+    Major(i64),
+    Minor(i64),
+    Patch(i64),
+}
+
+impl VersionDelta {
+    /// Applies this delta to `v`, returning `None` if the component would
+    /// leave the range of a [u64]. Every other component is left as is.
+    pub fn apply(&self, v: &Version) -> Option<Version> {
+        let mut v = v.clone();
+        let (component, amount) = match *self {
+            VersionDelta::Major(amount) => (&mut v.major, amount),
+            VersionDelta::Minor(amount) => (&mut v.minor, amount),
+            VersionDelta::Patch(amount) => (&mut v.patch, amount),
+        };
+        *component = component.checked_add_signed(amount)?;
+        Some(v)
+    }
+
+    fn negate(self) -> Self {
+        match self {
+            VersionDelta::Major(amount) => VersionDelta::Major(-amount),
+            VersionDelta::Minor(amount) => VersionDelta::Minor(-amount),
+            VersionDelta::Patch(amount) => VersionDelta::Patch(-amount),
+        }
+    }
+}
+
+fn arb_version_delta() -> impl Strategy<Value = VersionDelta> {
+    let amount = prop_oneof![1i64..=16, -16i64..=-1];
+    prop_oneof![
+        amount.clone().prop_map(VersionDelta::Major),
+        amount.clone().prop_map(VersionDelta::Minor),
+        amount.prop_map(VersionDelta::Patch),
+    ]
+}
+
+/// Provides a sequence of release versions, where each consecutive pair
+/// differs in exactly one component by a small amount (at most `16`, up or
+/// down), alongside the deltas between them, for testing delta encoding of
+/// version indexes.
+///
+/// Applying `deltas[i]` to `versions[i]` (see [VersionDelta::apply]) always
+/// gives `versions[i + 1]`. Components start anywhere in [u64], and a delta
+/// that would leave it's range is turned around.
+///
+/// * `len` - The number of versions, which is at least `1`.
+pub fn arb_version_delta_sequence(
+    len: usize,
+) -> impl Strategy<Value = (Vec<Version>, Vec<VersionDelta>)> {
+    assert!(len >= 1, "there is no first version");
+
+    (
+        any::<(u64, u64, u64)>(),
+        prop::collection::vec(arb_version_delta(), len - 1),
+    )
+        .prop_map(|((major, minor, patch), deltas)| {
+            let mut versions = vec![Version::new(major, minor, patch)];
+            let deltas = deltas
+                .into_iter()
+                .map(|delta| {
+                    let last = versions.last().unwrap();
+                    // At most one direction can leave the range.
+                    let (next, delta) = match delta.apply(last) {
+                        Some(next) => (next, delta),
+                        None => (delta.negate().apply(last).unwrap(), delta.negate()),
+                    };
+                    versions.push(next);
+                    delta
+                })
+                .collect();
+            (versions, deltas)
+        })
+}
+
+/// A step of the history from [arb_version_history].
+#[derive(Clone, Debug)]
+enum Bump {
+    Patch,
+    Minor,
+    Major,
+
+    /// A run of Pre-Releases of the next `MINOR` (or `MAJOR`, when `true`),
+    /// with the number of each of `alpha`, `beta` and `rc`, followed by it's
+    /// release.
+    PreReleaseRun(bool, [u8; 3]),
+}
+
+fn arb_bump() -> impl Strategy<Value = Bump> {
+    prop_oneof![
+        12 => Just(Bump::Patch),
+        4 => Just(Bump::Minor),
+        1 => Just(Bump::Major),
+        2 => (any::<bool>(), [0..3u8, 0..3u8, 1..3u8]).prop_map(|(major, counts)| Bump::PreReleaseRun(major, counts)),
+    ]
+}
+
+/// Provides a package's release history, in strictly increasing order of
+/// precedence, for testing "latest matching version" selection.
+///
+/// Starting from `0.1.0` or `1.0.0`, `PATCH` bumps are most common, with
+/// occasional `MINOR` and rarer `MAJOR` bumps, and sometimes a run of
+/// Pre-Releases (`-alpha.N`, `-beta.N`, then `-rc.N`) before a `MINOR` or
+/// `MAJOR` release. The history may end part way through such a run.
+///
+/// * `max_len` - The maximum number of versions, which is at least `1`.
+pub fn arb_version_history(max_len: usize) -> impl Strategy<Value = Vec<Version>> {
+    assert!(max_len >= 1, "there is no first version");
+
+    (
+        prop_oneof![Just(Version::new(0, 1, 0)), Just(Version::new(1, 0, 0))],
+        1..=max_len,
+        prop::collection::vec(arb_bump(), max_len),
+    )
+        .prop_map(|(first, len, bumps)| {
+            let mut history = vec![first];
+            for bump in bumps {
+                let last = history.last().unwrap();
+                let (major, minor, patch) = (last.major, last.minor, last.patch);
+                match bump {
+                    Bump::Patch => history.push(Version::new(major, minor, patch + 1)),
+                    Bump::Minor => history.push(Version::new(major, minor + 1, 0)),
+                    Bump::Major => history.push(Version::new(major + 1, 0, 0)),
+                    Bump::PreReleaseRun(to_major, counts) => {
+                        let next = if to_major {
+                            Version::new(major + 1, 0, 0)
+                        } else {
+                            Version::new(major, minor + 1, 0)
+                        };
+                        for (tag, count) in ["alpha", "beta", "rc"].into_iter().zip(counts) {
+                            for n in 1..=count {
+                                let mut v = next.clone();
+                                v.pre = Prerelease::new(&format!("{tag}.{n}")).unwrap();
+                                history.push(v);
+                            }
+                        }
+                        history.push(next);
+                    }
+                }
+                if history.len() >= len {
+                    break;
+                }
+            }
+            history.truncate(len);
+            history
+        })
+}
+
+/// The promotion of a release through it's Pre-Releases, from
+/// [arb_promotion_path].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PromotionPath {
+    /// Each version promoted, in the order they were promoted.
+    pub path: Vec<Version>,
+
+    /// The index of the first version in [PromotionPath::path] without a
+    /// higher precedence than the one before it, if the path is invalid.
+    pub out_of_order: Option<usize>,
+}
+
+/// How [arb_promotion_path] makes a valid ladder invalid.
+#[derive(Clone, Copy, Debug)]
+enum Injection {
+    /// Moves the version at the first index to the second, earlier index.
+    Move(usize, usize),
+
+    /// Promotes the version at the index a second time.
+    Repeat(usize),
+}
+
+/// Provides the promotion of `base` through it's Pre-Releases (`-alpha.N`,
+/// `-beta.N`, then `-rc.N`) to it's release, for testing release gating
+/// workflows.
+///
+/// About half of the paths are valid, in strictly increasing order of
+/// precedence. The rest have a version moved out of order, or promoted
+/// twice, and are flagged by [PromotionPath::out_of_order].
+///
+/// * `base` - The release being promoted. It's Pre-Release and Build
+///   Metadata are ignored.
+pub fn arb_promotion_path(base: &Version) -> impl Strategy<Value = PromotionPath> {
+    let release = Version::new(base.major, base.minor, base.patch);
+
+    [1..4u8, 1..4u8, 1..4u8]
+        .prop_map(move |counts| {
+            let mut ladder = Vec::new();
+            for (tag, count) in ["alpha", "beta", "rc"].into_iter().zip(counts) {
+                for n in 1..=count {
+                    let mut v = release.clone();
+                    v.pre = Prerelease::new(&format!("{tag}.{n}")).unwrap();
+                    ladder.push(v);
+                }
+            }
+            ladder.push(release.clone());
+            ladder
+        })
+        .prop_flat_map(|ladder| {
+            let len = ladder.len();
+            let injection = (1..len).prop_flat_map(|from| {
+                prop_oneof![
+                    (0..from).prop_map(move |to| Injection::Move(from, to)),
+                    Just(Injection::Repeat(from)),
+                ]
+            });
+            (Just(ladder), prop::option::of(injection))
+        })
+        .prop_map(|(mut path, injection)| {
+            match injection {
+                Some(Injection::Move(from, to)) => {
+                    let v = path.remove(from);
+                    path.insert(to, v);
+                }
+                Some(Injection::Repeat(i)) => path.insert(i + 1, path[i].clone()),
+                None => {}
+            }
+            let out_of_order = path
+                .windows(2)
+                .position(|pair| pair[0].cmp_precedence(&pair[1]).is_ge())
+                .map(|i| i + 1);
+
+            PromotionPath { path, out_of_order }
+        })
+}
+
+/// Provides a run of Pre-Releases of `target`, followed by `target` itself,
+/// in strictly increasing order of precedence, such as `2.0.0-alpha.1 <
+/// 2.0.0-beta.1 < 2.0.0-rc.1 < 2.0.0`, for testing "is this the final
+/// release" logic.
+///
+/// Any of the `alpha`, `beta` and `rc` stages may be skipped, numbers within a
+/// stage may skip (such as `-beta.2` then `-beta.5`), and a stage sometimes
+/// starts with it's bare tag (such as `-rc` before `-rc.1`). A chain may be
+/// just `target`.
+///
+/// * `target` - The release the chain ends with. It's Pre-Release and Build
+///   Metadata are ignored.
+/// * `max_len` - The maximum number of versions, including `target`, which is
+///   at least `1`.
+pub fn arb_prerelease_chain(
+    target: &Version,
+    max_len: usize,
+) -> impl Strategy<Value = Vec<Version>> {
+    assert!(max_len >= 1, "the chain always ends with the release");
+
+    let release = Version::new(target.major, target.minor, target.patch);
+
+    // A stage and number, where `0` is the bare tag, ordered as they're
+    // promoted.
+    let step = (0..3usize, prop_oneof![1 => Just(0u64), 4 => 1..10u64]);
+    prop::collection::btree_set(step, 0..max_len).prop_map(move |steps| {
+        let mut chain = steps
+            .into_iter()
+            .map(|(stage, n)| {
+                let tag = ["alpha", "beta", "rc"][stage];
+                let pre = match n {
+                    0 => tag.to_string(),
+                    n => format!("{tag}.{n}"),
+                };
+                let mut v = release.clone();
+                v.pre = Prerelease::new(&pre).unwrap();
+                v
+            })
+            .collect::<Vec<_>>();
+        chain.push(release.clone());
+        chain
+    })
+}
+
+/// A release channel, such as a toolchain manager's, ordered from least to
+/// most bleeding edge.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Channel {
+    /// Releases, without a Pre-Release.
+    Stable,
+
+    /// Pre-Releases of `beta.N`, of the next `MINOR` after stable.
+    Beta,
+
+    /// Pre-Releases of `nightly.N`, of the `MINOR` after beta.
+    Nightly,
+}
+
+impl Channel {
+    /// How many `MINOR`s ahead of stable this channel is.
+    fn lead(self) -> u64 {
+        match self {
+            Channel::Stable => 0,
+            Channel::Beta => 1,
+            Channel::Nightly => 2,
+        }
+    }
+}
+
+/// The releases of a single channel, ending at the `MINOR` of the channel.
+fn arb_channel_stream(
+    channel: Channel,
+    major: u64,
+    stable: u64,
+) -> impl Strategy<Value = Vec<Version>> {
+    let latest = stable + channel.lead();
+    prop::collection::vec(1..4u64, 1..=latest.min(4) as usize + 1).prop_map(move |counts| {
+        let first = latest + 1 - counts.len() as u64;
+        let mut releases = Vec::new();
+        for (minor, count) in (first..=latest).zip(counts) {
+            for n in 0..count {
+                let mut v = Version::new(major, minor, 0);
+                match channel {
+                    Channel::Stable => v.patch = n,
+                    Channel::Beta => v.pre = Prerelease::new(&format!("beta.{}", n + 1)).unwrap(),
+                    Channel::Nightly => {
+                        v.pre = Prerelease::new(&format!("nightly.{}", n + 1)).unwrap()
+                    }
+                }
+                releases.push(v);
+            }
+        }
+        releases
+    })
+}
+
+/// Provides the releases of each of `channels`, for testing channel aware
+/// update clients like toolchain managers.
+///
+/// Each channel's releases are in strictly increasing order of precedence,
+/// and span a few `MINOR`s of the same `MAJOR`. The latest nightly has a
+/// higher precedence than the latest beta, which has a higher precedence than
+/// the latest stable release, and every Pre-Release is tagged for it's
+/// channel (see [Channel]).
+///
+/// * `channels` - The channels to provide releases of.
+pub fn arb_channel_releases(
+    channels: &[Channel],
+) -> impl Strategy<Value = BTreeMap<Channel, Vec<Version>>> {
+    let channels = channels.to_vec();
+    (0..3u64, 0..100u64).prop_flat_map(move |(major, stable)| {
+        channels
+            .iter()
+            .map(|&channel| (Just(channel), arb_channel_stream(channel, major, stable)))
+            .collect::<Vec<_>>()
+            .prop_map(|streams| streams.into_iter().collect())
+    })
+}
+
+/// Bumps `base` with `bump`, which returns `None` on overflow, sometimes
+/// giving the bumped version a fresh Pre-Release.
+fn arb_bump_of(
+    base: Option<Version>,
+    probability_of_pre_release: f64,
+    bump: fn(&Version) -> Option<Version>,
+) -> BoxedStrategy<(Version, Version)> {
+    let base = match base {
+        Some(base) => {
+            assert!(bump(&base).is_some(), "{base} can't be bumped");
+            Just(base).boxed()
+        }
+        None => arb_version()
+            .prop_filter("the component must not overflow", move |base| {
+                bump(base).is_some()
+            })
+            .boxed(),
+    };
+
+    (
+        base,
+        prop::bool::weighted(probability_of_pre_release),
+        arb_semver_prerelease(),
+    )
+        .prop_map(move |(base, with_pre_release, pre)| {
+            let mut bumped = bump(&base).unwrap();
+            if with_pre_release {
+                bumped.pre = pre;
+            }
+            (base, bumped)
+        })
+        .boxed()
+}
+
+/// Provides `(base, bumped)`, where `bumped` is the next `MAJOR` of `base`
+/// (such as `2.0.0` after `1.2.3`), for testing release automation and
+/// changelog tooling.
+///
+/// The bumped version never has Build Metadata, and sometimes has a fresh
+/// Pre-Release (such as `2.0.0-rc.1`), which still has a higher precedence
+/// than `base`.
+///
+/// * `base` - The version to bump, or `None` to generate one. Panics if it's
+///   `MAJOR` can't be bumped.
+/// * `probability_of_pre_release` - The probability the bumped version is a
+///   Pre-Release, from `0.0` to `1.0`.
+pub fn arb_major_bump_of(
+    base: Option<Version>,
+    probability_of_pre_release: f64,
+) -> impl Strategy<Value = (Version, Version)> {
+    arb_bump_of(base, probability_of_pre_release, |v| {
+        Some(Version::new(v.major.checked_add(1)?, 0, 0))
+    })
+}
+
+/// Provides `(base, bumped)`, where `bumped` is the next `MINOR` of `base`
+/// (such as `1.3.0` after `1.2.3`).
+///
+/// See [arb_major_bump_of] for the parameters.
+pub fn arb_minor_bump_of(
+    base: Option<Version>,
+    probability_of_pre_release: f64,
+) -> impl Strategy<Value = (Version, Version)> {
+    arb_bump_of(base, probability_of_pre_release, |v| {
+        Some(Version::new(v.major, v.minor.checked_add(1)?, 0))
+    })
+}
+
+/// Provides `(base, bumped)`, where `bumped` is the next `PATCH` of `base`
+/// (such as `1.2.4` after `1.2.3`).
+///
+/// See [arb_major_bump_of] for the parameters.
+pub fn arb_patch_bump_of(
+    base: Option<Version>,
+    probability_of_pre_release: f64,
+) -> impl Strategy<Value = (Version, Version)> {
+    arb_bump_of(base, probability_of_pre_release, |v| {
+        Some(Version::new(v.major, v.minor, v.patch.checked_add(1)?))
+    })
+}
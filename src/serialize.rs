@@ -0,0 +1,90 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+//!
+//! `serde` representations for the types this crate uses in it's
+//! configuration, which don't implement `Serialize` or `Deserialize`
+//! themselves, for use with `#[serde(with = "...")]`.
+//!
+//! Each rejects values which can't be generated from, so a configuration
+//! fails to deserialize rather than panicking once it's used.
+use serde::{de::Error, Deserialize, Deserializer};
+
+/// A `[start, end]` pair, where `start` is less than `end`.
+fn non_empty_pair<'de, D: Deserializer<'de>>(d: D) -> Result<(usize, usize), D::Error> {
+    let (start, end) = <(usize, usize)>::deserialize(d)?;
+    if start < end {
+        Ok((start, end))
+    } else {
+        Err(D::Error::custom(format!(
+            "`[{start}, {end}]` is empty, where it's start must be less than it's end"
+        )))
+    }
+}
+
+/// A number within `0.0..=1.0`, such as a probability as a [f64], for use
+/// with `#[serde(deserialize_with = "...")]`.
+pub(crate) fn unit_interval<'de, D: Deserializer<'de>>(d: D) -> Result<f64, D::Error> {
+    let p = f64::deserialize(d)?;
+    if (0.0..=1.0).contains(&p) {
+        Ok(p)
+    } else {
+        Err(D::Error::custom(format!("`{p}` is not within `0.0..=1.0`")))
+    }
+}
+
+/// A [proptest::option::Probability] as a number within `0.0..=1.0`.
+pub(crate) mod probability {
+    use proptest::option::Probability;
+    use serde::{Deserializer, Serialize, Serializer};
+
+    pub(crate) fn serialize<S: Serializer>(p: &Probability, s: S) -> Result<S::Ok, S::Error> {
+        f64::from(*p).serialize(s)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Probability, D::Error> {
+        super::unit_interval(d).map(Probability::new)
+    }
+}
+
+/// A non-empty [std::ops::Range] as a `[start, end]` pair.
+pub(crate) mod range {
+    use serde::{Deserializer, Serialize, Serializer};
+    use std::ops::Range;
+
+    pub(crate) fn serialize<S: Serializer>(r: &Range<usize>, s: S) -> Result<S::Ok, S::Error> {
+        (r.start, r.end).serialize(s)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Range<usize>, D::Error> {
+        let (start, end) = super::non_empty_pair(d)?;
+        Ok(start..end)
+    }
+}
+
+/// A non-empty [proptest::collection::SizeRange] as a `[start, end]` pair,
+/// where `end` is exclusive.
+pub(crate) mod size_range {
+    use proptest::collection::SizeRange;
+    use serde::{Deserializer, Serialize, Serializer};
+
+    pub(crate) fn serialize<S: Serializer>(r: &SizeRange, s: S) -> Result<S::Ok, S::Error> {
+        (r.start(), r.end_excl()).serialize(s)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<SizeRange, D::Error> {
+        let (start, end) = super::non_empty_pair(d)?;
+        Ok((start..end).into())
+    }
+}
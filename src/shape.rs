@@ -0,0 +1,150 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+//!
+//! A single, reusable description of the shape of versions to generate,
+//! rather than a growing list of positional parameters.
+use proptest::option::Probability;
+use proptest::prelude::*;
+use semver::{BuildMetadata, Prerelease, Version};
+use std::ops::Range;
+
+use crate::{ArbBuildMetadata, ArbPrerelease, BuildMetadataParams, PrereleaseParams};
+
+/// The number of decimal digits in [u64::MAX].
+pub(crate) const MAX_DIGITS: usize = 20;
+
+/// Describes the shape of versions from [arb_version_shaped].
+///
+/// With the `serde` feature, this is `Serialize` and `Deserialize`, where
+/// missing fields are their default, and ranges are `[start, end]` pairs.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default, deny_unknown_fields)
+)]
+pub struct Shape {
+    /// (default: `0.5`) The probability of having a Pre-Release.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialize::probability"))]
+    pub has_pre: Probability,
+
+    /// (default: `1..4`) The number of identifiers in a Pre-Release, when
+    /// there is one, which is at least `1`.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialize::range"))]
+    pub pre_idents: Range<usize>,
+
+    /// (default: `0.5`) The probability of having Build Metadata.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialize::probability"))]
+    pub has_build: Probability,
+
+    /// (default: `1..4`) The number of identifiers in Build Metadata, when
+    /// there is some, which is at least `1`.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialize::range"))]
+    pub build_idents: Range<usize>,
+
+    /// (default: `1..21`) The number of decimal digits in each of `MAJOR`,
+    /// `MINOR` and `PATCH`, within `1..21` (since [u64::MAX] has 20).
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialize::range"))]
+    pub digits: Range<usize>,
+}
+
+impl Default for Shape {
+    fn default() -> Self {
+        Self {
+            has_pre: Probability::new(0.5),
+            pre_idents: 1..4,
+            has_build: Probability::new(0.5),
+            build_idents: 1..4,
+            digits: 1..MAX_DIGITS + 1,
+        }
+    }
+}
+
+/// A component with some number of decimal digits in `digits`.
+fn arb_component_of_digits(digits: Range<usize>) -> impl Strategy<Value = u64> {
+    (digits.start.max(1)..digits.end.min(MAX_DIGITS + 1)).prop_flat_map(|digits| {
+        let low = if digits == 1 {
+            0
+        } else {
+            10u64.pow(digits as u32 - 1)
+        };
+        let high = 10u64.checked_pow(digits as u32).map_or(u64::MAX, |n| n - 1);
+        low..=high
+    })
+}
+
+/// Provides versions of some [Shape].
+pub fn arb_version_shaped(shape: Shape) -> impl Strategy<Value = Version> {
+    assert!(
+        shape.pre_idents.start >= 1 && shape.build_idents.start >= 1,
+        "a Pre-Release or Build Metadata has at least one identifier"
+    );
+    assert!(
+        shape.digits.start < shape.digits.end && shape.digits.start <= MAX_DIGITS,
+        "`{:?}` digits can't make a `u64`",
+        shape.digits
+    );
+
+    let pre = any_with::<ArbPrerelease>(PrereleaseParams {
+        identifiers: shape.pre_idents.into(),
+        ..Default::default()
+    });
+    let build = any_with::<ArbBuildMetadata>(BuildMetadataParams {
+        identifiers: shape.build_idents.into(),
+        ..Default::default()
+    });
+
+    (
+        arb_component_of_digits(shape.digits.clone()),
+        arb_component_of_digits(shape.digits.clone()),
+        arb_component_of_digits(shape.digits),
+        prop::bool::weighted(shape.has_pre.into()),
+        pre,
+        prop::bool::weighted(shape.has_build.into()),
+        build,
+    )
+        .prop_map(
+            |(major, minor, patch, has_pre, pre, has_build, build)| Version {
+                major,
+                minor,
+                patch,
+                pre: if has_pre { pre.0 } else { Prerelease::EMPTY },
+                build: if has_build {
+                    build.0
+                } else {
+                    BuildMetadata::EMPTY
+                },
+            },
+        )
+}
+
+/// The number of decimal digits of `n`.
+fn digits_of(n: u64) -> u32 {
+    n.checked_ilog10().map_or(1, |log| log + 1)
+}
+
+prop_compose! {
+    /// Provides versions of the default [Shape], alongside the number of
+    /// decimal digits in each of `MAJOR`, `MINOR` and `PATCH`, and whether it
+    /// has a Pre-Release and Build Metadata, for coverage classifiers and
+    /// stratified assertions that shouldn't re-parse every value.
+    pub fn arb_version_decomposed()(
+        v in arb_version_shaped(Shape::default())
+    ) -> (Version, (u32, u32, u32), bool, bool) {
+        let digits = (digits_of(v.major), digits_of(v.minor), digits_of(v.patch));
+        let (has_pre, has_build) = (!v.pre.is_empty(), !v.build.is_empty());
+        (v, digits, has_pre, has_build)
+    }
+}
@@ -0,0 +1,339 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+//!
+//! [proptest::strategy::ValueTree] implementations for versions and
+//! requirements which shrink toward readable counterexamples, like `1.0.0`,
+//! rather than wherever the generator's internals happen to shrink to.
+use proptest::num::u64::BinarySearch;
+use proptest::prelude::*;
+use proptest::strategy::{NewTree, ValueTree};
+use proptest::test_runner::TestRunner;
+use semver::{BuildMetadata, Prerelease, Version, VersionReq};
+
+use crate::{
+    arb_full_comparator, arb_option_semver_build_metadata, arb_option_semver_prerelease,
+    FullComparator,
+};
+
+/// What a [VersionValueTree] is currently shrinking, in order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Stage {
+    Build,
+    Pre,
+    Major,
+    Minor,
+    Patch,
+    Done,
+}
+
+/// The [proptest::strategy::ValueTree] of [arb_version_shrinking].
+///
+/// Shrinks Build Metadata away first, then the Pre-Release (away, or else to
+/// fewer identifiers), then `MAJOR`, `MINOR` and `PATCH` toward zero.
+#[derive(Clone, Debug)]
+pub struct VersionValueTree {
+    major: BinarySearch,
+    minor: BinarySearch,
+    patch: BinarySearch,
+    pre: Prerelease,
+    build: BuildMetadata,
+
+    stage: Stage,
+
+    /// Simpler candidates for the Pre-Release or Build Metadata of the
+    /// current stage, simplest first, and the next one to try.
+    candidates: Vec<String>,
+    next: usize,
+
+    /// The value replaced by a simplification awaiting a verdict, if any.
+    replaced: Option<String>,
+}
+
+impl VersionValueTree {
+    /// Enters `stage`, with it's candidates.
+    fn enter(&mut self, stage: Stage) {
+        self.stage = stage;
+        self.next = 0;
+        self.candidates = match stage {
+            Stage::Build if !self.build.is_empty() => vec![String::new()],
+            Stage::Pre if !self.pre.is_empty() => {
+                let identifiers = self.pre.split('.').collect::<Vec<_>>();
+                (0..identifiers.len())
+                    .map(|len| identifiers[..len].join("."))
+                    .collect()
+            }
+            _ => Vec::new(),
+        };
+    }
+
+    /// Replaces the Pre-Release or Build Metadata of the current stage,
+    /// returning the old value.
+    fn replace(&mut self, value: &str) -> String {
+        match self.stage {
+            Stage::Build => {
+                std::mem::replace(&mut self.build, BuildMetadata::new(value).unwrap()).to_string()
+            }
+            Stage::Pre => {
+                std::mem::replace(&mut self.pre, Prerelease::new(value).unwrap()).to_string()
+            }
+            _ => unreachable!("only Build Metadata and Pre-Release are replaced"),
+        }
+    }
+
+    fn next_stage(&self) -> Stage {
+        match self.stage {
+            Stage::Build => Stage::Pre,
+            Stage::Pre => Stage::Major,
+            Stage::Major => Stage::Minor,
+            Stage::Minor => Stage::Patch,
+            Stage::Patch | Stage::Done => Stage::Done,
+        }
+    }
+}
+
+impl ValueTree for VersionValueTree {
+    type Value = Version;
+
+    fn current(&self) -> Version {
+        Version {
+            major: self.major.current(),
+            minor: self.minor.current(),
+            patch: self.patch.current(),
+            pre: self.pre.clone(),
+            build: self.build.clone(),
+        }
+    }
+
+    fn simplify(&mut self) -> bool {
+        // A replacement which wasn't complicated still fails, and is the
+        // simplest candidate that does, so this stage is done.
+        if self.replaced.take().is_some() {
+            self.enter(self.next_stage());
+        }
+
+        loop {
+            match self.stage {
+                Stage::Build | Stage::Pre => {
+                    if let Some(candidate) = self.candidates.get(self.next).cloned() {
+                        self.replaced = Some(self.replace(&candidate));
+                        return true;
+                    }
+                }
+                Stage::Major if self.major.simplify() => return true,
+                Stage::Minor if self.minor.simplify() => return true,
+                Stage::Patch if self.patch.simplify() => return true,
+                Stage::Done => return false,
+                _ => {}
+            }
+            self.enter(self.next_stage());
+        }
+    }
+
+    fn complicate(&mut self) -> bool {
+        match self.stage {
+            Stage::Build | Stage::Pre => match self.replaced.take() {
+                Some(replaced) => {
+                    self.replace(&replaced);
+                    self.next += 1;
+                    true
+                }
+                None => false,
+            },
+            Stage::Major => self.major.complicate(),
+            Stage::Minor => self.minor.complicate(),
+            Stage::Patch => self.patch.complicate(),
+            Stage::Done => false,
+        }
+    }
+}
+
+/// The [proptest::strategy::Strategy] of [arb_version_shrinking].
+#[derive(Clone, Debug)]
+pub struct ShrinkingVersion {
+    probability_of_pre_release: f64,
+    probability_of_build_metadata: f64,
+}
+
+impl Strategy for ShrinkingVersion {
+    type Tree = VersionValueTree;
+    type Value = Version;
+
+    fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+        let pre = arb_option_semver_prerelease(self.probability_of_pre_release)
+            .new_tree(runner)?
+            .current();
+        let build = arb_option_semver_build_metadata(self.probability_of_build_metadata)
+            .new_tree(runner)?
+            .current();
+
+        let mut tree = VersionValueTree {
+            major: any::<u64>().new_tree(runner)?,
+            minor: any::<u64>().new_tree(runner)?,
+            patch: any::<u64>().new_tree(runner)?,
+            pre: pre.unwrap_or(Prerelease::EMPTY),
+            build: build.unwrap_or(BuildMetadata::EMPTY),
+            stage: Stage::Build,
+            candidates: Vec::new(),
+            next: 0,
+            replaced: None,
+        };
+        tree.enter(Stage::Build);
+        Ok(tree)
+    }
+}
+
+/// Provides versions like [crate::arb_semver_version_weighted], which shrink
+/// toward readable counterexamples (see [VersionValueTree]), so a failure
+/// reports something like `1.0.0` instead of
+/// `184467.99213.4-x7qq+ab12`.
+///
+/// * `probability_of_pre_release` - Follows [proptest::option::Probability]
+///   rules.
+/// * `probability_of_build_metadata` - Follows
+///   [proptest::option::Probability] rules.
+pub fn arb_version_shrinking(
+    probability_of_pre_release: f64,
+    probability_of_build_metadata: f64,
+) -> ShrinkingVersion {
+    ShrinkingVersion {
+        probability_of_pre_release,
+        probability_of_build_metadata,
+    }
+}
+
+/// What a [VersionReqValueTree] is currently shrinking, in order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ReqStage {
+    /// Dropping the comparator at an index.
+    Drop(usize),
+
+    /// Simplifying the fields of the comparator at an index.
+    Fields(usize),
+
+    Done,
+}
+
+/// The last simplification of a [VersionReqValueTree].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ReqSimplification {
+    Dropped(usize),
+    Field(usize),
+}
+
+/// The [proptest::strategy::ValueTree] of [arb_version_req_shrinking].
+///
+/// Shrinks by dropping comparators one at a time (always keeping at least
+/// one), and only then simplifies the fields of each remaining comparator.
+pub struct VersionReqValueTree {
+    comparators: Vec<Box<dyn ValueTree<Value = FullComparator>>>,
+    included: Vec<bool>,
+    stage: ReqStage,
+    last: Option<ReqSimplification>,
+}
+
+impl ValueTree for VersionReqValueTree {
+    type Value = VersionReq;
+
+    fn current(&self) -> VersionReq {
+        let comparators = self
+            .comparators
+            .iter()
+            .zip(&self.included)
+            .filter(|(_, included)| **included)
+            .map(|(c, _)| c.current().to_string())
+            .collect::<Vec<_>>();
+        VersionReq::parse(&comparators.join(",")).unwrap()
+    }
+
+    fn simplify(&mut self) -> bool {
+        let len = self.comparators.len();
+        loop {
+            match self.stage {
+                ReqStage::Drop(i) if i >= len => self.stage = ReqStage::Fields(0),
+                ReqStage::Drop(i) => {
+                    self.stage = ReqStage::Drop(i + 1);
+                    let remaining = self.included.iter().filter(|i| **i).count();
+                    if self.included[i] && remaining > 1 {
+                        self.included[i] = false;
+                        self.last = Some(ReqSimplification::Dropped(i));
+                        return true;
+                    }
+                }
+                ReqStage::Fields(i) if i >= len => self.stage = ReqStage::Done,
+                ReqStage::Fields(i) => {
+                    if self.included[i] && self.comparators[i].simplify() {
+                        self.last = Some(ReqSimplification::Field(i));
+                        return true;
+                    }
+                    self.stage = ReqStage::Fields(i + 1);
+                }
+                ReqStage::Done => return false,
+            }
+        }
+    }
+
+    fn complicate(&mut self) -> bool {
+        match self.last {
+            Some(ReqSimplification::Dropped(i)) => {
+                self.included[i] = true;
+                self.last = None;
+                true
+            }
+            Some(ReqSimplification::Field(i)) => self.comparators[i].complicate(),
+            None => false,
+        }
+    }
+}
+
+/// The [proptest::strategy::Strategy] of [arb_version_req_shrinking].
+#[derive(Clone, Debug)]
+pub struct ShrinkingVersionReq {
+    max_comparators: usize,
+}
+
+impl Strategy for ShrinkingVersionReq {
+    type Tree = VersionReqValueTree;
+    type Value = VersionReq;
+
+    fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+        let len = (1..=self.max_comparators).new_tree(runner)?.current();
+        let comparator = arb_full_comparator(None, None, None).boxed();
+        let comparators = (0..len)
+            .map(|_| comparator.new_tree(runner))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(VersionReqValueTree {
+            included: vec![true; comparators.len()],
+            comparators,
+            stage: ReqStage::Drop(0),
+            last: None,
+        })
+    }
+}
+
+/// Provides requirements like [crate::arb_version_req] (without the lone
+/// `*`), which shrink by dropping comparators before simplifying them (see
+/// [VersionReqValueTree]), so a failing requirement shrinks to the few
+/// comparators that matter.
+///
+/// * `max_comparators` - At least `1`, and should always be less than or
+///   equal to [crate::MAX_COMPARATORS_IN_VERSION_REQ_STRING].
+pub fn arb_version_req_shrinking(max_comparators: usize) -> ShrinkingVersionReq {
+    assert!(
+        max_comparators >= 1,
+        "a requirement has at least one comparator"
+    );
+    ShrinkingVersionReq { max_comparators }
+}
@@ -0,0 +1,136 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+//!
+//! Deterministic coverage of a curated corpus first, then random generation,
+//! so a short CI run (a "smoke" run) is still guaranteed edge case coverage,
+//! while a long run keeps it's full randomness.
+use proptest::prelude::*;
+use proptest::strategy::{NewTree, ValueTree};
+use proptest::test_runner::TestRunner;
+use semver::Version;
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::{arb_version, EDGE_CASE_VERSIONS};
+
+/// A [proptest::strategy::Strategy] that hands out the values of a corpus, in
+/// order, before falling back to random generation. See [corpus_first].
+pub struct CorpusFirst<S: Strategy> {
+    corpus: Vec<S::Value>,
+    random: S,
+    next: AtomicUsize,
+}
+
+impl<S: Strategy> fmt::Debug for CorpusFirst<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CorpusFirst")
+            .field("corpus", &self.corpus)
+            .field("random", &self.random)
+            .field("next", &self.next)
+            .finish()
+    }
+}
+
+/// The [proptest::strategy::ValueTree] of [CorpusFirst].
+pub enum CorpusFirstTree<T: ValueTree> {
+    /// A value from the corpus, which never shrinks.
+    Corpus(T::Value),
+
+    Random(T),
+}
+
+impl<T: ValueTree> ValueTree for CorpusFirstTree<T>
+where
+    T::Value: Clone,
+{
+    type Value = T::Value;
+
+    fn current(&self) -> Self::Value {
+        match self {
+            CorpusFirstTree::Corpus(v) => v.clone(),
+            CorpusFirstTree::Random(t) => t.current(),
+        }
+    }
+
+    fn simplify(&mut self) -> bool {
+        match self {
+            CorpusFirstTree::Corpus(_) => false,
+            CorpusFirstTree::Random(t) => t.simplify(),
+        }
+    }
+
+    fn complicate(&mut self) -> bool {
+        match self {
+            CorpusFirstTree::Corpus(_) => false,
+            CorpusFirstTree::Random(t) => t.complicate(),
+        }
+    }
+}
+
+impl<S: Strategy> Strategy for CorpusFirst<S>
+where
+    S::Value: Clone,
+{
+    type Tree = CorpusFirstTree<S::Tree>;
+    type Value = S::Value;
+
+    fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+        let i = self.next.fetch_add(1, Ordering::Relaxed);
+        match self.corpus.get(i) {
+            Some(v) => Ok(CorpusFirstTree::Corpus(v.clone())),
+            None => self.random.new_tree(runner).map(CorpusFirstTree::Random),
+        }
+    }
+}
+
+/// Derives a strategy which covers `corpus` first, and fills the rest of a
+/// run with `random`.
+///
+/// When the corpus is larger than `budget`, an evenly spaced subsample of
+/// `budget` values is covered instead, which is the same on every run.
+///
+/// * `budget` - The number of cases in a run, which should match the
+///   `cases` of the [proptest::test_runner::Config].
+pub fn corpus_first<S: Strategy>(corpus: Vec<S::Value>, random: S, budget: usize) -> CorpusFirst<S>
+where
+    S::Value: Clone,
+{
+    let corpus = if corpus.len() > budget {
+        (0..budget)
+            .map(|i| corpus[i * corpus.len() / budget].clone())
+            .collect()
+    } else {
+        corpus
+    };
+
+    CorpusFirst {
+        corpus,
+        random,
+        next: AtomicUsize::new(0),
+    }
+}
+
+/// Provides versions, covering [EDGE_CASE_VERSIONS] first (within `budget`),
+/// and [arb_version] after.
+///
+/// * `budget` - The number of cases in a run, which should match the
+///   `cases` of the [proptest::test_runner::Config].
+pub fn arb_version_smoke(budget: usize) -> CorpusFirst<impl Strategy<Value = Version>> {
+    let corpus = EDGE_CASE_VERSIONS
+        .iter()
+        .map(|s| Version::parse(s).unwrap())
+        .collect();
+    corpus_first(corpus, arb_version(), budget)
+}
@@ -0,0 +1,243 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+//!
+//! Strings alongside the byte spans of their parts, as ground truth for tools
+//! which highlight, lint or report locations within them.
+use proptest::prelude::*;
+use semver::Version;
+use std::ops::Range;
+
+use crate::{arb_full_comparator, arb_version, FullComparator};
+
+/// The byte spans of the components of a version string.
+///
+/// Spans of Pre-Release and Build Metadata don't include their `-` or `+`
+/// prefix.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VersionSpans {
+    pub major: Range<usize>,
+    pub minor: Range<usize>,
+    pub patch: Range<usize>,
+    pub pre: Option<Range<usize>>,
+    pub build: Option<Range<usize>>,
+}
+
+/// Appends `part` to `s`, returning it's span.
+fn push_span(s: &mut String, part: &str) -> Range<usize> {
+    let start = s.len();
+    s.push_str(part);
+    start..s.len()
+}
+
+prop_compose! {
+    /// Provides a version string, alongside the byte spans of it's
+    /// components.
+    pub fn arb_annotated_version_string()(v in arb_version()) -> (String, VersionSpans) {
+        let mut s = String::new();
+        let major = push_span(&mut s, &v.major.to_string());
+        s.push('.');
+        let minor = push_span(&mut s, &v.minor.to_string());
+        s.push('.');
+        let patch = push_span(&mut s, &v.patch.to_string());
+        let pre = (!v.pre.is_empty()).then(|| {
+            s.push('-');
+            push_span(&mut s, v.pre.as_str())
+        });
+        let build = (!v.build.is_empty()).then(|| {
+            s.push('+');
+            push_span(&mut s, v.build.as_str())
+        });
+
+        (s, VersionSpans { major, minor, patch, pre, build })
+    }
+}
+
+/// The kind of a token of a requirement string.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReqTokenKind {
+    /// An operator, like `>=`.
+    Op,
+    Major,
+    Minor,
+    Patch,
+    /// A `*` in place of a Minor or Patch.
+    Wildcard,
+    /// A Pre-Release, without it's `-` prefix.
+    PreRelease,
+    /// A Build Metadata, without it's `+` prefix.
+    BuildMetadata,
+}
+
+/// A token of a requirement string, and it's byte span.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReqToken {
+    pub kind: ReqTokenKind,
+    pub span: Range<usize>,
+}
+
+/// The byte span of a single comparator of a requirement string, and the
+/// tokens within it, in order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ComparatorSpans {
+    pub span: Range<usize>,
+    pub tokens: Vec<ReqToken>,
+}
+
+/// Appends a comparator to `s`, returning it's spans.
+fn push_comparator(s: &mut String, comparator: &FullComparator, op_space: &str) -> ComparatorSpans {
+    let start = s.len();
+    let mut tokens = Vec::new();
+    let mut token = |s: &mut String, kind, part: &str| {
+        tokens.push(ReqToken {
+            kind,
+            span: push_span(s, part),
+        })
+    };
+
+    match comparator {
+        FullComparator::Plain(op, major, minor, patch, pre, build) => {
+            token(s, ReqTokenKind::Op, &op.to_string());
+            s.push_str(op_space);
+            token(s, ReqTokenKind::Major, &major.to_string());
+            s.push('.');
+            token(s, ReqTokenKind::Minor, &minor.to_string());
+            s.push('.');
+            token(s, ReqTokenKind::Patch, &patch.to_string());
+            if let Some(pre) = pre {
+                s.push('-');
+                token(s, ReqTokenKind::PreRelease, pre);
+            }
+            if let Some(build) = build {
+                s.push('+');
+                token(s, ReqTokenKind::BuildMetadata, build);
+            }
+        }
+        FullComparator::WildcardMinor(op, major) => {
+            token(s, ReqTokenKind::Op, &op.to_string());
+            s.push_str(op_space);
+            token(s, ReqTokenKind::Major, &major.to_string());
+            s.push('.');
+            token(s, ReqTokenKind::Wildcard, "*");
+            s.push('.');
+            token(s, ReqTokenKind::Wildcard, "*");
+        }
+        FullComparator::WildcardPatch(op, major, minor) => {
+            token(s, ReqTokenKind::Op, &op.to_string());
+            s.push_str(op_space);
+            token(s, ReqTokenKind::Major, &major.to_string());
+            s.push('.');
+            token(s, ReqTokenKind::Minor, &minor.to_string());
+            s.push('.');
+            token(s, ReqTokenKind::Wildcard, "*");
+        }
+        FullComparator::Wildcard => token(s, ReqTokenKind::Wildcard, "*"),
+    }
+
+    ComparatorSpans {
+        span: start..s.len(),
+        tokens,
+    }
+}
+
+prop_compose! {
+    /// Provides a requirement string, which [semver::VersionReq::parse]
+    /// accepts, alongside the spans of each of it's comparators, and of the
+    /// tokens within them.
+    ///
+    /// The whitespace around `,` separators, and between an operator and it's
+    /// version, varies, and is never part of any span.
+    ///
+    /// * `max_comparators` - The maximum number of comparators, which is at
+    ///   least `1`.
+    pub fn arb_annotated_req_string(max_comparators: usize)(
+        comparators in prop::collection::vec(
+            (
+                arb_full_comparator(None, None, None),
+                prop_oneof!["", " "],
+                prop_oneof![",", ", ", " , "],
+            ),
+            1..=max_comparators.max(1),
+        ),
+    ) -> (String, Vec<ComparatorSpans>) {
+        let mut s = String::new();
+        let mut spans = Vec::new();
+        for (i, (comparator, op_space, separator)) in comparators.iter().enumerate() {
+            if i > 0 {
+                s.push_str(separator);
+            }
+            spans.push(push_comparator(&mut s, comparator, op_space));
+        }
+        (s, spans)
+    }
+}
+
+/// A piece of the message of a log line from [arb_log_line_with_versions].
+#[derive(Clone, Debug)]
+enum LogSegment {
+    Word(String),
+    /// A version, with the text before and after it, such as `(` and `)`.
+    Version(&'static str, Version, &'static str),
+}
+
+fn arb_log_segment() -> impl Strategy<Value = LogSegment> {
+    prop_oneof![
+        3 => "[a-z]{1,8}".prop_map(LogSegment::Word),
+        1 => (
+            prop::sample::select(vec![
+                ("", ""),
+                ("v", ""),
+                ("(", ")"),
+                ("\"", "\""),
+                ("pkg@", ""),
+                ("version=", ""),
+                ("", ","),
+            ]),
+            arb_version(),
+        )
+            .prop_map(|((before, after), v)| LogSegment::Version(before, v, after)),
+    ]
+}
+
+prop_compose! {
+    /// Provides a log line (with a timestamp, level and component) containing
+    /// zero or more versions, alongside the byte span of each version, for
+    /// testing extractors that hunt for version mentions.
+    ///
+    /// Versions are sometimes decorated (`v1.2.3`, `(1.2.3)`, `pkg@1.2.3`,
+    /// `version=1.2.3`), and decorations aren't part of their spans. Other
+    /// words never contain digits, so only the versions look like versions.
+    pub fn arb_log_line_with_versions()(
+        time in (0..24u8, 0..60u8, 0..60u8),
+        level in prop::sample::select(vec!["TRACE", "DEBUG", "INFO", "WARN", "ERROR"]),
+        component in "[a-z]{3,8}",
+        segments in prop::collection::vec(arb_log_segment(), 0..12),
+    ) -> (String, Vec<(Range<usize>, Version)>) {
+        let (h, m, sec) = time;
+        let mut s = format!("2025-01-01T{h:02}:{m:02}:{sec:02}Z {level} {component}:");
+        let mut versions = Vec::new();
+        for segment in segments {
+            s.push(' ');
+            match segment {
+                LogSegment::Word(word) => s.push_str(&word),
+                LogSegment::Version(before, v, after) => {
+                    s.push_str(before);
+                    versions.push((push_span(&mut s, &v.to_string()), v));
+                    s.push_str(after);
+                }
+            }
+        }
+        (s, versions)
+    }
+}
@@ -0,0 +1,193 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+//!
+//! Ready made properties for user types, checked against inputs from the
+//! strategies of this crate.
+use proptest::prelude::*;
+use proptest::test_runner::TestCaseError;
+use semver::{Prerelease, Version};
+use std::cmp::Ordering;
+use std::fmt::Debug;
+use std::str::FromStr;
+
+use crate::{arb_semver, arb_separator_faults, arb_version};
+
+/// Some route from a string to `T`, which is `None` when the string is
+/// rejected.
+pub type StrParser<T> = dyn Fn(&str) -> Option<T>;
+
+/// Provides inputs for [check_str_parse_symmetry], mixing valid version
+/// strings, spec valid strings that may overflow a [u64] component, and
+/// version strings with a faulted separator, which are usually invalid.
+pub fn arb_parse_symmetry_input() -> impl Strategy<Value = String> {
+    prop_oneof![
+        arb_version().prop_map(|v| v.to_string()),
+        arb_semver(),
+        arb_version().prop_flat_map(|v| {
+            arb_separator_faults(&v.to_string(), None, None, None).prop_map(|(s, _)| s)
+        }),
+    ]
+}
+
+/// Checks that [FromStr] and [TryFrom<&str>] agree on `input` for `T`, both on
+/// whether it's accepted, and on the value it's parsed to.
+///
+/// For use inside [proptest!], with [arb_parse_symmetry_input].
+pub fn check_str_parse_symmetry<T>(input: &str) -> Result<(), TestCaseError>
+where
+    T: FromStr + for<'a> TryFrom<&'a str> + PartialEq + Debug,
+{
+    check_str_parse_symmetry_with::<T>(input, &[])
+}
+
+/// Like [check_str_parse_symmetry], but also checks any number of other named
+/// routes from a string to `T`, such as a `Deserialize` implementation (with
+/// something like `|s| serde_json::from_value(s.into()).ok()`).
+pub fn check_str_parse_symmetry_with<T>(
+    input: &str,
+    others: &[(&str, &StrParser<T>)],
+) -> Result<(), TestCaseError>
+where
+    T: FromStr + for<'a> TryFrom<&'a str> + PartialEq + Debug,
+{
+    let from_str = T::from_str(input).ok();
+    let try_from = T::try_from(input).ok();
+    prop_assert_eq!(
+        &from_str,
+        &try_from,
+        "FromStr and TryFrom<&str> disagree on {:?}",
+        input
+    );
+
+    for (name, other) in others {
+        prop_assert_eq!(
+            &from_str,
+            &other(input),
+            "FromStr and {} disagree on {:?}",
+            name,
+            input
+        );
+    }
+
+    Ok(())
+}
+
+/// Like [check_str_parse_symmetry], but also checks `Deserialize` from a
+/// string agrees with [FromStr], which requires the `serde` feature.
+#[cfg(feature = "serde")]
+pub fn check_str_parse_symmetry_serde<T>(input: &str) -> Result<(), TestCaseError>
+where
+    T: FromStr + for<'a> TryFrom<&'a str> + serde::de::DeserializeOwned + PartialEq + Debug,
+{
+    use serde::de::value::{Error, StrDeserializer};
+
+    let deserialize = |s: &str| T::deserialize(StrDeserializer::<Error>::new(s)).ok();
+    check_str_parse_symmetry_with::<T>(input, &[("Deserialize", &deserialize)])
+}
+
+/// A version from a small space, so that a few of them frequently share
+/// components, which is where an order is most likely to be inconsistent.
+fn arb_order_version() -> impl Strategy<Value = Version> {
+    (
+        0u64..3,
+        0u64..3,
+        0u64..3,
+        prop_oneof![
+            2 => Just(""),
+            1 => prop::sample::select(vec!["0", "1", "alpha", "alpha.1", "beta", "1.alpha"]),
+        ],
+    )
+        .prop_map(|(major, minor, patch, pre)| {
+            let mut v = Version::new(major, minor, patch);
+            v.pre = Prerelease::new(pre).unwrap();
+            v
+        })
+}
+
+/// Provides three versions, for [check_total_order], from a small space so
+/// they frequently share components, and shrink toward `0.0.0`.
+pub fn arb_version_triple() -> impl Strategy<Value = (Version, Version, Version)> {
+    (
+        arb_order_version(),
+        arb_order_version(),
+        arb_order_version(),
+    )
+}
+
+/// Checks that `cmp` behaves as a total order over `a`, `b`, and `c`, and
+/// agrees with semver precedence on release versions.
+///
+/// * Reflexivity: each version is equal to itself.
+/// * Antisymmetry: comparing in either order gives opposite results.
+/// * Transitivity: `a <= b` and `b <= c` gives `a <= c`, for every
+///   arrangement of the three.
+/// * Agreement: for release versions, `cmp` agrees with
+///   [semver::Version::cmp_precedence].
+///
+/// For use inside [proptest!], with [arb_version_triple], which shrinks any
+/// disagreement to a minimal triple.
+pub fn check_total_order<F>(
+    cmp: F,
+    a: &Version,
+    b: &Version,
+    c: &Version,
+) -> Result<(), TestCaseError>
+where
+    F: Fn(&Version, &Version) -> Ordering,
+{
+    let all = [a, b, c];
+
+    for x in all {
+        prop_assert_eq!(cmp(x, x), Ordering::Equal, "{} is not equal to itself", x);
+    }
+
+    for x in all {
+        for y in all {
+            prop_assert_eq!(
+                cmp(x, y),
+                cmp(y, x).reverse(),
+                "comparing {} and {} is not antisymmetric",
+                x,
+                y
+            );
+
+            if x.pre.is_empty() && y.pre.is_empty() {
+                prop_assert_eq!(
+                    cmp(x, y),
+                    x.cmp_precedence(y),
+                    "{} and {} disagree with precedence",
+                    x,
+                    y
+                );
+            }
+
+            for z in all {
+                if cmp(x, y).is_le() && cmp(y, z).is_le() {
+                    prop_assert!(
+                        cmp(x, z).is_le(),
+                        "{} <= {} <= {}, but not {} <= {}",
+                        x,
+                        y,
+                        z,
+                        x,
+                        z
+                    );
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,227 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+//!
+//! Checked constructors for the strategies of this crate that take weights,
+//! which return a [WeightError] up front rather than panicking deep inside
+//! `proptest` when a combination of weights can't be sampled from.
+//!
+//! Each is named after the strategy it checks, with a `try_` prefix, and
+//! takes the same arguments (where `None` is still the documented default).
+use proptest::prelude::*;
+use semver::Op;
+use std::fmt;
+
+use crate::{
+    arb_comparator_op, arb_full_comparator_vec, arb_full_comparator_with_ops, arb_identifier,
+    arb_semver_op_weighted, arb_separator_faults, ComparatorOp, ComparatorVec, FullComparator,
+    OpWeights, SeparatorFault,
+};
+
+/// Why a set of weights can't be sampled from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WeightError {
+    /// Every weight of the named strategy is zero, so there is nothing to
+    /// pick.
+    AllZero(&'static str),
+
+    /// The weights of the named strategy add up to more than [u32::MAX].
+    Overflow(&'static str),
+}
+
+impl fmt::Display for WeightError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WeightError::AllZero(strategy) => write!(f, "every weight of `{strategy}` is zero"),
+            WeightError::Overflow(strategy) => {
+                write!(
+                    f,
+                    "the weights of `{strategy}` add up to more than u32::MAX"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for WeightError {}
+
+/// Checks that `weights` can be sampled from.
+fn check(strategy: &'static str, weights: &[u32]) -> Result<(), WeightError> {
+    let total = weights.iter().map(|w| *w as u64).sum::<u64>();
+    if total == 0 {
+        Err(WeightError::AllZero(strategy))
+    } else if total > u32::MAX as u64 {
+        Err(WeightError::Overflow(strategy))
+    } else {
+        Ok(())
+    }
+}
+
+impl OpWeights {
+    /// Checks these weights can be sampled from by [arb_semver_op_weighted].
+    pub fn check_semver_op(&self) -> Result<(), WeightError> {
+        check(
+            "arb_semver_op_weighted",
+            &[
+                self.exact,
+                self.greater,
+                self.greater_eq,
+                self.less,
+                self.less_eq,
+                self.tilde,
+                self.caret,
+                self.wildcard,
+            ],
+        )
+    }
+
+    /// Checks these weights can be sampled from by [arb_comparator_op],
+    /// which ignores [OpWeights::wildcard].
+    pub fn check_comparator_op(&self) -> Result<(), WeightError> {
+        check(
+            "arb_comparator_op",
+            &[
+                self.exact,
+                self.greater,
+                self.greater_eq,
+                self.less,
+                self.less_eq,
+                self.tilde,
+                self.caret,
+            ],
+        )
+    }
+}
+
+/// A checked [crate::arb_semver_op].
+pub fn try_arb_semver_op(
+    default_weight: Option<u32>,
+    wildcard_weight: Option<u32>,
+) -> Result<BoxedStrategy<Op>, WeightError> {
+    try_arb_semver_op_weighted(&OpWeights {
+        wildcard: wildcard_weight.unwrap_or(1),
+        ..OpWeights::uniform(default_weight.unwrap_or(5))
+    })
+}
+
+/// A checked [arb_semver_op_weighted].
+pub fn try_arb_semver_op_weighted(weights: &OpWeights) -> Result<BoxedStrategy<Op>, WeightError> {
+    weights.check_semver_op()?;
+    Ok(arb_semver_op_weighted(weights))
+}
+
+/// A checked [arb_comparator_op].
+pub fn try_arb_comparator_op(
+    weights: &OpWeights,
+) -> Result<BoxedStrategy<ComparatorOp>, WeightError> {
+    weights.check_comparator_op()?;
+    Ok(arb_comparator_op(weights))
+}
+
+/// A checked [crate::arb_full_comparator].
+pub fn try_arb_full_comparator(
+    weight_of_plain: Option<u32>,
+    weight_of_wildcard_minor: Option<u32>,
+    weight_of_wildcard_patch: Option<u32>,
+) -> Result<impl Strategy<Value = FullComparator>, WeightError> {
+    try_arb_full_comparator_with_ops(
+        &OpWeights::default(),
+        weight_of_plain,
+        weight_of_wildcard_minor,
+        weight_of_wildcard_patch,
+    )
+}
+
+/// A checked [arb_full_comparator_with_ops], which also checks `ops` (see
+/// [OpWeights::check_comparator_op]).
+pub fn try_arb_full_comparator_with_ops(
+    ops: &OpWeights,
+    weight_of_plain: Option<u32>,
+    weight_of_wildcard_minor: Option<u32>,
+    weight_of_wildcard_patch: Option<u32>,
+) -> Result<impl Strategy<Value = FullComparator>, WeightError> {
+    ops.check_comparator_op()?;
+    check(
+        "arb_full_comparator_with_ops",
+        &[
+            weight_of_plain.unwrap_or(7),
+            weight_of_wildcard_minor.unwrap_or(1),
+            weight_of_wildcard_patch.unwrap_or(1),
+        ],
+    )?;
+    Ok(arb_full_comparator_with_ops(
+        ops,
+        weight_of_plain,
+        weight_of_wildcard_minor,
+        weight_of_wildcard_patch,
+    ))
+}
+
+/// A checked [arb_full_comparator_vec].
+pub fn try_arb_full_comparator_vec(
+    max_comparators: usize,
+    weight_of_wildcard: Option<u32>,
+    weight_of_comparator_list: Option<u32>,
+) -> Result<impl Strategy<Value = ComparatorVec>, WeightError> {
+    check(
+        "arb_full_comparator_vec",
+        &[
+            weight_of_wildcard.unwrap_or(1),
+            weight_of_comparator_list.unwrap_or(14),
+        ],
+    )?;
+    Ok(arb_full_comparator_vec(
+        max_comparators,
+        weight_of_wildcard,
+        weight_of_comparator_list,
+    ))
+}
+
+/// A checked [arb_identifier].
+pub fn try_arb_identifier(
+    weight_of_numeric: Option<u32>,
+    weight_of_alphanumeric: Option<u32>,
+) -> Result<impl Strategy<Value = String>, WeightError> {
+    check(
+        "arb_identifier",
+        &[
+            weight_of_numeric.unwrap_or(1),
+            weight_of_alphanumeric.unwrap_or(1),
+        ],
+    )?;
+    Ok(arb_identifier(weight_of_numeric, weight_of_alphanumeric))
+}
+
+/// A checked [arb_separator_faults].
+pub fn try_arb_separator_faults(
+    version: &str,
+    weight_of_swap: Option<u32>,
+    weight_of_duplicate: Option<u32>,
+    weight_of_remove: Option<u32>,
+) -> Result<impl Strategy<Value = (String, SeparatorFault)>, WeightError> {
+    check(
+        "arb_separator_faults",
+        &[
+            weight_of_swap.unwrap_or(1),
+            weight_of_duplicate.unwrap_or(1),
+            weight_of_remove.unwrap_or(1),
+        ],
+    )?;
+    Ok(arb_separator_faults(
+        version,
+        weight_of_swap,
+        weight_of_duplicate,
+        weight_of_remove,
+    ))
+}
@@ -0,0 +1,138 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+use proptest::prelude::*;
+use proptest_derive::Arbitrary;
+use proptest_semver::*;
+use semver::{Version, VersionReq};
+
+#[derive(Arbitrary, Debug)]
+struct Release {
+    pre: ArbPrerelease,
+    build: ArbBuildMetadata,
+}
+
+#[derive(Arbitrary, Debug)]
+struct Dependency {
+    version: ArbVersion,
+    req: ArbVersionReq,
+    comparator: ArbComparator,
+}
+
+proptest! {
+    #[test]
+    fn test_arb_prerelease(pr in any::<ArbPrerelease>()) {
+        let pr: semver::Prerelease = pr.into();
+        prop_assert_eq!(semver::Prerelease::new(pr.as_str()).unwrap(), pr);
+    }
+
+    #[test]
+    fn test_arb_prerelease_params(pr in any_with::<ArbPrerelease>(PrereleaseParams {
+        identifiers: (2..=3).into(),
+        identifier_len: (4..=5).into(),
+        ..Default::default()
+    })) {
+        let identifiers = pr.0.split('.').collect::<Vec<_>>();
+        prop_assert!((2..=3).contains(&identifiers.len()));
+        for i in identifiers {
+            prop_assert!((4..=5).contains(&i.len()), "{}", i);
+        }
+    }
+
+    #[test]
+    fn test_arb_build_metadata(bm in any::<ArbBuildMetadata>()) {
+        let bm: semver::BuildMetadata = bm.into();
+        prop_assert_eq!(semver::BuildMetadata::new(bm.as_str()).unwrap(), bm);
+    }
+
+    #[test]
+    fn test_arb_build_metadata_params(bm in any_with::<ArbBuildMetadata>(BuildMetadataParams {
+        identifiers: 1.into(),
+        identifier_len: 40.into(),
+    })) {
+        prop_assert_eq!(bm.0.len(), 40);
+    }
+
+    #[test]
+    fn test_arb_full_comparator_any(c in any::<FullComparator>()) {
+        VersionReq::parse(&c.to_string()).unwrap();
+    }
+
+    #[test]
+    fn test_arb_full_comparator_params(c in any_with::<FullComparator>(FullComparatorParams {
+        weight_of_plain: Some(0),
+        weight_of_wildcard_minor: Some(1),
+        weight_of_wildcard_patch: Some(0),
+        ..Default::default()
+    })) {
+        prop_assert!(matches!(c, FullComparator::WildcardMinor(..)));
+    }
+
+    #[test]
+    fn test_arb_full_comparator_params_ops(c in any_with::<FullComparator>(FullComparatorParams {
+        ops: OpWeights { caret: 1, ..OpWeights::uniform(0) },
+        ..Default::default()
+    })) {
+        prop_assert!(c.to_string().starts_with('^'));
+    }
+
+    #[test]
+    fn test_arb_comparator_vec_any(c in any::<ComparatorVec>()) {
+        VersionReq::parse(&c.to_string()).unwrap();
+    }
+
+    #[test]
+    fn test_arb_comparator_vec_params(c in any_with::<ComparatorVec>(ComparatorVecParams {
+        max_comparators: MAX_COMPARATORS_IN_VERSION_REQ_STRING,
+        weight_of_wildcard: Some(0),
+        ..Default::default()
+    })) {
+        let r = VersionReq::parse(&c.to_string()).unwrap();
+        prop_assert_eq!(r.comparators.len(), MAX_COMPARATORS_IN_VERSION_REQ_STRING);
+    }
+
+    #[test]
+    fn test_derived_release(r in any::<Release>()) {
+        let _ = semver::Version {
+            major: 1,
+            minor: 0,
+            patch: 0,
+            pre: r.pre.into(),
+            build: r.build.into(),
+        };
+    }
+
+    #[test]
+    fn test_arb_version(v in any::<ArbVersion>()) {
+        let v: Version = v.into();
+        prop_assert_eq!(Version::parse(&v.to_string()).unwrap(), v);
+    }
+
+    #[test]
+    fn test_arb_version_params(v in any_with::<ArbVersion>(VersionParams {
+        pre: PrereleaseParams { identifiers: 1.into(), ..Default::default() },
+        build: BuildMetadataParams { identifiers: 0.into(), ..Default::default() },
+    })) {
+        prop_assert!(!v.0.pre.is_empty());
+        prop_assert!(v.0.build.is_empty());
+    }
+
+    #[test]
+    fn test_derived_dependency(d in any::<Dependency>()) {
+        let req: VersionReq = d.req.into();
+        prop_assert_eq!(VersionReq::parse(&req.to_string()).unwrap(), req);
+        let _ = d.version.0.cmp_precedence(&Version::new(1, 0, 0));
+        let _ = d.comparator.0.matches(&Version::new(1, 0, 0));
+    }
+}
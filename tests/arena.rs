@@ -0,0 +1,49 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+use proptest::prelude::*;
+use proptest_semver::*;
+use semver::Version;
+
+/// Stands in for a zero-copy parser, which borrows from it's input.
+fn major_of(s: &str) -> &str {
+    s.split('.').next().unwrap()
+}
+
+proptest! {
+    #[test]
+    fn test_arb_version_arena(arena in arb_version_arena(16)) {
+        prop_assert!((1..=16).contains(&arena.len()));
+        prop_assert_eq!(arena.iter().count(), arena.len());
+
+        let majors = arena.iter().map(major_of).collect::<Vec<_>>();
+        for (i, s) in arena.iter().enumerate() {
+            prop_assert_eq!(arena.get(i), Some(s));
+            let v = Version::parse(s).unwrap();
+            prop_assert_eq!(v.major.to_string(), majors[i]);
+        }
+        prop_assert_eq!(arena.get(arena.len()), None);
+    }
+}
+
+#[test]
+fn test_version_arena_from_iter() {
+    let arena = ["1.0.0", "", "2.0.0-rc.1"]
+        .into_iter()
+        .collect::<VersionArena>();
+    assert_eq!(
+        arena.iter().collect::<Vec<_>>(),
+        vec!["1.0.0", "", "2.0.0-rc.1"]
+    );
+}
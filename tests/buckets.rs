@@ -0,0 +1,126 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+use proptest::prelude::*;
+use proptest_semver::*;
+use semver::{Comparator, Op, Version, VersionReq};
+use std::collections::HashSet;
+
+proptest! {
+    #[test]
+    fn test_arb_versions_in_buckets(versions in arb_versions_in_buckets(&[
+        (VersionBucket::Major(0), 3),
+        (VersionBucket::Minor(1, 0), 5),
+        (VersionBucket::Minor(1, 1), 0),
+        (VersionBucket::Major(u64::MAX), 1),
+    ])) {
+        prop_assert_eq!(versions.len(), 9);
+        prop_assert_eq!(versions.iter().collect::<HashSet<_>>().len(), 9);
+
+        let count = |b: VersionBucket| versions.iter().filter(|v| b.contains(v)).count();
+        prop_assert_eq!(count(VersionBucket::Major(0)), 3);
+        prop_assert_eq!(count(VersionBucket::Minor(1, 0)), 5);
+        prop_assert_eq!(count(VersionBucket::Minor(1, 1)), 0);
+        prop_assert_eq!(count(VersionBucket::Major(u64::MAX)), 1);
+    }
+}
+
+proptest! {
+    #[test]
+    fn test_arb_sharded_versions(sharded in arb_sharded_versions(4, 32)) {
+        prop_assert_eq!(sharded.shards.len(), 4);
+        for shard in &sharded.shards {
+            prop_assert!(shard.windows(2).all(|w| w[0] <= w[1]));
+        }
+
+        // A naive k-way merge arrives at the same global answer.
+        let mut heads = vec![0; sharded.shards.len()];
+        let mut merged = Vec::new();
+        while let Some(i) = (0..sharded.shards.len())
+            .filter(|&i| heads[i] < sharded.shards[i].len())
+            .min_by_key(|&i| &sharded.shards[i][heads[i]])
+        {
+            merged.push(sharded.shards[i][heads[i]].clone());
+            heads[i] += 1;
+        }
+        prop_assert_eq!(merged, sharded.merged);
+    }
+}
+
+proptest! {
+    #[test]
+    fn test_arb_version_bucketing_case(case in arb_version_bucketing_case()) {
+        prop_assert_eq!(case.counts.values().sum::<usize>(), case.versions.len());
+        for (bucket, count) in &case.counts {
+            prop_assert!(*count > 0);
+            prop_assert_eq!(case.versions.iter().filter(|v| bucket.contains(v)).count(), *count);
+        }
+        for v in &case.versions {
+            let bucket = VersionBucket::of(v, case.level);
+            prop_assert!(case.counts.contains_key(&bucket));
+        }
+    }
+
+    #[test]
+    fn test_arb_compatibility_classes(c in arb_compatibility_classes(5, 4)) {
+        prop_assert!((1..=5).contains(&c.classes.len()));
+        let mut grouped = std::collections::BTreeMap::new();
+        for v in &c.versions {
+            prop_assert!(v.build.is_empty());
+            grouped.entry(CompatibilityClass::of(v)).or_insert_with(Vec::new).push(v.clone());
+        }
+        for versions in grouped.values_mut() {
+            prop_assert!((1..=4).contains(&versions.len()));
+            versions.sort();
+        }
+        prop_assert_eq!(grouped, c.classes);
+    }
+}
+
+fn caret(v: &Version) -> VersionReq {
+    VersionReq {
+        comparators: vec![Comparator {
+            op: Op::Caret,
+            major: v.major,
+            minor: Some(v.minor),
+            patch: Some(v.patch),
+            pre: v.pre.clone(),
+        }],
+    }
+}
+
+proptest! {
+    #[test]
+    fn test_arb_semver_compatible_pair((base, other) in arb_semver_compatible_pair()) {
+        prop_assert!(caret(&base).matches(&other), "^{} should match {}", base, other);
+        prop_assert_eq!(CompatibilityClass::of(&base), CompatibilityClass::of(&other));
+        prop_assert!(base.build.is_empty() && other.build.is_empty());
+    }
+
+    #[test]
+    fn test_arb_semver_breaking_pair((base, other) in arb_semver_breaking_pair()) {
+        prop_assert!(!caret(&base).matches(&other), "^{} should not match {}", base, other);
+        prop_assert!(CompatibilityClass::of(&base) != CompatibilityClass::of(&other));
+        prop_assert!(base < other);
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_arb_versions_in_buckets_overlapping() {
+    let _ = arb_versions_in_buckets(&[
+        (VersionBucket::Major(1), 1),
+        (VersionBucket::Minor(1, 2), 1),
+    ]);
+}
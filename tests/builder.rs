@@ -0,0 +1,114 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+use proptest::prelude::*;
+use proptest_semver::*;
+
+proptest! {
+    #[test]
+    fn test_version_strategy(v in VersionStrategy::new()
+        .major(0..10u64)
+        .minor(3..=3u64)
+        .prerelease_probability(1.0)
+        .build_metadata(false)
+        .build()
+    ) {
+        prop_assert!(v.major < 10);
+        prop_assert_eq!(v.minor, 3);
+        prop_assert!(!v.pre.is_empty());
+        prop_assert!(v.build.is_empty());
+    }
+
+    #[test]
+    fn test_version_strategy_release(v in VersionStrategy::new().prerelease_probability(0.0).build()) {
+        prop_assert!(v.pre.is_empty());
+    }
+
+    #[test]
+    fn test_version_strategy_bounded_pre_release(v in VersionStrategy::new()
+        .prerelease_probability(1.0)
+        .prerelease_identifiers(1..3)
+        .prerelease_identifier_len(1..5)
+        .build()
+    ) {
+        let identifiers = v.pre.split('.').collect::<Vec<_>>();
+        prop_assert!((1..3).contains(&identifiers.len()));
+        for i in identifiers {
+            prop_assert!((1..5).contains(&i.len()), "{}", i);
+        }
+    }
+
+    #[test]
+    fn test_version_strategy_bounded_build_metadata(v in VersionStrategy::new()
+        .build_metadata_probability(1.0)
+        .build_metadata_identifiers(2)
+        .build_metadata_charset(IdentifierCharset::Digits)
+        .build()
+    ) {
+        let identifiers = v.build.split('.').collect::<Vec<_>>();
+        prop_assert_eq!(identifiers.len(), 2);
+        for i in identifiers {
+            prop_assert!((1..9).contains(&i.len()), "{}", i);
+            prop_assert!(i.bytes().all(|b| b.is_ascii_digit()));
+        }
+    }
+
+    #[test]
+    fn test_version_strategy_params(v in VersionStrategy::new()
+        .prerelease_probability(1.0)
+        .prerelease_params(PrereleaseParams {
+            identifiers: (2..3).into(),
+            identifier_len: (1..4).into(),
+            probability_of_numeric: 0.0,
+        })
+        .build_metadata_probability(1.0)
+        .build_metadata_charset(IdentifierCharset::Hex)
+        .build_metadata_params(BuildMetadataParams {
+            identifiers: (1..2).into(),
+            identifier_len: (40..41).into(),
+        })
+        .build()) {
+        prop_assert_eq!(v.pre.split('.').count(), 2);
+        prop_assert_eq!(v.build.len(), 40);
+        prop_assert!(v.build.bytes().all(|b| b.is_ascii_hexdigit()));
+    }
+
+    #[test]
+    fn test_arb_version_with_components(v in arb_version_with_components(0..=3, 10.., ..5)) {
+        prop_assert!(v.major <= 3);
+        prop_assert!(v.minor >= 10);
+        prop_assert!(v.patch < 5);
+    }
+
+    #[test]
+    fn test_arb_version_with_components_edges(v in arb_version_with_components(u64::MAX.., ..1, ..)) {
+        prop_assert_eq!(v.major, u64::MAX);
+        prop_assert_eq!(v.minor, 0);
+    }
+
+    #[test]
+    fn test_arb_realistic_version(v in arb_realistic_version()) {
+        prop_assert_eq!(semver::Version::parse(&v.to_string()).unwrap(), v.clone());
+        for n in [v.major, v.minor, v.patch] {
+            prop_assert!(n < 200);
+        }
+        prop_assert!(v.pre.split('.').count() <= 2);
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_arb_version_with_components_empty() {
+    let _ = arb_version_with_components(3..3, .., ..);
+}
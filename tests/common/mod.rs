@@ -0,0 +1,67 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+//!
+//! Helpers shared between test suites.
+#![allow(dead_code)]
+
+use proptest::prelude::*;
+use semver::{Prerelease, Version, VersionReq};
+
+/// Versions on and around every number mentioned by the requirement, which is
+/// where a decomposition is most likely to be wrong.
+pub fn candidates(req: &VersionReq) -> Vec<Version> {
+    let near = |n: u64| [n.checked_sub(1), Some(n), n.checked_add(1), Some(0)];
+    let mut out = Vec::new();
+
+    for c in &req.comparators {
+        let pres = [
+            Prerelease::EMPTY,
+            Prerelease::new("0").unwrap(),
+            c.pre.clone(),
+        ];
+        for major in near(c.major).into_iter().flatten() {
+            for minor in near(c.minor.unwrap_or(0)).into_iter().flatten() {
+                for patch in near(c.patch.unwrap_or(0)).into_iter().flatten() {
+                    for pre in &pres {
+                        let mut v = Version::new(major, minor, patch);
+                        v.pre = pre.clone();
+                        out.push(v);
+                    }
+                }
+            }
+        }
+    }
+
+    out
+}
+
+/// Small requirements, which unlike [proptest_semver::arb_version_req] frequently match the
+/// candidates they are checked against.
+pub fn small_req() -> impl Strategy<Value = VersionReq> {
+    let comparator = (
+        prop::sample::select(vec!["=", ">", ">=", "<", "<=", "~", "^", ""]),
+        0u64..3,
+        prop::option::of(0u64..3),
+        prop::option::of(0u64..3),
+        prop::sample::select(vec!["", "-0", "-alpha", "-alpha.1", "-beta"]),
+    )
+        .prop_map(|(op, major, minor, patch, pre)| match (minor, patch) {
+            (Some(minor), Some(patch)) => format!("{op}{major}.{minor}.{patch}{pre}"),
+            (Some(minor), None) => format!("{op}{major}.{minor}"),
+            (None, _) => format!("{op}{major}"),
+        });
+
+    prop::collection::vec(comparator, 1..4).prop_map(|c| VersionReq::parse(&c.join(", ")).unwrap())
+}
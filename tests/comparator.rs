@@ -0,0 +1,33 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+use proptest::prelude::*;
+use proptest_semver::*;
+use semver::Comparator;
+
+proptest! {
+    #[test]
+    fn test_arb_semver_comparator_consistent(c in arb_semver_comparator_consistent()) {
+        prop_assert!(is_parse_reachable(&c));
+        prop_assert_eq!(Comparator::parse(&c.to_string()).unwrap(), c);
+    }
+
+    #[test]
+    fn test_is_parse_reachable(c in arb_semver_comparator()) {
+        // `Display` always writes a string that parses, but only reachable
+        // comparators survive the round trip unchanged.
+        let parsed = Comparator::parse(&c.to_string()).unwrap();
+        prop_assert_eq!(is_parse_reachable(&c), parsed == c);
+    }
+}
@@ -0,0 +1,43 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+use proptest::prelude::*;
+use proptest_semver::*;
+use semver::{Version, VersionReq};
+
+/// Constants work anywhere a strategy is needed, including a `static`.
+static VERSIONS: ConstStrategy<Version> = VERSION;
+
+proptest! {
+    #[test]
+    fn test_version(v in VERSIONS) {
+        prop_assert_eq!(Version::parse(&v.to_string()).unwrap(), v);
+    }
+
+    #[test]
+    fn test_version_req(req in VERSION_REQ) {
+        prop_assert_eq!(VersionReq::parse(&req.to_string()).unwrap(), req);
+    }
+
+    #[test]
+    fn test_comparator(c in COMPARATOR) {
+        prop_assert!(is_parse_reachable(&c));
+    }
+
+    #[test]
+    fn test_identifiers((pre, build) in (PRERELEASE, BUILD_METADATA)) {
+        prop_assert!(!pre.is_empty());
+        prop_assert!(!build.is_empty());
+    }
+}
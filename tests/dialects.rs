@@ -0,0 +1,54 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+use proptest::prelude::*;
+use proptest_semver::*;
+use semver::{Version, VersionReq};
+
+proptest! {
+    #[test]
+    fn test_arb_dialect_tagged_range((range, dialects) in arb_dialect_tagged_range()) {
+        prop_assert!(!dialects.is_empty());
+        prop_assert_eq!(VersionReq::parse(&range).is_ok(), dialects.contains(&Dialect::Cargo), "{}", range);
+    }
+
+    #[test]
+    fn test_arb_bazel_module_dep(dep in arb_bazel_module_dep()) {
+        prop_assert_eq!(Version::parse(&dep.version).ok(), dep.semver.clone(), "{}", dep.version);
+        let name = format!("name = \"{}\"", dep.name);
+        prop_assert!(dep.snippet.contains(&name));
+        if dep.version.is_empty() {
+            prop_assert!(dep.release.is_empty());
+        } else {
+            let version = format!("version = \"{}\"", dep.version);
+            prop_assert!(dep.snippet.contains(&version));
+            prop_assert!(dep.version.starts_with(&dep.release.join(".")));
+        }
+    }
+
+    #[test]
+    fn test_arb_req_only_string(s in arb_req_only_string()) {
+        prop_assert!(VersionReq::parse(&s).is_ok(), "{:?}", s);
+        prop_assert!(Version::parse(&s).is_err(), "{:?}", s);
+    }
+
+    #[test]
+    fn test_arb_build_discarding_string(s in arb_build_discarding_string()) {
+        let v = Version::parse(&s).unwrap();
+        let req = VersionReq::parse(&s).unwrap();
+        prop_assert!(!v.build.is_empty());
+        prop_assert!(!req.to_string().contains('+'));
+        prop_assert!(req.matches(&v));
+    }
+}
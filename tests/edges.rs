@@ -0,0 +1,67 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+use proptest::prelude::*;
+use proptest_semver::*;
+use semver::Version;
+
+proptest! {
+    #[test]
+    fn test_arb_display_edge_versions(v in arb_display_edge_versions()) {
+        let s = v.to_string();
+        prop_assert_eq!(Version::parse(&s).unwrap(), v.clone());
+
+        let mut expected = format!("{}.{}.{}", v.major, v.minor, v.patch);
+        if !v.pre.is_empty() {
+            expected.push_str(&format!("-{}", v.pre));
+        }
+        if !v.build.is_empty() {
+            expected.push_str(&format!("+{}", v.build));
+        }
+        prop_assert_eq!(s, expected);
+    }
+
+    #[test]
+    fn test_arb_overflowing_semver_string(s in arb_overflowing_semver_string()) {
+        let without_build = s.split('+').next().unwrap();
+        let (core, pre) = without_build.split_once('-').unwrap_or((without_build, ""));
+        let core = core.split('.').collect::<Vec<_>>();
+        prop_assert_eq!(core.len(), 3);
+
+        let numeric = core
+            .iter()
+            .copied()
+            .chain(pre.split('.').filter(|p| !p.is_empty() && p.bytes().all(|b| b.is_ascii_digit())))
+            .collect::<Vec<_>>();
+        for n in &numeric {
+            prop_assert!(*n == "0" || !n.starts_with('0'), "{}", s);
+        }
+        prop_assert!(numeric.iter().any(|n| n.parse::<u64>().is_err()), "{}", s);
+
+        prop_assert!(core.iter().any(|c| c.parse::<u64>().is_err()), "{}", s);
+        prop_assert!(Version::parse(&s).is_err(), "{}", s);
+    }
+
+    #[test]
+    fn test_arb_overflowing_pre_release_string(s in arb_overflowing_pre_release_string()) {
+        let v = Version::parse(&s).unwrap();
+        prop_assert!(v.pre.split('.').any(|p| p.bytes().all(|b| b.is_ascii_digit()) && p.parse::<u64>().is_err()), "{}", s);
+        prop_assert!(!v.pre.split('.').any(|p| p.len() > 1 && p.starts_with('0') && p.bytes().all(|b| b.is_ascii_digit())), "{}", s);
+    }
+
+    #[test]
+    fn test_arb_boundary_version(v in arb_boundary_version()) {
+        prop_assert_eq!(Version::parse(&v.to_string()).unwrap(), v.clone());
+    }
+}
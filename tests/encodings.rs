@@ -0,0 +1,144 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+use proptest::prelude::*;
+use proptest_semver::*;
+use semver::{Version, VersionReq};
+
+/// Every double quoted string, in order, which is enough to read back the
+/// fragments, since none of the strings contain a quote.
+fn quoted(s: &str) -> Vec<&str> {
+    s.split('"').skip(1).step_by(2).collect()
+}
+
+/// Splits a row into it's fields, as CSV does.
+fn split_row(line: &str, delimiter: char) -> Vec<String> {
+    let mut fields = vec![String::new()];
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                fields.last_mut().unwrap().push('"');
+            }
+            '"' => quoted = !quoted,
+            c if c == delimiter && !quoted => fields.push(String::new()),
+            c => fields.last_mut().unwrap().push(c),
+        }
+    }
+    fields
+}
+
+proptest! {
+    #[test]
+    fn test_arb_version_with_sortable_key(
+        (a, a_key) in arb_version_with_sortable_key(),
+        (b, b_key) in arb_version_with_sortable_key(),
+    ) {
+        prop_assert_eq!(a_key.len(), 62);
+        prop_assert_eq!(a_key.len(), b_key.len());
+        prop_assert_eq!(a_key.cmp(&b_key), a.cmp_precedence(&b));
+    }
+
+    #[test]
+    fn test_arb_policy_config_toml((config, lists) in arb_policy_config(ConfigFormat::Toml, &["deny", "skip"], 4)) {
+        let expected = lists
+            .iter()
+            .flat_map(|(_, entries)| entries)
+            .flat_map(|e| [e.name.clone(), e.policy.to_string()])
+            .collect::<Vec<_>>();
+        prop_assert_eq!(quoted(&config), expected);
+        prop_assert!(config.starts_with("deny = ["));
+    }
+
+    #[test]
+    fn test_arb_policy_config_json((config, lists) in arb_policy_config(ConfigFormat::Json, &["deny"], 4)) {
+        let mut expected = vec!["deny".to_string()];
+        for e in &lists[0].1 {
+            expected.extend(["name".to_string(), e.name.clone(), "version".to_string(), e.policy.to_string()]);
+        }
+        prop_assert_eq!(quoted(&config), expected);
+    }
+
+    #[test]
+    fn test_arb_pin_policy(policy in arb_pin_policy()) {
+        let req = VersionReq::parse(&policy.to_string()).unwrap();
+        prop_assert_eq!(req, policy.version_req());
+    }
+
+    #[test]
+    fn test_arb_cli_version_flag(flag in arb_cli_version_flag()) {
+        let value = match flag.syntax {
+            CliSyntax::FlagEquals => flag.text.strip_prefix("--").unwrap().split_once('=').unwrap().1,
+            CliSyntax::FlagSpace => flag.text.strip_prefix("--").unwrap().split_once(' ').unwrap().1,
+            CliSyntax::EnvVar => flag.text.split_once('=').unwrap().1,
+        };
+        prop_assert_eq!(value, &flag.value);
+
+        match &flag.expected {
+            CliVersionValue::Version(v) => prop_assert_eq!(&semver::Version::parse(value).unwrap(), v),
+            CliVersionValue::Req(req) => {
+                prop_assert!(semver::Version::parse(value).is_err());
+                prop_assert_eq!(&VersionReq::parse(value).unwrap(), req);
+            }
+            CliVersionValue::Invalid => prop_assert!(VersionReq::parse(value).is_err()),
+        }
+    }
+
+    #[test]
+    fn test_arb_version_lines((blob, expected) in arb_version_lines(16, 0.25)) {
+        let lines = blob.lines().collect::<Vec<_>>();
+        prop_assert_eq!(lines.len(), expected.len());
+
+        for (line, expected) in lines.into_iter().zip(expected) {
+            let actual = if line.starts_with('#') {
+                VersionLine::Comment
+            } else if line.trim().is_empty() {
+                VersionLine::Blank
+            } else if let Ok(v) = Version::parse(line) {
+                VersionLine::Version(v)
+            } else {
+                VersionLine::Invalid(line.to_string())
+            };
+            prop_assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn test_arb_versioned_rows(
+        (delimiter, rows) in prop::sample::select(vec![',', '\t', ';', '|']).prop_flat_map(|delimiter| {
+            let schema = RowSchema {
+                delimiter,
+                columns: vec![ColumnKind::Text, ColumnKind::Version, ColumnKind::Integer, ColumnKind::Req],
+            };
+            (Just(delimiter), arb_versioned_rows(&schema, 8))
+        })
+    ) {
+        for (line, cells) in rows {
+            let fields = split_row(&line, delimiter);
+            prop_assert_eq!(fields.len(), cells.len());
+
+            for (field, cell) in fields.into_iter().zip(cells) {
+                let actual = match cell {
+                    Cell::Version(_) => Cell::Version(Version::parse(&field).unwrap()),
+                    Cell::Req(_) => Cell::Req(VersionReq::parse(&field).unwrap()),
+                    Cell::Text(_) => Cell::Text(field),
+                    Cell::Integer(_) => Cell::Integer(field.parse().unwrap()),
+                };
+                prop_assert_eq!(actual, cell);
+            }
+        }
+    }
+}
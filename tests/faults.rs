@@ -0,0 +1,78 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+use proptest::prelude::*;
+use proptest_semver::*;
+
+proptest! {
+    #[test]
+    fn test_arb_separator_faults((v, (faulted, fault)) in arb_version().prop_flat_map(|v| {
+        let s = v.to_string();
+        (Just(s.clone()), arb_separator_faults(&s, None, None, None))
+    })) {
+        prop_assert_ne!(&v, &faulted);
+        prop_assert_eq!(v[fault.offset..].chars().next(), Some(fault.separator));
+
+        let expected_len = match fault.kind {
+            SeparatorFaultKind::Swap(c) => {
+                prop_assert_ne!(c, fault.separator);
+                v.len()
+            }
+            SeparatorFaultKind::Duplicate => v.len() + 1,
+            SeparatorFaultKind::Remove => v.len() - 1,
+        };
+        prop_assert_eq!(faulted.len(), expected_len, "{}", fault);
+    }
+
+    #[test]
+    fn test_arb_separator_faults_remove_only((v, (faulted, fault)) in arb_version().prop_flat_map(|v| {
+        let s = v.to_string();
+        (Just(s.clone()), arb_separator_faults(&s, Some(0), Some(0), None))
+    })) {
+        prop_assert_eq!(fault.kind, SeparatorFaultKind::Remove);
+        prop_assert_eq!(faulted.len() + 1, v.len());
+    }
+
+    #[test]
+    fn test_arb_invalid_semver(s in arb_invalid_semver()) {
+        prop_assert!(semver::Version::parse(&s).is_err(), "{}", s);
+    }
+
+    #[test]
+    fn test_arb_version_mutation((v, (mutated, mutation)) in arb_version().prop_flat_map(|v| {
+        let s = v.to_string();
+        (Just(s.clone()), arb_version_mutation(&s))
+    })) {
+        prop_assert_ne!(&v, &mutated);
+        match mutation {
+            Mutation::Separator(_) => {}
+            Mutation::LeadingZero(offset) => {
+                prop_assert_eq!(mutated.len(), v.len() + 1);
+                prop_assert!(v.as_bytes()[offset].is_ascii_digit());
+                prop_assert!(offset == 0 || !v.as_bytes()[offset - 1].is_ascii_alphanumeric());
+            }
+            Mutation::Replace { offset, original, replacement } => {
+                prop_assert_eq!(v[offset..].chars().next(), Some(original));
+                prop_assert_eq!(mutated[offset..].chars().next(), Some(replacement));
+                prop_assert!(semver::Version::parse(&mutated).is_err(), "{}", mutated);
+            }
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_arb_separator_faults_too_few_separators() {
+    let _ = arb_separator_faults("1.0", None, None, None);
+}
@@ -0,0 +1,101 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+use proptest::prelude::*;
+use proptest_semver::*;
+use semver::{Version, VersionReq};
+use std::collections::BTreeMap;
+
+/// Brute force search for a solution of `graph`, picking a single version of
+/// each package.
+fn solve(
+    graph: &DependencyGraph,
+    picked: &BTreeMap<String, Version>,
+    pending: &[(String, VersionReq)],
+) -> bool {
+    let Some(((package, req), rest)) = pending.split_first() else {
+        return true;
+    };
+    if let Some(v) = picked.get(package) {
+        return req.matches(v) && solve(graph, picked, rest);
+    }
+
+    graph.packages[package]
+        .iter()
+        .filter(|(v, _)| req.matches(v))
+        .any(|(v, deps)| {
+            let mut picked = picked.clone();
+            picked.insert(package.clone(), v.clone());
+            let mut pending = rest.to_vec();
+            pending.extend(deps.iter().map(|(p, r)| (p.clone(), r.clone())));
+            solve(graph, &picked, &pending)
+        })
+}
+
+fn root_requirements(graph: &DependencyGraph) -> Vec<(String, VersionReq)> {
+    graph
+        .root
+        .iter()
+        .map(|(p, r)| (p.clone(), r.clone()))
+        .collect()
+}
+
+proptest! {
+    #[test]
+    fn test_arb_dependency_graph_solvable(graph in arb_dependency_graph(3, 3, true)) {
+        let solution = graph.solution.clone().unwrap();
+        for (package, req) in &graph.root {
+            prop_assert!(req.matches(&solution[package]));
+        }
+        for (package, v) in &solution {
+            for (dep, req) in &graph.packages[package][v] {
+                prop_assert!(req.matches(&solution[dep]), "{} {} needs {} {}", package, v, dep, req);
+            }
+        }
+        prop_assert!(solve(&graph, &BTreeMap::new(), &root_requirements(&graph)));
+    }
+
+    #[test]
+    fn test_arb_dependency_graph_conflicting(graph in arb_dependency_graph(3, 3, false)) {
+        prop_assert!(graph.solution.is_none());
+        prop_assert!(!solve(&graph, &BTreeMap::new(), &root_requirements(&graph)));
+    }
+
+    #[test]
+    fn test_arb_dependency_graph_shape(graph in arb_dependency_graph(2, 2, true)) {
+        prop_assert!((1..=2).contains(&graph.root.len()));
+        prop_assert!(graph.packages.len() <= 4);
+        for versions in graph.packages.values() {
+            prop_assert!((1..=4).contains(&versions.len()));
+            for deps in versions.values() {
+                prop_assert!(deps.len() <= 2);
+            }
+        }
+    }
+
+    #[test]
+    fn test_arb_lockfile_fixture(fixture in arb_lockfile_fixture(6)) {
+        prop_assert!((1..=6).contains(&fixture.lock.len()));
+        for (package, req) in &fixture.manifest {
+            let pinned = fixture.lock[package].iter().filter(|v| req.matches(v)).count();
+            prop_assert_eq!(pinned, 1, "{} {}", package, req);
+        }
+        for (package, versions) in &fixture.lock {
+            prop_assert!(versions.windows(2).all(|w| w[0] < w[1]));
+            for v in versions {
+                prop_assert!(fixture.manifest.iter().any(|(p, req)| p == package && req.matches(v)));
+            }
+        }
+    }
+}
@@ -0,0 +1,92 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+use proptest::prelude::*;
+use proptest_semver::*;
+use semver::{BuildMetadata, Prerelease, Version};
+
+proptest! {
+    #[test]
+    fn test_arb_identifier(i in arb_identifier(None, None)) {
+        prop_assert!(!i.contains('.'));
+        Prerelease::new(&i).unwrap();
+        BuildMetadata::new(&i).unwrap();
+    }
+
+    #[test]
+    fn test_arb_identifier_numeric(i in arb_identifier(Some(1), Some(0))) {
+        prop_assert!(i.bytes().all(|b| b.is_ascii_digit()));
+    }
+
+    #[test]
+    fn test_arb_identifier_alphanumeric(i in arb_identifier(Some(0), Some(1))) {
+        prop_assert!(!i.bytes().all(|b| b.is_ascii_digit()));
+    }
+
+    #[test]
+    fn test_arb_identifier_composed(ids in prop::collection::vec(arb_identifier(None, None), 1..5)) {
+        let joined = ids.join(".");
+        Prerelease::new(&joined).unwrap();
+        BuildMetadata::new(&joined).unwrap();
+    }
+
+    #[test]
+    fn test_arb_hyphenated_identifiers(i in arb_hyphenated_identifiers()) {
+        prop_assert!(i.split('.').all(|i| i.contains('-')));
+        Prerelease::new(&i).unwrap();
+        BuildMetadata::new(&i).unwrap();
+
+        let v = Version::parse(&format!("1.0.0-{i}+{i}")).unwrap();
+        prop_assert_eq!(v.pre.as_str(), &i);
+        prop_assert_eq!(v.build.as_str(), &i);
+    }
+
+    #[test]
+    fn test_arb_prerelease_identifier(i in arb_prerelease_identifier()) {
+        let pre = prerelease_from_identifiers(std::slice::from_ref(&i));
+        prop_assert_eq!(pre.as_str(), i.to_string());
+        if let PrereleaseIdentifier::AlphaNumeric(s) = &i {
+            prop_assert!(!s.bytes().all(|b| b.is_ascii_digit()));
+        }
+    }
+
+    #[test]
+    fn test_arb_prerelease_identifiers((a, b) in (arb_prerelease_identifiers(1..4), arb_prerelease_identifiers(1..4))) {
+        prop_assert!((1..4).contains(&a.len()));
+        let (pa, pb) = (prerelease_from_identifiers(&a), prerelease_from_identifiers(&b));
+        prop_assert_eq!(a.cmp(&b), pa.cmp(&pb), "{} vs {}", pa, pb);
+    }
+
+    #[test]
+    fn test_arb_build_metadata_identifiers(bm in arb_build_metadata_identifiers(0..3, 40, IdentifierCharset::Hex)) {
+        for i in bm.split('.').filter(|i| !i.is_empty()) {
+            prop_assert_eq!(i.len(), 40);
+            prop_assert!(i.bytes().all(|b| b.is_ascii_hexdigit() && !b.is_ascii_uppercase()));
+        }
+    }
+
+    #[test]
+    fn test_arb_build_metadata_git_sha(bm in arb_build_metadata_git_sha()) {
+        prop_assert!([7, 12, 40].contains(&bm.len()));
+        prop_assert!(bm.bytes().all(|b| b.is_ascii_hexdigit()));
+    }
+
+    #[test]
+    fn test_arb_build_metadata_timestamp(bm in arb_build_metadata_timestamp()) {
+        prop_assert_eq!(bm.len(), 14);
+        prop_assert!(bm.bytes().all(|b| b.is_ascii_digit()));
+        let month: u32 = bm[4..6].parse().unwrap();
+        prop_assert!((1..=12).contains(&month));
+    }
+}
@@ -0,0 +1,296 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+mod common;
+
+use common::{candidates, small_req};
+use proptest::prelude::*;
+use proptest_semver::*;
+use semver::{Version, VersionReq};
+use std::ops::Bound;
+
+proptest! {
+    #[test]
+    fn test_decompose_small_version_req_agrees_with_matches(req in small_req()) {
+        let intervals = decompose_version_req(&req);
+        for v in candidates(&req) {
+            prop_assert_eq!(req.matches(&v), intervals.iter().any(|i| i.contains(&v)), "{} {}", req, v);
+        }
+    }
+
+    #[test]
+    fn test_decompose_version_req_agrees_with_matches((req, intervals) in arb_version_req_with_intervals(3)) {
+        for v in candidates(&req) {
+            prop_assert_eq!(req.matches(&v), intervals.iter().any(|i| i.contains(&v)), "{} {}", req, v);
+        }
+    }
+
+    #[test]
+    fn test_decompose_version_req_is_disjoint((req, intervals) in arb_version_req_with_intervals(3)) {
+        for v in candidates(&req) {
+            prop_assert!(intervals.iter().filter(|i| i.contains(&v)).count() <= 1);
+        }
+        prop_assert!(intervals.iter().filter(|i| !i.includes_prerelease).count() <= 1);
+    }
+}
+
+/// Checks every sampled version lies in the region it claims to.
+fn check_stratified(req: &VersionReq, sampled: &[(VersionRegion, Version)]) {
+    let named = |i: usize| {
+        let c = &req.comparators[i];
+        let mut v = Version::new(c.major, c.minor.unwrap_or(0), c.patch.unwrap_or(0));
+        v.pre = c.pre.clone();
+        v
+    };
+
+    for (region, v) in sampled {
+        match region {
+            VersionRegion::Inside => assert!(req.matches(v), "{req} {v}"),
+            VersionRegion::Below(i) => assert!(v.cmp_precedence(&named(*i)).is_lt(), "{req} {v}"),
+            VersionRegion::Above(i) => assert!(v.cmp_precedence(&named(*i)).is_gt(), "{req} {v}"),
+            VersionRegion::PreReleaseAdjacent(i) => {
+                let n = named(*i);
+                assert_eq!((v.major, v.minor, v.patch), (n.major, n.minor, n.patch));
+                assert!(!v.pre.is_empty());
+            }
+        }
+    }
+
+    // Every comparator always has it's Pre-Release adjacent region.
+    for i in 0..req.comparators.len() {
+        assert!(sampled
+            .iter()
+            .any(|(r, _)| *r == VersionRegion::PreReleaseAdjacent(i)));
+    }
+
+    // Only an empty decomposition has no inside.
+    assert_eq!(
+        sampled.iter().any(|(r, _)| *r == VersionRegion::Inside),
+        !decompose_version_req(req).is_empty()
+    );
+}
+
+proptest! {
+    #[test]
+    fn test_arb_version_stratified_small((req, sampled) in small_req().prop_flat_map(|req| (Just(req.clone()), arb_version_stratified(&req)))) {
+        check_stratified(&req, &sampled);
+    }
+
+    #[test]
+    fn test_arb_version_stratified((req, sampled) in arb_version_req(3).prop_flat_map(|req| (Just(req.clone()), arb_version_stratified(&req)))) {
+        check_stratified(&req, &sampled);
+    }
+}
+
+proptest! {
+    #[test]
+    fn test_arb_version_matching((req, v) in arb_version_req(3).prop_flat_map(|req| {
+        (Just(req.clone()), arb_version_matching(&req).map(|s| s.prop_map(Some).boxed()).unwrap_or_else(|| Just(None).boxed()))
+    })) {
+        prop_assert_eq!(v.is_some(), !decompose_version_req(&req).is_empty());
+        if let Some(v) = v {
+            prop_assert!(req.matches(&v), "{} should match {}", req, v);
+        }
+    }
+
+    #[test]
+    fn test_arb_version_matching_satisfiable((req, v) in arb_matching_req_version_pair().prop_flat_map(|(req, _)| {
+        (Just(req.clone()), arb_version_matching(&req).unwrap())
+    })) {
+        prop_assert!(req.matches(&v), "{} should match {}", req, v);
+    }
+
+    #[test]
+    fn test_arb_satisfiable_version_req((req, v) in arb_satisfiable_version_req()) {
+        prop_assert!(req.matches(&v), "{} should match {}", req, v);
+        prop_assert!(!decompose_version_req(&req).is_empty());
+    }
+
+    #[test]
+    fn test_arb_version_not_matching((req, v) in arb_version_req(3).prop_flat_map(|req| {
+        (Just(req.clone()), arb_version_not_matching(&req).unwrap())
+    })) {
+        prop_assert!(!req.matches(&v), "{} should not match {}", req, v);
+    }
+
+    #[test]
+    fn test_arb_version_not_matching_small((req, v) in small_req().prop_flat_map(|req| {
+        (Just(req.clone()), arb_version_not_matching(&req).unwrap())
+    })) {
+        prop_assert!(!req.matches(&v), "{} should not match {}", req, v);
+    }
+
+    #[test]
+    fn test_arb_version_bounds((lower, upper) in arb_version_bounds()) {
+        let range = VersionInterval {
+            lower: lower.clone(),
+            upper: upper.clone(),
+            includes_prerelease: true,
+        };
+        prop_assert!(is_non_empty(&range));
+
+        let req = version_req_from_bounds(&lower, &upper);
+        VersionReq::parse(&req.to_string()).unwrap();
+        for v in candidates(&req) {
+            if v.pre.is_empty() {
+                prop_assert_eq!(req.matches(&v), range.contains(&v), "{} {}", req, v);
+            } else if req.matches(&v) {
+                prop_assert!(range.contains(&v), "{} {}", req, v);
+            }
+        }
+    }
+}
+
+/// A range is non-empty when it's lowest possible member is in range, which
+/// is either `0.0.0-0`, it's lower bound, or the version immediately after it.
+fn is_non_empty(range: &VersionInterval) -> bool {
+    let lowest = match &range.lower {
+        Bound::Unbounded => Version::parse("0.0.0-0").unwrap(),
+        Bound::Included(v) => v.clone(),
+        Bound::Excluded(v) if v.pre.is_empty() => {
+            let next = match (v.patch.checked_add(1), v.minor.checked_add(1)) {
+                (Some(patch), _) => (v.major, v.minor, patch),
+                (None, Some(minor)) => (v.major, minor, 0),
+                (None, None) => match v.major.checked_add(1) {
+                    Some(major) => (major, 0, 0),
+                    None => return false,
+                },
+            };
+            Version::parse(&format!("{}.{}.{}-0", next.0, next.1, next.2)).unwrap()
+        }
+        Bound::Excluded(v) => {
+            let mut v = v.clone();
+            v.pre = semver::Prerelease::new(&format!("{}.0", v.pre)).unwrap();
+            v
+        }
+    };
+    range.contains(&lowest)
+}
+
+#[test]
+fn test_decompose_version_req_known() {
+    let req = VersionReq::parse(">=1.0.0-alpha, <2").unwrap();
+    assert_eq!(
+        decompose_version_req(&req),
+        vec![
+            VersionInterval {
+                lower: Bound::Included(Version::parse("1.0.0-alpha").unwrap()),
+                upper: Bound::Excluded(Version::parse("1.0.0").unwrap()),
+                includes_prerelease: true,
+            },
+            VersionInterval {
+                lower: Bound::Included(Version::parse("1.0.0").unwrap()),
+                upper: Bound::Excluded(Version::parse("2.0.0").unwrap()),
+                includes_prerelease: false,
+            },
+        ]
+    );
+
+    let req = VersionReq::parse(">2.0.0, <1.0.0").unwrap();
+    assert!(decompose_version_req(&req).is_empty());
+}
+
+/// Versions matched by `a` but not `b`, from the candidates of both.
+fn counterexamples(a: &semver::Comparator, b: &semver::Comparator) -> Vec<Version> {
+    let req = VersionReq {
+        comparators: vec![a.clone(), b.clone()],
+    };
+    let mut around = candidates(&req);
+    for c in [a, b] {
+        if let (Some(minor), Some(patch)) = (c.minor, c.patch) {
+            if !c.pre.is_empty() {
+                let mut v = Version::new(c.major, minor, patch);
+                v.pre = semver::Prerelease::new(&format!("{}.0", c.pre)).unwrap();
+                around.push(v);
+            }
+        }
+    }
+
+    around
+        .into_iter()
+        .filter(|v| a.matches(v) && !b.matches(v))
+        .collect()
+}
+
+proptest! {
+    #[test]
+    fn test_arb_comparator_implication_pair((a, b, implied) in arb_comparator_implication_pair()) {
+        prop_assert_eq!(implied, implies(&a, &b));
+        prop_assert!(implies(&a, &a));
+        prop_assert!(implies(&b, &b));
+
+        let counterexamples = counterexamples(&a, &b);
+        prop_assert_eq!(implied, counterexamples.is_empty(), "{} => {}: {:?}", a, b, counterexamples);
+    }
+
+    #[test]
+    fn test_implies_small((a, b) in (small_req(), small_req())) {
+        let (a, b) = (&a.comparators[0], &b.comparators[0]);
+        let counterexamples = counterexamples(a, b);
+        prop_assert_eq!(implies(a, b), counterexamples.is_empty(), "{} => {}: {:?}", a, b, counterexamples);
+    }
+
+    #[test]
+    fn test_arb_version_set((set, reqs) in arb_version_set(3)) {
+        for v in reqs.iter().flat_map(candidates) {
+            prop_assert_eq!(set.contains(&v), reqs.iter().any(|r| r.matches(&v)), "{:?} {}", reqs, v);
+        }
+
+        let converted = set.to_version_reqs();
+        let roundtrip = converted
+            .iter()
+            .map(VersionSet::from_version_req)
+            .fold(VersionSet::default(), |a, b| a.union(&b));
+        prop_assert_eq!(&roundtrip, &set);
+        for v in reqs.iter().chain(&converted).flat_map(candidates) {
+            prop_assert_eq!(set.contains(&v), converted.iter().any(|r| r.matches(&v)), "{:?} {}", converted, v);
+        }
+    }
+
+    #[test]
+    fn test_version_set_operations(((a, a_reqs), (b, b_reqs)) in (arb_version_set(2), arb_version_set(2))) {
+        let (union, intersection) = (a.union(&b), a.intersection(&b));
+        for v in a_reqs.iter().chain(&b_reqs).flat_map(candidates) {
+            prop_assert_eq!(union.contains(&v), a.contains(&v) || b.contains(&v), "{}", v);
+            prop_assert_eq!(intersection.contains(&v), a.contains(&v) && b.contains(&v), "{}", v);
+            if a.is_subset(&b) {
+                prop_assert!(!a.contains(&v) || b.contains(&v), "{}", v);
+            }
+        }
+
+        prop_assert!(a.is_subset(&union) && b.is_subset(&union));
+        prop_assert!(intersection.is_subset(&a) && intersection.is_subset(&b));
+        prop_assert_eq!(a.is_subset(&b), intersection == a);
+        prop_assert_eq!(&a.intersection(&a), &a);
+        prop_assert_eq!(&a.union(&a), &a);
+    }
+
+    #[test]
+    fn test_arb_version_req_intersection_pair((a, b, kind) in arb_version_req_intersection_pair()) {
+        let near = candidates(&a).into_iter().chain(candidates(&b)).collect::<Vec<_>>();
+        let both = near.iter().any(|v| a.matches(v) && b.matches(v));
+        let only_a = near.iter().any(|v| a.matches(v) && !b.matches(v));
+        let only_b = near.iter().any(|v| !a.matches(v) && b.matches(v));
+        match kind {
+            IntersectionKind::Disjoint => prop_assert!(!both, "{} and {}", a, b),
+            IntersectionKind::Overlapping => prop_assert!(!decompose_version_req(&a).is_empty()),
+            IntersectionKind::Equal => prop_assert!(!only_a && !only_b, "{} and {}", a, b),
+            IntersectionKind::Contains => prop_assert!(!only_b, "{} and {}", a, b),
+            IntersectionKind::ContainedBy => prop_assert!(!only_a, "{} and {}", a, b),
+        }
+        if both && only_a && only_b {
+            prop_assert_eq!(kind, IntersectionKind::Overlapping);
+        }
+    }
+}
@@ -0,0 +1,76 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+use proptest::prelude::*;
+use proptest::strategy::ValueTree;
+use proptest::test_runner::TestRunner;
+use proptest_semver::*;
+use semver::Version;
+
+proptest! {
+    #[test]
+    fn test_arb_identifier_limit_probe(p in arb_identifier_limit_probe()) {
+        let parsed = Version::parse(&p.version);
+        prop_assert_eq!(parsed.is_ok(), p.accepted, "{:?}", parsed);
+
+        if let Ok(v) = parsed {
+            match p.position {
+                IdentifierPosition::PreRelease => prop_assert_eq!(v.pre.as_str(), &p.identifier),
+                IdentifierPosition::BuildMetadata => prop_assert_eq!(v.build.as_str(), &p.identifier),
+                _ => prop_assert!(v.to_string().contains(&p.identifier)),
+            }
+        }
+    }
+
+    #[test]
+    fn test_arb_version_bounded_mem(v in arb_version_bounded_mem(64)) {
+        prop_assert!(version_heap_bytes(&v) <= 64, "{}", v);
+        prop_assert!(v.pre.len() <= 31 && v.build.len() <= 31);
+    }
+
+    #[test]
+    fn test_arb_version_bounded_mem_inline(v in arb_version_bounded_mem(0)) {
+        prop_assert_eq!(version_heap_bytes(&v), 0);
+        prop_assert!(v.pre.len() <= 8 && v.build.len() <= 8);
+    }
+}
+
+#[test]
+fn test_version_heap_bytes() {
+    let v = semver::Version::parse("1.0.0-12345678+abc").unwrap();
+    assert_eq!(version_heap_bytes(&v), 0);
+
+    let v = semver::Version::parse("1.0.0-123456789").unwrap();
+    assert_eq!(version_heap_bytes(&v), 10);
+
+    let v = semver::Version::parse(&format!("1.0.0+{}", "a".repeat(128))).unwrap();
+    assert_eq!(version_heap_bytes(&v), 130);
+}
+
+#[test]
+fn test_arb_version_bounded_mem_spread() {
+    // Past the largest threshold, where lengths are spread across up to
+    // about `16k` bytes, rather than piling up near it.
+    let strategy = arb_version_bounded_mem(2 * 16384);
+    let mut runner = TestRunner::deterministic();
+    let lens = (0..64)
+        .map(|_| {
+            let v = strategy.new_tree(&mut runner).unwrap().current();
+            assert!(version_heap_bytes(&v) <= 2 * 16384, "{}", v);
+            v.pre.len()
+        })
+        .collect::<Vec<_>>();
+    let short = lens.iter().filter(|len| **len < 8192).count();
+    assert!((16..=48).contains(&short), "{lens:?}");
+}
@@ -18,6 +18,8 @@
 //!
 //! The Regex from the spec is available here: <https://semver.org/>, and where
 //! most of these come from.
+// These tests predate linting the test targets, and are kept as written.
+#![allow(clippy::identity_op, clippy::single_match)]
 use proptest::prelude::*;
 use proptest_semver::*;
 use semver::{Comparator, Version, VersionReq};
@@ -177,4 +179,14 @@ proptest! {
         fn test_arb_full_comparator(a in arb_full_comparator(None, None, None), v in arb_version()) {
             VersionReq::parse(&a.to_string()).unwrap().matches(&v);
         }
+
+        #[test]
+        fn test_arb_semver_op_weighted(op in arb_semver_op_weighted(&OpWeights { tilde: 1, ..OpWeights::uniform(0) })) {
+            prop_assert_eq!(op, semver::Op::Tilde);
+        }
+
+        #[test]
+        fn test_arb_full_comparator_crates_io(a in arb_full_comparator_with_ops(&OpWeights::CRATES_IO, Some(98), Some(1), Some(1)), v in arb_version()) {
+            VersionReq::parse(&a.to_string()).unwrap().matches(&v);
+        }
 }
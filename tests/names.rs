@@ -0,0 +1,62 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+use proptest::prelude::*;
+use proptest_semver::*;
+
+fn is_npm_name_part(s: &str) -> bool {
+    !s.is_empty()
+        && !s.starts_with(['.', '_'])
+        && s.bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b"._~-".contains(&b))
+}
+
+proptest! {
+    #[test]
+    fn test_arb_crate_name(name in arb_crate_name()) {
+        prop_assert!((1..=MAX_CRATE_NAME_LEN).contains(&name.len()));
+        prop_assert!(name.starts_with(|c: char| c.is_ascii_alphabetic()));
+        prop_assert!(name.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_'));
+    }
+
+    #[test]
+    fn test_arb_crate_name_with_reserved(name in arb_crate_name_with_reserved(0.5)) {
+        prop_assert!((1..=MAX_CRATE_NAME_LEN).contains(&name.len()));
+        prop_assert!(name.starts_with(|c: char| c.is_ascii_alphabetic()));
+        prop_assert!(name.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_'));
+    }
+
+    #[test]
+    fn test_arb_crate_name_with_reserved_avoided(name in arb_crate_name_with_reserved(0.0)) {
+        prop_assert!(!is_reserved_crate_name(&name), "{}", name);
+    }
+
+    #[test]
+    fn test_arb_crate_name_with_reserved_always(name in arb_crate_name_with_reserved(1.0)) {
+        prop_assert!(is_reserved_crate_name(&name), "{}", name);
+    }
+
+    #[test]
+    fn test_arb_npm_package_name(name in arb_npm_package_name(0.5)) {
+        prop_assert!(name.len() <= MAX_NPM_PACKAGE_NAME_LEN);
+        match name.strip_prefix('@') {
+            Some(scoped) => {
+                let (scope, name) = scoped.split_once('/').unwrap();
+                prop_assert!(is_npm_name_part(scope));
+                prop_assert!(is_npm_name_part(name));
+            }
+            None => prop_assert!(is_npm_name_part(&name)),
+        }
+    }
+}
@@ -0,0 +1,76 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+use proptest::prelude::*;
+use proptest_semver::*;
+use semver::Prerelease;
+
+proptest! {
+    #[test]
+    fn test_arb_pre_release_case_variants((a, b, ordering) in arb_pre_release_case_variants()) {
+        prop_assert_ne!(&a, &b);
+        prop_assert!(a.eq_ignore_ascii_case(&b));
+        prop_assert_eq!(Prerelease::new(&a).unwrap().cmp(&Prerelease::new(&b).unwrap()), ordering);
+    }
+
+    #[test]
+    fn test_arb_lexicographic_trap_pair((a, b, ordering) in arb_lexicographic_trap_pair()) {
+        prop_assert_eq!(a.cmp_precedence(&b), ordering);
+        prop_assert_eq!(a.to_string().cmp(&b.to_string()), ordering.reverse());
+    }
+
+    #[test]
+    fn test_arb_ident_kind_boundary_pair((a, b, ordering) in arb_ident_kind_boundary_pair()) {
+        let (a_ids, b_ids) = (a.split('.').collect::<Vec<_>>(), b.split('.').collect::<Vec<_>>());
+        prop_assert_eq!(a_ids.len(), b_ids.len());
+
+        let differing = a_ids.iter().zip(&b_ids).filter(|(x, y)| x != y).collect::<Vec<_>>();
+        prop_assert_eq!(differing.len(), 1);
+        let (x, y) = differing[0];
+        let numeric = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+        prop_assert!(numeric(x) != numeric(y));
+
+        prop_assert_eq!(Prerelease::new(&a).unwrap().cmp(&Prerelease::new(&b).unwrap()), ordering);
+    }
+
+    #[test]
+    fn test_arb_ident_count_pair((a, b, ordering) in arb_ident_count_pair()) {
+        let (shorter, longer) = if ordering == std::cmp::Ordering::Less { (&a, &b) } else { (&b, &a) };
+        let prefix = format!("{shorter}.");
+        prop_assert!(longer.starts_with(&prefix));
+
+        prop_assert_eq!(Prerelease::new(&a).unwrap().cmp(&Prerelease::new(&b).unwrap()), ordering);
+    }
+
+    #[test]
+    fn test_arb_version_pair_ordered(
+        (ordering, (a, b)) in prop_oneof![
+            Just(std::cmp::Ordering::Less),
+            Just(std::cmp::Ordering::Equal),
+            Just(std::cmp::Ordering::Greater),
+        ].prop_flat_map(|ordering| (Just(ordering), arb_version_pair_ordered(ordering)))
+    ) {
+        prop_assert_eq!(a.cmp(&b), ordering);
+        prop_assert_eq!(b.cmp(&a), ordering.reverse());
+    }
+
+    #[test]
+    fn test_arb_precedence_equal_pair((a, b) in arb_precedence_equal_pair()) {
+        prop_assert_eq!(a.cmp_precedence(&b), std::cmp::Ordering::Equal);
+        prop_assert_ne!(a.cmp(&b), std::cmp::Ordering::Equal);
+        prop_assert_ne!(&a.build, &b.build);
+        prop_assert_ne!(a.to_string(), b.to_string());
+        prop_assert_eq!((a.major, a.minor, a.patch, &a.pre), (b.major, b.minor, b.patch, &b.pre));
+    }
+}
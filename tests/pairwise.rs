@@ -0,0 +1,69 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+use proptest::prelude::*;
+use proptest_semver::*;
+use semver::Version;
+
+#[test]
+fn test_pairwise_versions_cover_every_pair() {
+    let features = pairwise_versions()
+        .into_iter()
+        .map(|(f, _)| {
+            [
+                format!("pre={}", f.has_pre_release),
+                format!("build={}", f.has_build_metadata),
+                format!("zero={}", f.zero_major),
+                format!("boundary={:?}", f.boundary),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    let values = [
+        vec!["pre=false", "pre=true"],
+        vec!["build=false", "build=true"],
+        vec!["zero=false", "zero=true"],
+        vec!["boundary=None", "boundary=Minor", "boundary=Patch"],
+    ];
+
+    for a in 0..values.len() {
+        for b in a + 1..values.len() {
+            for x in &values[a] {
+                for y in &values[b] {
+                    assert!(
+                        features.iter().any(|f| f[a] == *x && f[b] == *y),
+                        "{x} with {y} is not covered"
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_pairwise_versions_are_deterministic() {
+    assert_eq!(pairwise_versions(), pairwise_versions());
+}
+
+proptest! {
+    #[test]
+    fn test_pairwise_versions_select((features, v) in prop::sample::select(pairwise_versions())) {
+        prop_assert_eq!(Version::parse(&v.to_string()).unwrap(), v.clone());
+        prop_assert_eq!(!v.pre.is_empty(), features.has_pre_release);
+        prop_assert_eq!(!v.build.is_empty(), features.has_build_metadata);
+        prop_assert_eq!(v.major == 0, features.zero_major);
+        prop_assert_eq!(v.minor == u64::MAX, features.boundary == BoundaryComponent::Minor);
+        prop_assert_eq!(v.patch == u64::MAX, features.boundary == BoundaryComponent::Patch);
+    }
+}
@@ -0,0 +1,65 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+use proptest::prelude::*;
+use proptest_semver::*;
+use semver::Version;
+use std::cmp::Ordering;
+
+proptest! {
+    #[test]
+    fn test_arb_version_below((v, below) in arb_version_weighted(0.5, 0.25).prop_flat_map(|v| (Just(v.clone()), arb_version_below(&v).unwrap()))) {
+        prop_assert_eq!(below.cmp_precedence(&v), Ordering::Less, "{} should be below {}", below, v);
+        prop_assert!(below.build.is_empty());
+    }
+
+    #[test]
+    fn test_arb_version_below_pre_release_boundary((v, below) in (0..4u64, 0..4u64, 0..4u64).prop_flat_map(|(major, minor, patch)| {
+        let v = Version::new(major, minor, patch);
+        (Just(v.clone()), arb_version_below(&v).unwrap())
+    })) {
+        prop_assert_eq!(below.cmp_precedence(&v), Ordering::Less, "{} should be below {}", below, v);
+    }
+
+    #[test]
+    fn test_arb_version_above((v, above) in arb_version_weighted(0.5, 0.25).prop_flat_map(|v| (Just(v.clone()), arb_version_above(&v).unwrap()))) {
+        prop_assert_eq!(above.cmp_precedence(&v), Ordering::Greater, "{} should be above {}", above, v);
+        prop_assert!(above.build.is_empty());
+    }
+
+    #[test]
+    fn test_arb_version_above_pre_release_boundary((v, above) in (0..4u64, 0..4u64, 0..4u64, arb_semver_prerelease()).prop_flat_map(|(major, minor, patch, pre)| {
+        let mut v = Version::new(major, minor, patch);
+        v.pre = pre;
+        (Just(v.clone()), arb_version_above(&v).unwrap())
+    })) {
+        prop_assert_eq!(above.cmp_precedence(&v), Ordering::Greater, "{} should be above {}", above, v);
+    }
+}
+
+#[test]
+fn test_arb_version_above_maximum() {
+    assert!(arb_version_above(&Version::new(u64::MAX, u64::MAX, u64::MAX)).is_none());
+    assert!(arb_version_above(
+        &Version::parse("18446744073709551615.18446744073709551615.18446744073709551615-0")
+            .unwrap()
+    )
+    .is_some());
+}
+
+#[test]
+fn test_arb_version_below_minimum() {
+    assert!(arb_version_below(&Version::parse("0.0.0-0").unwrap()).is_none());
+    assert!(arb_version_below(&Version::new(0, 0, 0)).is_some());
+}
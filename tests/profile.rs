@@ -0,0 +1,177 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+#![cfg(feature = "serde")]
+use proptest::option::Probability;
+use proptest_semver::*;
+
+#[test]
+fn test_shape_from_toml_str() {
+    let shape = Shape::from_toml_str(
+        "# A profile for small versions.\nhas_pre = 0.2\npre_idents = [1, 3]\n\ndigits = [1, 3] # Up to 99.\n",
+    )
+    .unwrap();
+    assert_eq!(
+        shape,
+        Shape {
+            has_pre: Probability::new(0.2),
+            pre_idents: 1..3,
+            digits: 1..3,
+            ..Shape::default()
+        }
+    );
+}
+
+#[test]
+fn test_shape_from_json_str() {
+    let shape = Shape::from_json_str(r#"{"has_build": 0, "build_idents": [2, 4]}"#).unwrap();
+    assert_eq!(
+        shape,
+        Shape {
+            has_build: Probability::new(0.0),
+            build_idents: 2..4,
+            ..Shape::default()
+        }
+    );
+}
+
+#[test]
+fn test_shape_from_str_errors() {
+    assert!(Shape::from_toml_str("has_pre = 2").is_err());
+    assert!(Shape::from_toml_str("digits = 3").is_err());
+    assert!(Shape::from_toml_str("colour = 0.5").is_err());
+    assert!(Shape::from_json_str("[]").is_err());
+    assert!(Shape::from_json_str(r#"{has_pre: 0.5}"#).is_err());
+
+    // Ranges that can't be generated from.
+    assert!(Shape::from_toml_str("pre_idents = [0, 3]").is_err());
+    assert!(Shape::from_toml_str("build_idents = [2, 2]").is_err());
+    assert!(Shape::from_toml_str("digits = [5, 2]").is_err());
+    assert!(Shape::from_json_str(r#"{"digits": [21, 22]}"#).is_err());
+}
+
+#[test]
+fn test_shape_to_str_round_trip() {
+    let shape = Shape {
+        has_pre: Probability::new(0.2),
+        pre_idents: 1..3,
+        digits: 1..3,
+        ..Shape::default()
+    };
+    assert_eq!(
+        Shape::from_toml_str(&shape.to_toml_string()).unwrap(),
+        shape
+    );
+    assert_eq!(
+        Shape::from_json_str(&shape.to_json_string()).unwrap(),
+        shape
+    );
+}
+
+#[test]
+fn test_op_weights_from_str() {
+    let expected = OpWeights {
+        caret: 900,
+        tilde: 30,
+        ..OpWeights::default()
+    };
+    assert_eq!(
+        OpWeights::from_toml_str("caret = 900\ntilde = 30").unwrap(),
+        expected
+    );
+    assert_eq!(
+        OpWeights::from_json_str(r#"{"caret": 900, "tilde": 30}"#).unwrap(),
+        expected
+    );
+    assert!(OpWeights::from_toml_str("caret = -1").is_err());
+    assert!(OpWeights::from_toml_str("caret = 0.5").is_err());
+    assert!(OpWeights::from_toml_str(
+        "exact = 0\ngreater = 0\ngreater_eq = 0\nless = 0\nless_eq = 0\ntilde = 0\ncaret = 0\nwildcard = 0"
+    )
+    .is_err());
+
+    assert_eq!(
+        OpWeights::from_toml_str(&OpWeights::CRATES_IO.to_toml_string()).unwrap(),
+        OpWeights::CRATES_IO
+    );
+    assert_eq!(
+        OpWeights::from_json_str(&OpWeights::CRATES_IO.to_json_string()).unwrap(),
+        OpWeights::CRATES_IO
+    );
+}
+
+#[test]
+fn test_params_serde() {
+    let params: VersionParams = serde_json::from_str(
+        r#"{"pre": {"identifiers": [1, 3]}, "build": {"identifiers": [0, 1]}}"#,
+    )
+    .unwrap();
+    assert_eq!(params.pre.identifiers.start_end_incl(), (1, 2));
+    assert_eq!(
+        params.pre.identifier_len,
+        PrereleaseParams::default().identifier_len
+    );
+    assert_eq!(params.build.identifiers.start_end_incl(), (0, 0));
+
+    let json = serde_json::to_string(&params).unwrap();
+    let again: VersionParams = serde_json::from_str(&json).unwrap();
+    assert_eq!(again.pre.identifiers, params.pre.identifiers);
+
+    let params: FullComparatorParams =
+        toml::from_str("weight_of_plain = 3\n\n[ops]\ncaret = 900").unwrap();
+    assert_eq!(params.weight_of_plain, Some(3));
+    assert_eq!(params.ops.caret, 900);
+    assert_eq!(params.ops.tilde, OpWeights::default().tilde);
+
+    assert_eq!(
+        serde_json::from_str::<IdentifierCharset>(r#""Hex""#).unwrap(),
+        IdentifierCharset::Hex
+    );
+}
+
+#[test]
+fn test_params_from_str() {
+    let params = PrereleaseParams::from_toml_str("identifiers = [1, 3]").unwrap();
+    assert_eq!(params.identifiers.start_end_incl(), (1, 2));
+    assert_eq!(
+        PrereleaseParams::from_json_str(&params.to_json_string())
+            .unwrap()
+            .identifiers,
+        params.identifiers
+    );
+
+    // Ranges and probabilities that can't be generated from.
+    assert!(PrereleaseParams::from_toml_str("identifiers = [4, 0]").is_err());
+    assert!(PrereleaseParams::from_toml_str("probability_of_numeric = 1.5").is_err());
+    assert!(BuildMetadataParams::from_json_str(r#"{"identifier_len": [3, 3]}"#).is_err());
+    assert!(VersionParams::from_toml_str("[pre]\nidentifiers = [4, 0]").is_err());
+    assert!(serde_json::from_str::<Shape>(r#"{"digits": [5, 2]}"#).is_err());
+
+    // Weights that can't be sampled from.
+    assert!(FullComparatorParams::from_toml_str(
+        "weight_of_plain = 0\nweight_of_wildcard_minor = 0\nweight_of_wildcard_patch = 0"
+    )
+    .is_err());
+    assert!(ComparatorVecParams::from_toml_str(
+        "weight_of_wildcard = 0\nweight_of_comparator_list = 0"
+    )
+    .is_err());
+    assert!(ComparatorVecParams::from_toml_str("max_comparators = 33").is_err());
+    assert_eq!(
+        ComparatorVecParams::from_toml_str("max_comparators = 2")
+            .unwrap()
+            .max_comparators,
+        2
+    );
+}
@@ -0,0 +1,162 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+use proptest::prelude::*;
+use proptest_semver::*;
+use semver::Version;
+use std::collections::BTreeMap;
+
+proptest! {
+    #[test]
+    fn test_arb_registry(registry in arb_registry(8, 16)) {
+        prop_assert!((1..=8).contains(&registry.len()));
+        for versions in registry.values() {
+            prop_assert!((1..=16).contains(&versions.len()));
+            for (s, v) in versions {
+                prop_assert_eq!(&Version::parse(s).unwrap(), v);
+            }
+            prop_assert!(versions.windows(2).all(|w| w[0].1 < w[1].1));
+        }
+    }
+
+    #[test]
+    fn test_arb_registry_with_yanked(registry in arb_registry_with_yanked(8, 16, 0.25)) {
+        prop_assert!((1..=8).contains(&registry.len()));
+        for versions in registry.values() {
+            prop_assert!((1..=16).contains(&versions.len()));
+            prop_assert!(versions.windows(2).all(|w| w[0].version < w[1].version));
+        }
+    }
+
+    #[test]
+    fn test_arb_registry_with_yanked_never(registry in arb_registry_with_yanked(4, 4, 0.0)) {
+        prop_assert!(registry.values().flatten().all(|p| !p.yanked));
+    }
+
+    #[test]
+    fn test_arb_dist_tags((versions, tags) in arb_vec_versions(16).prop_flat_map(|versions| {
+        (Just(versions.clone()), arb_dist_tags(&versions))
+    })) {
+        for v in tags.values() {
+            prop_assert!(versions.contains(v));
+        }
+
+        let has_release = versions.iter().any(|v| v.pre.is_empty());
+        prop_assert_eq!(tags.contains_key("latest"), has_release);
+        if let Some(latest) = tags.get("latest") {
+            prop_assert!(latest.pre.is_empty());
+            if let Some(next) = tags.get("next") {
+                prop_assert!(next.cmp_precedence(latest).is_gt());
+            }
+        }
+        if let Some(beta) = tags.get("beta") {
+            prop_assert!(!beta.pre.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_arb_history_with_yanked_latest((history, fallback) in arb_history_with_yanked_latest(16)) {
+        prop_assert!(history.last().unwrap().yanked);
+        prop_assert!(history.windows(2).all(|w| w[0].version.cmp_precedence(&w[1].version).is_lt()));
+
+        let highest = history
+            .iter()
+            .filter(|p| !p.yanked)
+            .map(|p| &p.version)
+            .max_by(|a, b| a.cmp_precedence(b));
+        prop_assert_eq!(highest, fallback.as_ref());
+    }
+
+    #[test]
+    fn test_arb_multi_package_event_log((log, state) in arb_multi_package_event_log(3, 24)) {
+        prop_assert_eq!(log.len(), 24);
+        prop_assert_eq!(state.len(), 3);
+
+        // Replays the log into a materialized view.
+        let mut view: BTreeMap<String, Vec<PublishedVersion>> =
+            state.keys().map(|name| (name.clone(), Vec::new())).collect();
+        for event in &log {
+            let published = view.get_mut(&event.package).unwrap();
+            match &event.kind {
+                RegistryEventKind::Publish(v) => {
+                    prop_assert!(published.iter().all(|p| p.version != *v));
+                    published.push(PublishedVersion { version: v.clone(), yanked: false });
+                }
+                RegistryEventKind::Yank(v) => {
+                    let p = published.iter_mut().find(|p| p.version == *v).unwrap();
+                    prop_assert!(!p.yanked);
+                    p.yanked = true;
+                }
+            }
+        }
+        for published in view.values_mut() {
+            published.sort_by(|a, b| a.version.cmp_precedence(&b.version));
+        }
+
+        prop_assert_eq!(view, state);
+    }
+
+    #[test]
+    fn test_arb_version_query_batch(batch in arb_version_query_batch(32)) {
+        prop_assert!(!batch.is_empty() && batch.len() <= 32);
+
+        let mut counts = BTreeMap::new();
+        for (name, req) in &batch {
+            *counts.entry((name.clone(), req.to_string())).or_insert(0) += 1;
+        }
+        prop_assert!(counts.len() <= 32);
+    }
+
+    #[test]
+    fn test_arb_mirror_pair(pair in arb_mirror_pair()) {
+        prop_assert!(!pair.primary.is_empty());
+        for list in [&pair.primary, &pair.mirror] {
+            for w in list.windows(2) {
+                prop_assert!(w[0].version.cmp_precedence(&w[1].version).is_lt());
+            }
+        }
+
+        // Reconciling the mirror with the diff brings it in sync.
+        let mut mirror = pair.mirror.clone();
+        mirror.retain(|p| !pair.diff.extra.contains(&p.version));
+        for p in &mut mirror {
+            if pair.diff.yanked_differs.contains(&p.version) {
+                p.yanked = !p.yanked;
+            }
+        }
+        for p in &pair.primary {
+            if pair.diff.missing.contains(&p.version) {
+                mirror.push(p.clone());
+            }
+        }
+        mirror.sort_by(|a, b| a.version.cmp_precedence(&b.version));
+        prop_assert_eq!(&mirror, &pair.primary);
+
+        for v in &pair.diff.extra {
+            prop_assert!(pair.mirror.iter().any(|p| &p.version == v && p.yanked));
+        }
+        prop_assert_eq!(pair.diff.is_empty(), pair.mirror == pair.primary);
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig { cases: 8, ..ProptestConfig::default() })]
+
+    // More distinct queries than the weight of the most popular one used to
+    // be, where the least popular ones had a weight of `0`.
+    #[test]
+    fn test_arb_version_query_batch_large(batch in arb_version_query_batch(2000)) {
+        prop_assert!(!batch.is_empty() && batch.len() <= 2000);
+    }
+}
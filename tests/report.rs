@@ -0,0 +1,66 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+use proptest_semver::*;
+
+#[test]
+fn test_report_is_deterministic() {
+    assert_eq!(report(64), report(64));
+}
+
+#[test]
+fn test_report() {
+    for r in report(64) {
+        assert_eq!(r.samples, 64);
+        assert_eq!(r.variants.values().sum::<usize>(), 64);
+        assert!(r.min_len <= r.max_len);
+        assert!(r.min_len as f64 <= r.mean_len && r.mean_len <= r.max_len as f64);
+        if r.name != "arb_semver" {
+            assert_eq!(r.validity_rate(), 1.0, "{}", r.name);
+        }
+
+        let json = r.to_json();
+        assert!(json.starts_with('{') && json.ends_with('}'));
+        assert!(json.contains(&format!("\"name\": \"{}\"", r.name)));
+    }
+}
+
+#[test]
+fn test_report_strategy_empty() {
+    let r = report_strategy("nothing", arb_version(), 0, |v| Sample {
+        text: v.to_string(),
+        valid: true,
+        variant: String::new(),
+    });
+    assert_eq!((r.min_len, r.max_len, r.mean_len), (0, 0, 0.0));
+    assert_eq!(r.validity_rate(), 0.0);
+}
+
+#[test]
+fn test_report_to_json_escapes() {
+    let r = report_strategy("a \"name\"\n\u{7f}", arb_version(), 1, |v| Sample {
+        text: v.to_string(),
+        valid: true,
+        variant: "back\\slash\t".to_string(),
+    });
+    let json = r.to_json();
+    assert!(json.starts_with(r#"{"name": "a \"name\"\n\u007f", "#));
+    assert!(json.ends_with(r#""variants": {"back\\slash\t": 1}}"#));
+
+    #[cfg(feature = "serde")]
+    {
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["name"], r.name.as_str());
+    }
+}
@@ -0,0 +1,60 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+use proptest::prelude::*;
+use proptest_semver::*;
+
+proptest! {
+    #[test]
+    fn test_arb_repro_bundle(bundle in arb_repro_bundle()) {
+        prop_assert!(bundle.packages.contains_key(&bundle.package));
+        if let Some(expected) = &bundle.expected {
+            prop_assert!(bundle.req.matches(expected));
+            for v in &bundle.packages[&bundle.package] {
+                prop_assert!(!bundle.req.matches(v) || v.cmp_precedence(expected).is_le());
+            }
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_repro_bundle_json(bundle in arb_repro_bundle()) {
+        let json = bundle.to_json();
+        prop_assert!(!json.contains('\n'));
+        prop_assert_eq!(ReproBundle::from_json(&json).unwrap(), bundle);
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_repro_bundle_from_json() {
+    let bundle = ReproBundle::from_json(
+        r#"{ "packages": { "a": ["1.0.0", "1.2.0"] }, "package": "a", "req": "^1.1", "expected": null }"#,
+    )
+    .unwrap();
+    assert_eq!(bundle.packages["a"].len(), 2);
+    assert_eq!(bundle.expected, None);
+
+    assert!(ReproBundle::from_json(r#"{"packages":{}}"#).is_err());
+    assert!(ReproBundle::from_json(
+        r#"{"packages":{"a":["1"]},"package":"a","req":"*","expected":null}"#
+    )
+    .is_err());
+
+    // The package queried isn't one of the packages.
+    assert!(ReproBundle::from_json(
+        r#"{"packages":{"a":["1.0.0"]},"package":"b","req":"*","expected":null}"#
+    )
+    .is_err());
+}
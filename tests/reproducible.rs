@@ -0,0 +1,40 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+use proptest::prelude::*;
+use proptest_semver::*;
+use semver::Version;
+
+proptest! {
+    #[test]
+    fn test_arb_reproducible_version((seed, a, b) in prop::collection::vec(any::<u8>(), 0..32).prop_flat_map(|seed| {
+        (Just(seed.clone()), arb_reproducible_version(&seed), arb_reproducible_version(&seed))
+    })) {
+        prop_assert_eq!(Version::parse(&a.to_string()).unwrap(), a.clone());
+        prop_assert_eq!(&a.pre, &b.pre);
+        prop_assert_eq!(&a.build, &b.build);
+        prop_assert_eq!((a.pre.clone(), a.build.clone()), reproducible_stamp(&seed));
+    }
+}
+
+#[test]
+fn test_reproducible_stamp_is_stable() {
+    let (pre, build) = reproducible_stamp(b"");
+    assert_eq!(pre.as_str(), "repro.gcbf29ce484222325");
+    assert_eq!(build.as_str(), "cbf29ce484222325");
+
+    let (pre, build) = reproducible_stamp(&[0xde, 0xad]);
+    assert_ne!(pre.as_str(), "repro.gcbf29ce484222325");
+    assert_eq!(build.as_str(), "dead");
+}
@@ -0,0 +1,160 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+mod common;
+
+use common::candidates;
+use proptest::prelude::*;
+use proptest_semver::*;
+use semver::VersionReq;
+
+proptest! {
+    #[test]
+    fn test_arb_req_with_duplicates((req, redundancies) in arb_req_with_duplicates(8)) {
+        prop_assert!(!redundancies.is_empty());
+        prop_assert!(req.comparators.len() <= 8);
+
+        let redundant = redundancies.iter().map(|r| r.index).collect::<Vec<_>>();
+        let reduced = VersionReq {
+            comparators: req
+                .comparators
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| !redundant.contains(i))
+                .map(|(_, c)| c.clone())
+                .collect(),
+        };
+
+        for r in &redundancies {
+            prop_assert!(!redundant.contains(&r.of));
+            if r.kind == RedundancyKind::Duplicate {
+                prop_assert_eq!(&req.comparators[r.index], &req.comparators[r.of]);
+            }
+        }
+
+        prop_assert_eq!(decompose_version_req(&req), decompose_version_req(&reduced));
+        for v in candidates(&req) {
+            prop_assert_eq!(req.matches(&v), reduced.matches(&v));
+        }
+
+        // Always survives a round trip through a String.
+        VersionReq::parse(&req.to_string()).unwrap();
+    }
+
+    #[test]
+    fn test_arb_reqs_ordered_by_restrictiveness((reqs, order) in arb_reqs_ordered_by_restrictiveness(5)) {
+        prop_assert_eq!(reqs.len(), 5);
+        let mut sorted = order.clone();
+        sorted.sort_unstable();
+        prop_assert_eq!(sorted, (0..5).collect::<Vec<_>>());
+
+        for pair in order.windows(2) {
+            let (looser, stricter) = (&reqs[pair[0]], &reqs[pair[1]]);
+            let mut only_looser = false;
+            for req in [looser, stricter] {
+                for v in candidates(req) {
+                    if stricter.matches(&v) {
+                        prop_assert!(looser.matches(&v), "{} {} {}", looser, stricter, v);
+                    } else if looser.matches(&v) {
+                        only_looser = true;
+                    }
+                }
+            }
+            prop_assert!(only_looser, "{} {}", looser, stricter);
+        }
+    }
+
+    #[test]
+    fn test_arb_req_cache_case(case in arb_req_cache_case()) {
+        prop_assert_ne!(&case.a, &case.b);
+        let (a, b) = (VersionReq::parse(&case.a).unwrap(), VersionReq::parse(&case.b).unwrap());
+
+        // Also just above every Pre-Release, since `>1.0.0-0` and `>=1` only
+        // disagree there.
+        let mut near = candidates(&a);
+        near.extend(candidates(&b));
+        for v in near.clone() {
+            if !v.pre.is_empty() {
+                let mut v = v;
+                v.pre = semver::Prerelease::new(&format!("{}.0", v.pre)).unwrap();
+                near.push(v);
+            }
+        }
+        let disagreement = near.into_iter().find(|v| a.matches(v) != b.matches(v));
+        match case.relation {
+            ReqCacheRelation::Equivalent => prop_assert!(disagreement.is_none(), "{:?}", case),
+            ReqCacheRelation::Different => prop_assert!(disagreement.is_some(), "{:?}", case),
+        }
+    }
+
+    #[test]
+    fn test_arb_req_over((universe, req) in arb_vec_versions(8).prop_flat_map(|universe| {
+        (Just(universe.clone()), arb_req_over(&universe))
+    })) {
+        VersionReq::parse(&req.to_string()).unwrap();
+        for c in &req.comparators {
+            let anchored = universe.iter().any(|v| {
+                v.major == c.major
+                    && c.minor.is_none_or(|minor| v.minor == minor)
+                    && c.patch.is_none_or(|patch| v.patch == patch)
+            });
+            prop_assert!(anchored, "{}", c);
+        }
+    }
+
+    #[test]
+    fn test_arb_matching_req_version_pair((req, v) in arb_matching_req_version_pair()) {
+        prop_assert_eq!(&VersionReq::parse(&req.to_string()).unwrap(), &req);
+        prop_assert!(req.matches(&v), "{} should match {}", req, v);
+    }
+
+    #[test]
+    fn test_arb_non_matching_req_version_pair((req, v) in arb_non_matching_req_version_pair()) {
+        prop_assert_eq!(&VersionReq::parse(&req.to_string()).unwrap(), &req);
+        prop_assert!(!req.matches(&v), "{} should not match {}", req, v);
+    }
+
+    #[test]
+    fn test_arb_version_req_matching((v, req) in arb_realistic_version().prop_flat_map(|v| (Just(v.clone()), arb_version_req_matching(&v)))) {
+        prop_assert_eq!(&VersionReq::parse(&req.to_string()).unwrap(), &req);
+        prop_assert!(req.matches(&v), "{} should match {}", req, v);
+    }
+
+    #[test]
+    fn test_arb_version_req_excluding((v, req) in arb_version_weighted(0.75, 0.25).prop_flat_map(|v| (Just(v.clone()), arb_version_req_excluding(&v)))) {
+        prop_assert_eq!(&VersionReq::parse(&req.to_string()).unwrap(), &req);
+        prop_assert!(!req.matches(&v), "{} should not match {}", req, v);
+    }
+
+    #[test]
+    fn test_arb_equivalent_version_req_pair((a, b) in arb_equivalent_version_req_pair()) {
+        prop_assert_ne!(&a, &b);
+        let (a, b) = (VersionReq::parse(&a).unwrap(), VersionReq::parse(&b).unwrap());
+        prop_assert_eq!(decompose_version_req(&a), decompose_version_req(&b));
+
+        let mut near = candidates(&a);
+        near.extend(candidates(&b));
+        let disagreement = near.into_iter().find(|v| a.matches(v) != b.matches(v));
+        prop_assert!(disagreement.is_none(), "{} and {} disagree on {:?}", a, b, disagreement);
+    }
+
+    #[test]
+    fn test_arb_unsatisfiable_version_req(req in arb_unsatisfiable_version_req()) {
+        prop_assert_eq!(&VersionReq::parse(&req.to_string()).unwrap(), &req);
+        prop_assert!(decompose_version_req(&req).is_empty(), "{}", req);
+
+        let satisfying = candidates(&req).into_iter().find(|v| req.matches(v));
+        prop_assert!(satisfying.is_none(), "{} matches {:?}", req, satisfying);
+    }
+}
@@ -0,0 +1,82 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+use proptest::prelude::*;
+use proptest_semver::*;
+
+/// Walks the chain of a [SchemaMigrationCase] the way a runner would,
+/// returning the index of the first step that doesn't follow on.
+fn first_gap(case: &SchemaMigrationCase) -> Option<usize> {
+    let mut at = &case.current;
+    for (i, step) in case.steps.iter().enumerate() {
+        if step.from != *at {
+            return Some(i);
+        }
+        at = &step.to;
+    }
+
+    (*at != case.target).then_some(case.steps.len())
+}
+
+proptest! {
+    #[test]
+    fn test_arb_schema_migration_case(case in arb_schema_migration_case(0.5)) {
+        prop_assert!(case.current.cmp_precedence(&case.target).is_lt());
+        for step in &case.steps {
+            prop_assert!(step.from.cmp_precedence(&step.to).is_lt());
+            prop_assert!(step.from.build.is_empty() && step.to.build.is_empty());
+        }
+        prop_assert_eq!(first_gap(&case), case.broken);
+        if case.broken.is_none() {
+            prop_assert!(!case.steps.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_arb_rollout_window((req, clients, enabled) in arb_rollout_window(3)) {
+        prop_assert_eq!(clients.len(), enabled.len());
+        for (v, enabled) in clients.iter().zip(enabled) {
+            prop_assert_eq!(req.matches(v), enabled);
+        }
+    }
+
+    #[test]
+    fn test_arb_plugin_compat_matrix(m in arb_plugin_compat_matrix(4, 3)) {
+        prop_assert_eq!(m.hosts.len(), 4);
+        prop_assert_eq!(m.plugins.len(), 3);
+        prop_assert_eq!(m.compatible.len(), 3);
+        for (req, row) in m.plugins.iter().zip(&m.compatible) {
+            prop_assert_eq!(row.len(), 4);
+            for (v, compatible) in m.hosts.iter().zip(row) {
+                prop_assert_eq!(req.matches(v), *compatible);
+            }
+        }
+    }
+
+    #[test]
+    fn test_arb_ci_matrix(m in arb_ci_matrix()) {
+        let expected = m.toolchains.len() * m.dependencies.len() * m.os.len();
+        prop_assert_eq!(m.cells.len(), expected);
+        prop_assert!(m.toolchains.windows(2).all(|w| w[0] < w[1]));
+        for cell in &m.cells {
+            let toolchain = &m.toolchains[cell.toolchain];
+            let dependency = &m.dependencies[cell.dependency];
+            let os = &m.os[cell.os];
+            let minimum = &dependency.toolchain.comparators[0];
+            let builds = (toolchain.major, toolchain.minor) >= (minimum.major, minimum.minor.unwrap());
+            let supported = dependency.unsupported_os.as_deref() != Some(os.as_str());
+            prop_assert_eq!(cell.included, builds && supported);
+        }
+    }
+}
@@ -0,0 +1,144 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+use proptest::prelude::*;
+use proptest_semver::*;
+
+proptest! {
+    #[test]
+    fn test_arb_version_delta_sequence((versions, deltas) in arb_version_delta_sequence(8)) {
+        prop_assert_eq!(versions.len(), 8);
+        prop_assert_eq!(deltas.len(), 7);
+
+        for (pair, delta) in versions.windows(2).zip(&deltas) {
+            prop_assert_eq!(delta.apply(&pair[0]), Some(pair[1].clone()));
+
+            let changed = [
+                pair[0].major != pair[1].major,
+                pair[0].minor != pair[1].minor,
+                pair[0].patch != pair[1].patch,
+            ];
+            prop_assert_eq!(changed.iter().filter(|c| **c).count(), 1);
+        }
+    }
+
+    #[test]
+    fn test_arb_version_delta_sequence_single((versions, deltas) in arb_version_delta_sequence(1)) {
+        prop_assert_eq!(versions.len(), 1);
+        prop_assert!(deltas.is_empty());
+    }
+
+    #[test]
+    fn test_arb_version_history(history in arb_version_history(32)) {
+        prop_assert!((1..=32).contains(&history.len()));
+        for pair in history.windows(2) {
+            prop_assert_eq!(pair[0].cmp_precedence(&pair[1]), std::cmp::Ordering::Less, "{} then {}", pair[0], pair[1]);
+        }
+    }
+
+    #[test]
+    fn test_arb_promotion_path((base, promotion) in arb_version().prop_flat_map(|base| (Just(base.clone()), arb_promotion_path(&base)))) {
+        let release = promotion.path.iter().filter(|v| v.pre.is_empty()).count();
+        prop_assert!(release >= 1);
+        for v in &promotion.path {
+            prop_assert_eq!((v.major, v.minor, v.patch), (base.major, base.minor, base.patch));
+            prop_assert!(v.build.is_empty());
+        }
+
+        match promotion.out_of_order {
+            Some(i) => {
+                prop_assert!(promotion.path[i - 1].cmp_precedence(&promotion.path[i]).is_ge());
+                for pair in promotion.path[..i].windows(2) {
+                    prop_assert!(pair[0].cmp_precedence(&pair[1]).is_lt());
+                }
+            }
+            None => {
+                prop_assert_eq!(release, 1);
+                prop_assert!(promotion.path.last().unwrap().pre.is_empty());
+                for pair in promotion.path.windows(2) {
+                    prop_assert!(pair[0].cmp_precedence(&pair[1]).is_lt());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_arb_channel_releases(releases in arb_channel_releases(&[Channel::Stable, Channel::Beta, Channel::Nightly])) {
+        prop_assert_eq!(releases.len(), 3);
+        for (channel, stream) in &releases {
+            prop_assert!(!stream.is_empty());
+            for pair in stream.windows(2) {
+                prop_assert!(pair[0].cmp_precedence(&pair[1]).is_lt());
+            }
+            for v in stream {
+                let tag = v.pre.split('.').next().unwrap();
+                let expected = match channel {
+                    Channel::Stable => "",
+                    Channel::Beta => "beta",
+                    Channel::Nightly => "nightly",
+                };
+                prop_assert_eq!(tag, expected);
+            }
+        }
+
+        let latest = |c: Channel| releases[&c].last().unwrap().clone();
+        prop_assert!(latest(Channel::Nightly).cmp_precedence(&latest(Channel::Beta)).is_gt());
+        prop_assert!(latest(Channel::Beta).cmp_precedence(&latest(Channel::Stable)).is_gt());
+    }
+
+    #[test]
+    fn test_arb_channel_releases_subset(releases in arb_channel_releases(&[Channel::Beta])) {
+        prop_assert_eq!(releases.keys().copied().collect::<Vec<_>>(), vec![Channel::Beta]);
+    }
+
+    #[test]
+    fn test_arb_prerelease_chain((target, chain) in arb_version().prop_flat_map(|v| (Just(v.clone()), arb_prerelease_chain(&v, 6)))) {
+        prop_assert!((1..=6).contains(&chain.len()));
+        let (last, pre_releases) = chain.split_last().unwrap();
+        prop_assert_eq!(last, &semver::Version::new(target.major, target.minor, target.patch));
+        for v in pre_releases {
+            prop_assert!(!v.pre.is_empty());
+            prop_assert_eq!((v.major, v.minor, v.patch), (target.major, target.minor, target.patch));
+        }
+        for pair in chain.windows(2) {
+            prop_assert!(pair[0] < pair[1], "{} should be below {}", pair[0], pair[1]);
+        }
+    }
+
+    #[test]
+    fn test_arb_major_bump_of((base, bumped) in arb_major_bump_of(None, 0.25)) {
+        prop_assert_eq!((bumped.major, bumped.minor, bumped.patch), (base.major + 1, 0, 0));
+        prop_assert!(bumped.build.is_empty());
+        prop_assert_eq!(bumped.cmp_precedence(&base), std::cmp::Ordering::Greater);
+    }
+
+    #[test]
+    fn test_arb_minor_bump_of((base, bumped) in arb_minor_bump_of(None, 0.25)) {
+        prop_assert_eq!((bumped.major, bumped.minor, bumped.patch), (base.major, base.minor + 1, 0));
+        prop_assert!(bumped.build.is_empty());
+        prop_assert_eq!(bumped.cmp_precedence(&base), std::cmp::Ordering::Greater);
+    }
+
+    #[test]
+    fn test_arb_patch_bump_of((base, bumped) in arb_patch_bump_of(Some(semver::Version::parse("1.2.3-alpha+build").unwrap()), 0.0)) {
+        prop_assert_eq!(bumped, semver::Version::new(1, 2, 4));
+        prop_assert_eq!(base.to_string(), "1.2.3-alpha+build");
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_arb_major_bump_of_overflow() {
+    let _ = arb_major_bump_of(Some(semver::Version::new(u64::MAX, 0, 0)), 0.5);
+}
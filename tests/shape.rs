@@ -0,0 +1,79 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+use proptest::option::Probability;
+use proptest::prelude::*;
+use proptest_semver::*;
+use semver::Version;
+
+proptest! {
+    #[test]
+    fn test_arb_version_shaped_default(v in arb_version_shaped(Shape::default())) {
+        prop_assert_eq!(Version::parse(&v.to_string()).unwrap(), v);
+    }
+
+    #[test]
+    fn test_arb_version_shaped(v in arb_version_shaped(Shape {
+        has_pre: Probability::new(1.0),
+        pre_idents: 2..3,
+        has_build: Probability::new(0.0),
+        digits: 2..4,
+        ..Shape::default()
+    })) {
+        prop_assert_eq!(v.pre.split('.').count(), 2);
+        prop_assert!(v.build.is_empty());
+        for n in [v.major, v.minor, v.patch] {
+            prop_assert!((10..1000).contains(&n), "{}", n);
+        }
+    }
+
+    #[test]
+    fn test_arb_version_shaped_max_digits(v in arb_version_shaped(Shape { digits: 20..21, ..Shape::default() })) {
+        prop_assert!(v.major >= 10u64.pow(19));
+    }
+
+    #[test]
+    fn test_arb_version_decomposed((v, (major, minor, patch), has_pre, has_build) in arb_version_decomposed()) {
+        prop_assert_eq!(major as usize, v.major.to_string().len());
+        prop_assert_eq!(minor as usize, v.minor.to_string().len());
+        prop_assert_eq!(patch as usize, v.patch.to_string().len());
+        prop_assert_eq!(has_pre, !v.pre.is_empty());
+        prop_assert_eq!(has_build, !v.build.is_empty());
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_shape_serde_round_trip() {
+    let shape = Shape {
+        has_pre: Probability::new(0.25),
+        pre_idents: 2..5,
+        has_build: Probability::new(1.0),
+        digits: 1..3,
+        ..Shape::default()
+    };
+    let json = serde_json::to_string(&shape).unwrap();
+    assert_eq!(serde_json::from_str::<Shape>(&json).unwrap(), shape);
+
+    // Missing fields are their default.
+    assert_eq!(
+        serde_json::from_str::<Shape>(r#"{"digits": [1, 3]}"#).unwrap(),
+        Shape {
+            digits: 1..3,
+            ..Shape::default()
+        }
+    );
+    assert!(serde_json::from_str::<Shape>(r#"{"has_pre": 2}"#).is_err());
+    assert!(serde_json::from_str::<Shape>(r#"{"colour": 0.5}"#).is_err());
+}
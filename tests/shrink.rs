@@ -0,0 +1,92 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+use proptest::prelude::*;
+use proptest::test_runner::{TestError, TestRunner};
+use proptest_semver::*;
+use semver::{Op, Version, VersionReq};
+
+/// The minimal failing input of `property`, which must fail.
+fn minimal(property: impl Fn(&Version) -> bool) -> Version {
+    let mut runner = TestRunner::default();
+    match runner.run(&arb_version_shrinking(0.5, 0.5), |v| {
+        prop_assert!(property(&v));
+        Ok(())
+    }) {
+        Err(TestError::Fail(_, v)) => v,
+        result => panic!("expected a failure, got {result:?}"),
+    }
+}
+
+#[test]
+fn test_arb_version_shrinking_to_release() {
+    assert_eq!(
+        minimal(|v| *v < Version::new(1, 0, 0)),
+        Version::new(1, 0, 0)
+    );
+}
+
+#[test]
+fn test_arb_version_shrinking_keeps_needed_pre_release() {
+    let v = minimal(|v| v.pre.is_empty());
+    assert_eq!((v.major, v.minor, v.patch), (0, 0, 0));
+    assert_eq!(v.pre.split('.').count(), 1);
+    assert!(v.build.is_empty());
+}
+
+#[test]
+fn test_arb_version_shrinking_keeps_needed_build_metadata() {
+    let v = minimal(|v| v.build.is_empty());
+    assert_eq!((v.major, v.minor, v.patch), (0, 0, 0));
+    assert!(v.pre.is_empty());
+}
+
+/// The minimal failing requirement of `property`, which must fail.
+fn minimal_req(property: impl Fn(&VersionReq) -> bool) -> VersionReq {
+    let mut runner = TestRunner::default();
+    match runner.run(&arb_version_req_shrinking(16), |req| {
+        prop_assert!(property(&req));
+        Ok(())
+    }) {
+        Err(TestError::Fail(_, req)) => req,
+        result => panic!("expected a failure, got {result:?}"),
+    }
+}
+
+#[test]
+fn test_arb_version_req_shrinking_drops_comparators() {
+    let req = minimal_req(|req| req.comparators.len() < 3);
+    assert_eq!(req.comparators.len(), 3);
+}
+
+#[test]
+fn test_arb_version_req_shrinking_keeps_needed_comparator() {
+    let req = minimal_req(|req| req.comparators.iter().all(|c| c.op != Op::Less));
+    assert_eq!(req.comparators.len(), 1);
+    assert_eq!(req.comparators[0].op, Op::Less);
+    assert_eq!(req.comparators[0].major, 0);
+}
+
+proptest! {
+    #[test]
+    fn test_arb_version_shrinking(v in arb_version_shrinking(0.5, 0.5)) {
+        prop_assert_eq!(Version::parse(&v.to_string()).unwrap(), v);
+    }
+
+    #[test]
+    fn test_arb_version_req_shrinking(req in arb_version_req_shrinking(8)) {
+        prop_assert!((1..=8).contains(&req.comparators.len()));
+        prop_assert_eq!(VersionReq::parse(&req.to_string()).unwrap(), req);
+    }
+}
@@ -0,0 +1,73 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+use proptest::prelude::*;
+use proptest::strategy::ValueTree;
+use proptest::test_runner::TestRunner;
+use proptest_semver::*;
+use semver::Version;
+
+fn run(strategy: &impl Strategy<Value = Version>, cases: usize) -> Vec<Version> {
+    let mut runner = TestRunner::default();
+    (0..cases)
+        .map(|_| strategy.new_tree(&mut runner).unwrap().current())
+        .collect()
+}
+
+#[test]
+fn test_arb_version_smoke_covers_corpus_first() {
+    let corpus = EDGE_CASE_VERSIONS
+        .iter()
+        .map(|s| Version::parse(s).unwrap())
+        .collect::<Vec<_>>();
+
+    let budget = corpus.len() + 16;
+    let values = run(&arb_version_smoke(budget), budget);
+    assert_eq!(values[..corpus.len()], corpus[..]);
+}
+
+#[test]
+fn test_arb_version_smoke_subsamples_deterministically() {
+    let a = run(&arb_version_smoke(8), 8);
+    let b = run(&arb_version_smoke(8), 8);
+    assert_eq!(a, b);
+    assert_eq!(a[0], Version::parse(EDGE_CASE_VERSIONS[0]).unwrap());
+
+    let mut unique = a.clone();
+    unique.dedup();
+    assert_eq!(unique.len(), 8);
+}
+
+#[test]
+fn test_corpus_first_random_shrinks() {
+    let strategy = corpus_first(vec![1000u32], 500u32..1000, 1);
+    let mut runner = TestRunner::default();
+
+    let mut tree = strategy.new_tree(&mut runner).unwrap();
+    assert_eq!(tree.current(), 1000);
+    assert!(!tree.simplify());
+
+    let mut tree = strategy.new_tree(&mut runner).unwrap();
+    while tree.simplify() {}
+    assert_eq!(tree.current(), 500);
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig { cases: 64, ..ProptestConfig::default() })]
+
+    #[test]
+    fn test_arb_version_smoke(v in arb_version_smoke(64)) {
+        prop_assert_eq!(Version::parse(&v.to_string()).unwrap(), v);
+    }
+}
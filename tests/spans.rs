@@ -0,0 +1,65 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+use proptest::prelude::*;
+use proptest_semver::*;
+use semver::{Comparator, Version, VersionReq};
+
+proptest! {
+    #[test]
+    fn test_arb_annotated_version_string((s, spans) in arb_annotated_version_string()) {
+        let v = Version::parse(&s).unwrap();
+
+        prop_assert_eq!(&s[spans.major], v.major.to_string());
+        prop_assert_eq!(&s[spans.minor], v.minor.to_string());
+        prop_assert_eq!(&s[spans.patch], v.patch.to_string());
+        prop_assert_eq!(spans.pre.map(|r| &s[r]).unwrap_or_default(), v.pre.as_str());
+        prop_assert_eq!(spans.build.map(|r| &s[r]).unwrap_or_default(), v.build.as_str());
+    }
+
+    #[test]
+    fn test_arb_annotated_req_string((s, spans) in arb_annotated_req_string(4)) {
+        let req = VersionReq::parse(&s).unwrap();
+        prop_assert_eq!(req.comparators.len(), spans.len());
+
+        for (comparator, spans) in req.comparators.iter().zip(&spans) {
+            prop_assert_eq!(&Comparator::parse(&s[spans.span.clone()]).unwrap(), comparator);
+
+            for token in &spans.tokens {
+                prop_assert!(spans.span.start <= token.span.start && token.span.end <= spans.span.end);
+                let text = &s[token.span.clone()];
+                match token.kind {
+                    ReqTokenKind::Op => prop_assert!(comparator.to_string().starts_with(text)),
+                    ReqTokenKind::Major => prop_assert_eq!(text, comparator.major.to_string()),
+                    ReqTokenKind::Minor => prop_assert_eq!(Some(text.parse().unwrap()), comparator.minor),
+                    ReqTokenKind::Patch => prop_assert_eq!(Some(text.parse().unwrap()), comparator.patch),
+                    ReqTokenKind::Wildcard => prop_assert_eq!(text, "*"),
+                    ReqTokenKind::PreRelease => prop_assert_eq!(text, comparator.pre.as_str()),
+                    ReqTokenKind::BuildMetadata => prop_assert!(!text.is_empty()),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_arb_log_line_with_versions((line, versions) in arb_log_line_with_versions()) {
+        let mut end = 0;
+        for (span, v) in &versions {
+            prop_assert!(span.start >= end);
+            end = span.end;
+            prop_assert_eq!(&Version::parse(&line[span.clone()]).unwrap(), v);
+        }
+        prop_assert!(!line.contains('\n'));
+    }
+}
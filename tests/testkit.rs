@@ -0,0 +1,149 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+use proptest::prelude::*;
+use proptest_semver::*;
+use semver::Version;
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize), serde(transparent))]
+struct Symmetric(Version);
+
+impl FromStr for Symmetric {
+    type Err = semver::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Version::parse(s).map(Symmetric)
+    }
+}
+
+impl TryFrom<&str> for Symmetric {
+    type Error = semver::Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+/// Trims whitespace in one route, but not the other.
+#[derive(Debug, PartialEq)]
+struct Asymmetric(Version);
+
+impl FromStr for Asymmetric {
+    type Err = semver::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Version::parse(s.trim()).map(Asymmetric)
+    }
+}
+
+impl TryFrom<&str> for Asymmetric {
+    type Error = semver::Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Version::parse(s).map(Asymmetric)
+    }
+}
+
+proptest! {
+    #[test]
+    fn test_check_str_parse_symmetry(s in arb_parse_symmetry_input()) {
+        check_str_parse_symmetry::<Symmetric>(&s)?;
+    }
+
+    #[test]
+    fn test_check_str_parse_symmetry_with(s in arb_parse_symmetry_input()) {
+        let parse = |s: &str| Version::parse(s).ok().map(Symmetric);
+        check_str_parse_symmetry_with::<Symmetric>(&s, &[("Version::parse", &parse)])?;
+    }
+
+    #[test]
+    fn test_check_str_parse_symmetry_asymmetric(v in arb_version()) {
+        let s = format!(" {v}");
+        prop_assert!(check_str_parse_symmetry::<Asymmetric>(&s).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_check_str_parse_symmetry_serde(s in arb_parse_symmetry_input()) {
+        check_str_parse_symmetry_serde::<Symmetric>(&s)?;
+    }
+
+    #[test]
+    fn test_check_total_order_precedence((a, b, c) in arb_version_triple()) {
+        check_total_order(Version::cmp_precedence, &a, &b, &c)?;
+    }
+
+    #[test]
+    fn test_check_total_order_ord((a, b, c) in arb_version_triple()) {
+        check_total_order(Version::cmp, &a, &b, &c)?;
+    }
+}
+
+/// A total order, but not semver precedence, since `10.0.0` sorts before
+/// `9.0.0`.
+fn by_string(a: &Version, b: &Version) -> Ordering {
+    a.to_string().cmp(&b.to_string())
+}
+
+#[test]
+fn test_check_total_order_by_string() {
+    let (a, b, c) = (
+        Version::new(10, 0, 0),
+        Version::new(9, 0, 0),
+        Version::new(0, 0, 0),
+    );
+    assert!(check_total_order(by_string, &a, &b, &c).is_err());
+}
+
+#[test]
+fn test_check_total_order_not_antisymmetric() {
+    let always_less = |_: &Version, _: &Version| Ordering::Less;
+    let v = Version::new(1, 0, 0);
+    assert!(check_total_order(always_less, &v, &v, &v).is_err());
+}
+
+/// Trims whitespace in [FromStr] and [TryFrom<&str>], but not when
+/// deserialized.
+#[cfg(feature = "serde")]
+#[derive(Debug, PartialEq, serde::Deserialize)]
+#[serde(transparent)]
+struct Lenient(Version);
+
+#[cfg(feature = "serde")]
+impl FromStr for Lenient {
+    type Err = semver::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Version::parse(s.trim()).map(Lenient)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<&str> for Lenient {
+    type Error = semver::Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_check_str_parse_symmetry_serde_lenient() {
+    assert!(check_str_parse_symmetry_serde::<Lenient>("1.2.3").is_ok());
+    assert!(check_str_parse_symmetry_serde::<Lenient>(" 1.2.3").is_err());
+}
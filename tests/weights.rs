@@ -0,0 +1,61 @@
+//! SPDX-License-Identifier: Apache-2.0
+//! Copyright 2025 canardleteer
+//!
+//! Licensed under the Apache License, Version 2.0 (the "License"); you may not
+//! use this file except in compliance with the License. You may obtain a copy
+//! of the License at
+//!
+//! <http://www.apache.org/licenses/LICENSE-2.0>
+//!
+//! Unless required by applicable law or agreed to in writing, software
+//! distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+//! WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+//! License for the specific language governing permissions and limitations
+//! under the License.
+use proptest::prelude::*;
+use proptest_semver::*;
+
+#[test]
+fn test_try_arb_semver_op() {
+    assert!(try_arb_semver_op(None, None).is_ok());
+    assert!(try_arb_semver_op(Some(0), None).is_ok());
+    assert_eq!(
+        try_arb_semver_op(Some(0), Some(0)).err(),
+        Some(WeightError::AllZero("arb_semver_op_weighted"))
+    );
+    assert_eq!(
+        try_arb_semver_op(Some(u32::MAX), None).err(),
+        Some(WeightError::Overflow("arb_semver_op_weighted"))
+    );
+}
+
+#[test]
+fn test_try_arb_comparator_op_ignores_wildcard() {
+    let weights = OpWeights {
+        wildcard: 1,
+        ..OpWeights::uniform(0)
+    };
+    assert!(try_arb_semver_op_weighted(&weights).is_ok());
+    assert!(try_arb_comparator_op(&weights).is_err());
+    assert!(try_arb_full_comparator_with_ops(&weights, None, None, None).is_err());
+}
+
+#[test]
+fn test_try_weighted_defaults() {
+    assert!(try_arb_full_comparator(None, None, None).is_ok());
+    assert!(try_arb_full_comparator(Some(0), Some(0), Some(0)).is_err());
+    assert!(try_arb_full_comparator_vec(4, Some(0), None).is_ok());
+    assert!(try_arb_full_comparator_vec(4, Some(0), Some(0)).is_err());
+    assert!(try_arb_identifier(Some(0), Some(0)).is_err());
+    assert!(try_arb_separator_faults("1.0.0", Some(0), Some(0), Some(0)).is_err());
+
+    let e = try_arb_identifier(Some(0), Some(0)).err().unwrap();
+    assert_eq!(e.to_string(), "every weight of `arb_identifier` is zero");
+}
+
+proptest! {
+    #[test]
+    fn test_try_arb_identifier(i in try_arb_identifier(Some(1), Some(0)).unwrap()) {
+        prop_assert!(i.bytes().all(|b| b.is_ascii_digit()));
+    }
+}